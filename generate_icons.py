@@ -437,6 +437,96 @@ def draw_magic_pull(draw, size):
         p3 = (tip_x + side * math.cos(rad+0.5), tip_y + side * math.sin(rad+0.5))
         draw.polygon([p1, p2, p3], fill=(200, 200, 255, 200))
 
+def draw_magic_meteor(draw, size):
+    """Draw a falling meteor over its impact telegraph."""
+    center = size // 2
+
+    # Telegraph circle on the ground
+    r = size * 0.38
+    cy = center + size * 0.12
+    draw.ellipse([center-r, cy-r*0.45, center+r, cy+r*0.45], fill=(255, 60, 20, 60), outline=(255, 90, 30, 200), width=int(size*0.015))
+
+    # Fiery tail
+    head = (center + size*0.08, center - size*0.02)
+    tail = (center + size*0.4, center - size*0.38)
+    draw.line([tail, head], fill=(255, 120, 0, 80), width=int(size*0.22))
+    draw.line([tail, head], fill=(255, 180, 40, 160), width=int(size*0.12))
+
+    # Rock
+    r = size * 0.12
+    draw.ellipse([head[0]-r, head[1]-r, head[0]+r, head[1]+r], fill=(120, 60, 30, 255), outline=(255, 140, 40, 255), width=int(size*0.015))
+    r = size * 0.05
+    draw.ellipse([head[0]-r, head[1]-r, head[0]+r, head[1]+r], fill=(255, 200, 80, 255))
+
+def draw_magic_frost_ring(draw, size):
+    """Draw an icy ring with shards."""
+    center = size // 2
+
+    # Ring body
+    for r_fac, alpha, w in [(0.4, 60, 0.08), (0.36, 200, 0.03)]:
+        r = size * r_fac
+        draw.ellipse([center-r, center-r, center+r, center+r], outline=(150, 230, 255, alpha), width=int(size*w))
+
+    # Shards pointing outward
+    for i in range(8):
+        rad = math.radians(i * 45)
+        base_r = size * 0.36
+        tip_r = size * 0.47
+        side = size * 0.04
+        bx, by = center + base_r * math.cos(rad), center + base_r * math.sin(rad)
+        tx, ty = center + tip_r * math.cos(rad), center + tip_r * math.sin(rad)
+        px, py = -math.sin(rad) * side, math.cos(rad) * side
+        draw.polygon([(tx, ty), (bx + px, by + py), (bx - px, by - py)], fill=(220, 250, 255, 255))
+
+    # Snowflake core
+    for i in range(3):
+        rad = math.radians(i * 60)
+        dx, dy = size * 0.14 * math.cos(rad), size * 0.14 * math.sin(rad)
+        draw.line([(center-dx, center-dy), (center+dx, center+dy)], fill=(200, 245, 255, 255), width=int(size*0.025))
+
+def draw_magic_chain_lightning(draw, size):
+    """Draw lightning jumping between three targets."""
+    targets = [(size*0.18, size*0.3), (size*0.52, size*0.72), (size*0.85, size*0.25)]
+
+    # Arcs
+    for (ax, ay), (bx, by) in zip(targets, targets[1:]):
+        mid1 = (ax + (bx-ax)*0.35 + size*0.05, ay + (by-ay)*0.35 - size*0.05)
+        mid2 = (ax + (bx-ax)*0.65 - size*0.05, ay + (by-ay)*0.65 + size*0.05)
+        points = [(ax, ay), mid1, mid2, (bx, by)]
+        draw.line(points, fill=(100, 150, 255, 100), width=int(size*0.09), joint='curve')
+        draw.line(points, fill=(230, 240, 255, 255), width=int(size*0.025), joint='curve')
+
+    # Targets
+    for (x, y) in targets:
+        r = size * 0.07
+        draw.ellipse([x-r, y-r, x+r, y+r], fill=(60, 80, 180, 255), outline=(200, 220, 255, 255), width=int(size*0.015))
+
+def draw_magic_turret(draw, size):
+    """Draw an arcane turret crystal on a base."""
+    center = size // 2
+
+    # Base
+    draw.ellipse([size*0.22, size*0.68, size*0.78, size*0.88], fill=(20, 20, 60, 255), outline=(180, 0, 255, 200), width=int(size*0.015))
+
+    # Crystal
+    draw.polygon([
+        (center, size*0.12),
+        (center + size*0.16, size*0.45),
+        (center, size*0.76),
+        (center - size*0.16, size*0.45),
+    ], fill=(180, 0, 255, 180), outline=(255, 240, 255, 255), width=int(size*0.015))
+
+    # Core glow
+    r = size * 0.06
+    draw.ellipse([center-r, size*0.45-r, center+r, size*0.45+r], fill=(255, 240, 255, 255))
+
+    # Bolts firing out
+    for angle in [-30, 210]:
+        rad = math.radians(angle)
+        x0, y0 = center + size*0.2*math.cos(rad), size*0.4 + size*0.2*math.sin(rad)
+        x1, y1 = center + size*0.42*math.cos(rad), size*0.4 + size*0.42*math.sin(rad)
+        draw.line([(x0, y0), (x1, y1)], fill=(200, 100, 255, 220), width=int(size*0.03))
+
 def main():
     os.makedirs(OUTPUT_DIR, exist_ok=True)
     print("Generating improved icons...")
@@ -457,6 +547,10 @@ def main():
     create_icon(draw_magic_global, "magic_global.png")
     create_icon(draw_magic_push, "magic_push.png")
     create_icon(draw_magic_pull, "magic_pull.png")
+    create_icon(draw_magic_meteor, "magic_meteor.png")
+    create_icon(draw_magic_frost_ring, "magic_frost_ring.png")
+    create_icon(draw_magic_chain_lightning, "magic_chain_lightning.png")
+    create_icon(draw_magic_turret, "magic_turret.png")
     
     print(f"Done! Check {OUTPUT_DIR}")
 
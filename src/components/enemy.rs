@@ -1,6 +1,7 @@
 use super::physics::{Collider, Velocity};
 use super::status::UnitStatus;
 use bevy::prelude::*;
+use serde::Serialize;
 
 #[derive(Component)]
 #[require(Transform, Visibility, Velocity, Collider, UnitStatus)]
@@ -38,7 +39,29 @@ pub struct YellowEnemy;
 
 #[derive(Component)]
 #[require(Transform, Visibility)]
+#[allow(clippy::struct_field_names)]
 pub struct YellowAi {
     pub blink_timer: Timer,
     pub force_timer: Timer,
+    pub spell_timer: Timer,
+}
+
+/// Enemy archetype, derived from the marker components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum EnemyKind {
+    Grunt,
+    Elite,
+    Yellow,
+}
+
+impl EnemyKind {
+    pub const fn from_markers(elite: bool, yellow: bool) -> Self {
+        if elite {
+            Self::Elite
+        } else if yellow {
+            Self::Yellow
+        } else {
+            Self::Grunt
+        }
+    }
 }
@@ -1,6 +1,9 @@
 pub mod attack_effects;
 pub mod enemy;
+pub mod perk;
 pub mod physics;
+pub mod pickup;
 pub mod player;
+pub mod relic;
 pub mod status;
 pub mod weapon;
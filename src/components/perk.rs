@@ -0,0 +1,14 @@
+use crate::systems::ui::ShopButton;
+
+/// Level-up rewards drafted from the perk screen, see `systems::experience`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Perk {
+    /// Same effect as one copy of the shop card, free of charge
+    Upgrade(ShopButton),
+    /// Kills heal the player
+    Bloodthirst,
+    /// Critical hits restore mana
+    Overcharge,
+    /// Hand-fired damage is boosted while the hands hold different weapons
+    Ambidextrous,
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpKind {
+    /// Faster movement
+    Haste,
+    /// Huge magnet radius
+    Magnet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PickupKind {
+    Gold(u32),
+    Health(f32),
+    PowerUp(PowerUpKind),
+    /// Random relic the player doesn't own yet, rolled on collection
+    Relic,
+}
+
+/// Loot on the ground, collected on contact or pulled in by the player's magnet
+#[derive(Component)]
+#[require(Transform, Visibility)]
+pub struct Pickup {
+    pub kind: PickupKind,
+    pub lifetime: Timer,
+}
+
+/// Endless mode vendor roaming near the player, selling one shop card on contact
+#[derive(Component)]
+#[require(Transform, Visibility)]
+pub struct ShopDrone {
+    pub card: crate::systems::ui::menu::ShopButton,
+    pub price: u32,
+    pub heading: Vec2,
+    pub turn_timer: Timer,
+    pub lifetime: Timer,
+}
+
+/// Temporary buffs granted by power-up pickups
+#[derive(Component, Default)]
+pub struct PowerUps {
+    pub active: Vec<(PowerUpKind, Timer)>,
+}
+
+impl PowerUps {
+    /// Starts a power-up, or refreshes its duration if already running
+    pub fn grant(&mut self, kind: PowerUpKind, duration: f32) {
+        let timer = Timer::from_seconds(duration, TimerMode::Once);
+        if let Some((_, existing)) = self.active.iter_mut().find(|(k, _)| *k == kind) {
+            *existing = timer;
+        } else {
+            self.active.push((kind, timer));
+        }
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.active.iter().any(|(k, _)| *k == kind)
+    }
+
+    pub fn tick(&mut self, delta: std::time::Duration) {
+        for (_, timer) in &mut self.active {
+            timer.tick(delta);
+        }
+        self.active.retain(|(_, timer)| !timer.is_finished());
+    }
+
+    /// Movement speed multiplier from Haste (1.0 when inactive)
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_active(PowerUpKind::Haste) {
+            crate::configs::pickup::HASTE_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
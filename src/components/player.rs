@@ -1,7 +1,11 @@
+use super::perk::Perk;
 use super::physics::{Collider, Velocity};
+use super::pickup::PowerUps;
+use super::relic::{Relic, RelicTriggers};
 use super::status::UnitStatus;
 use super::weapon::{GunState, MagicLoadout, SwordState, Weapon, WeaponType};
 use bevy::prelude::*;
+use serde::Serialize;
 
 #[derive(Component)]
 pub struct Currency {
@@ -34,9 +38,58 @@ impl Default for Health {
 }
 
 #[derive(Component)]
+pub struct Mana {
+    pub current: f32,
+    pub max: f32,
+    /// Mana restored per second
+    pub regen: f32,
+    /// Drives the HUD flash after a cast fails for lack of mana
+    pub starved_timer: Timer,
+}
+
+impl Mana {
+    pub fn new(max: f32, regen: f32) -> Self {
+        let mut starved_timer =
+            Timer::from_seconds(crate::configs::player::MANA_STARVED_FLASH, TimerMode::Once);
+        starved_timer.finish();
+        Self {
+            current: max,
+            max,
+            regen,
+            starved_timer,
+        }
+    }
+
+    /// Deducts `cost` if affordable. A failed spend restarts the starved flash.
+    pub fn try_spend(&mut self, cost: f32) -> bool {
+        if self.current >= cost {
+            self.current -= cost;
+            true
+        } else {
+            self.starved_timer.reset();
+            false
+        }
+    }
+
+    pub fn is_starved(&self) -> bool {
+        !self.starved_timer.is_finished()
+    }
+}
+
+impl Default for Mana {
+    fn default() -> Self {
+        Self::new(
+            crate::configs::player::MAX_MANA,
+            crate::configs::player::MANA_REGEN,
+        )
+    }
+}
+
+#[derive(Component, Clone, Copy)]
 pub struct PlayerStats {
     pub speed: f32,
     pub damage_multiplier: f32,
+    pub magnet_radius: f32,
 }
 
 impl Default for PlayerStats {
@@ -44,16 +97,18 @@ impl Default for PlayerStats {
         Self {
             speed: 300.0,
             damage_multiplier: 1.0,
+            magnet_radius: crate::configs::player::MAGNET_RADIUS,
         }
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 pub struct CombatStats {
     pub lifesteal: f32,
     pub crit_chance: f32,
     pub crit_damage: f32,
     pub cooldown_reduction: f32,
+    pub armor: f32,
 }
 
 impl Default for CombatStats {
@@ -63,10 +118,20 @@ impl Default for CombatStats {
             crit_chance: 0.0,
             crit_damage: 2.0,
             cooldown_reduction: 0.0,
+            armor: 0.0,
         }
     }
 }
 
+impl CombatStats {
+    /// Fraction of incoming damage absorbed by armor, with diminishing returns
+    pub fn damage_reduction(&self) -> f32 {
+        let armor = self.armor.max(0.0);
+        (armor / (armor + crate::configs::player::ARMOR_SCALING))
+            .min(crate::configs::player::MAX_DAMAGE_REDUCTION)
+    }
+}
+
 #[derive(Component, Default)]
 pub struct Progression {
     pub heal_count: u32,
@@ -77,6 +142,94 @@ pub struct Progression {
     pub lifesteal_upgrades: u32,
     pub cdr_upgrades: u32,
     pub nova_core: u32,
+    pub max_mana_upgrades: u32,
+    pub mana_regen_upgrades: u32,
+    pub armor_upgrades: u32,
+    pub move_speed_upgrades: u32,
+    pub dash_cooldown_upgrades: u32,
+    pub magnet_upgrades: u32,
+    /// Owned relics in pickup order
+    pub relics: Vec<Relic>,
+    /// Level-up perks in pick order
+    pub perks: Vec<Perk>,
+}
+
+impl Progression {
+    pub fn has_relic(&self, relic: Relic) -> bool {
+        self.relics.contains(&relic)
+    }
+
+    pub fn has_perk(&self, perk: Perk) -> bool {
+        self.perks.contains(&perk)
+    }
+}
+
+/// In-run level fed by kills. Level-ups queue in `pending` until a perk is picked for each.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Experience {
+    pub level: u32,
+    /// Progress towards the next level
+    pub xp: u32,
+    pub pending: u32,
+}
+
+impl Default for Experience {
+    fn default() -> Self {
+        Self {
+            level: 1,
+            xp: 0,
+            pending: 0,
+        }
+    }
+}
+
+impl Experience {
+    pub const fn xp_needed(&self) -> u32 {
+        crate::configs::experience::xp_to_next(self.level)
+    }
+
+    /// Adds XP and returns how many levels were gained
+    pub const fn gain(&mut self, amount: u32) -> u32 {
+        self.xp += amount;
+        let mut gained = 0;
+        while self.xp >= self.xp_needed() {
+            self.xp -= self.xp_needed();
+            self.level += 1;
+            gained += 1;
+        }
+        self.pending += gained;
+        gained
+    }
+}
+
+/// Hand-independent dodge. `active` runs while the burst is moving the player.
+#[derive(Component)]
+pub struct Dash {
+    pub cooldown: Timer,
+    pub active: Timer,
+    pub direction: Vec2,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        let mut cooldown =
+            Timer::from_seconds(crate::configs::player::DASH_COOLDOWN, TimerMode::Once);
+        cooldown.finish();
+        let mut active =
+            Timer::from_seconds(crate::configs::player::DASH_DURATION, TimerMode::Once);
+        active.finish();
+        Self {
+            cooldown,
+            active,
+            direction: Vec2::ZERO,
+        }
+    }
+}
+
+impl Dash {
+    pub fn is_dashing(&self) -> bool {
+        !self.active.is_finished()
+    }
 }
 
 #[derive(Component, Default)]
@@ -87,14 +240,19 @@ pub struct Progression {
     Collider,
     Currency,
     Health,
+    Mana,
+    Dash,
     PlayerStats,
     CombatStats,
     Progression,
+    Experience,
+    PowerUps,
+    RelicTriggers,
     UnitStatus
 )]
 pub struct Player;
 
-#[derive(Component, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Component, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Serialize)]
 pub enum HandType {
     #[default]
     Left,
@@ -115,3 +273,32 @@ pub struct GameCamera;
 #[derive(Component)]
 #[require(Transform, Visibility)]
 pub struct VirtualCursor;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::experience::xp_to_next;
+
+    #[test]
+    fn test_experience_carries_overflow_into_next_level() {
+        let mut experience = Experience::default();
+        assert_eq!(experience.gain(xp_to_next(1) - 1), 0);
+        assert_eq!(experience.level, 1);
+
+        // One XP finishes level 1, the rest counts towards level 2
+        assert_eq!(experience.gain(3), 1);
+        assert_eq!(experience.level, 2);
+        assert_eq!(experience.xp, 2);
+        assert_eq!(experience.pending, 1);
+    }
+
+    #[test]
+    fn test_experience_queues_multiple_level_ups() {
+        let mut experience = Experience::default();
+        let gained = experience.gain(xp_to_next(1) + xp_to_next(2));
+        assert_eq!(gained, 2);
+        assert_eq!(experience.level, 3);
+        assert_eq!(experience.xp, 0);
+        assert_eq!(experience.pending, 2);
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+/// Passive items that hook into combat events, see `systems::combat::relics`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relic {
+    /// Enemies explode on death
+    VolatileCore,
+    /// Critical hits arc chain lightning
+    StormSigil,
+    /// Taking damage releases a nova
+    ThornHeart,
+    /// Shuriken teleport leaves a decoy behind
+    MirrorVeil,
+    /// Every Nth sword swing is shattered for free
+    Whetstone,
+}
+
+/// Per-run relic counters and internal cooldowns. Owned relics live in `Progression`.
+#[derive(Component)]
+pub struct RelicTriggers {
+    pub sword_swings: u32,
+    pub storm_cooldown: Timer,
+}
+
+impl Default for RelicTriggers {
+    fn default() -> Self {
+        let mut storm_cooldown =
+            Timer::from_seconds(crate::configs::relic::STORM_COOLDOWN, TimerMode::Once);
+        storm_cooldown.finish();
+        Self {
+            sword_swings: 0,
+            storm_cooldown,
+        }
+    }
+}
+
+impl RelicTriggers {
+    /// Counts a sword swing, returns true if it is the free shattered one
+    pub const fn count_sword_swing(&mut self) -> bool {
+        self.sword_swings += 1;
+        self.sword_swings
+            .is_multiple_of(crate::configs::relic::WHETSTONE_SWING_INTERVAL)
+    }
+}
+
+/// Lure left behind by Mirror Veil. Nearby enemies chase it instead of the player.
+#[derive(Component)]
+#[require(Transform, Visibility)]
+pub struct Decoy;
@@ -4,6 +4,8 @@ use bevy::prelude::*;
 pub enum ForceType {
     Push,
     Pull,
+    /// Short shove applied to the player when taking a hit
+    Knockback,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,6 +18,8 @@ pub enum StatusEffect {
         speed: f32,
         move_type: ForceType,
     },
+    /// Multiplies movement speed by `factor` while active
+    Slowed { timer: Timer, factor: f32 },
 }
 
 #[derive(Component, Default, Debug)]
@@ -38,9 +42,44 @@ impl UnitStatus {
             self.effects
                 .retain(|e| !matches!(e, StatusEffect::Rooted { .. }));
         }
+        // Slows don't stack: a new slow refreshes the duration and keeps the stronger factor
+        if let StatusEffect::Slowed { timer, factor } = effect {
+            let active = self.effects.iter_mut().find_map(|e| match e {
+                StatusEffect::Slowed {
+                    timer: active_timer,
+                    factor: active_factor,
+                } => Some((active_timer, active_factor)),
+                _ => None,
+            });
+            if let Some((active_timer, active_factor)) = active {
+                *active_timer = timer;
+                *active_factor = active_factor.min(factor);
+                return;
+            }
+            self.effects.push(StatusEffect::Slowed { timer, factor });
+            return;
+        }
         self.effects.push(effect);
     }
 
+    pub fn slow(&mut self, factor: f32, duration: f32) {
+        self.add(StatusEffect::Slowed {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            factor,
+        });
+    }
+
+    /// Movement speed multiplier from active slows (1.0 when unaffected)
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .filter_map(|e| match e {
+                StatusEffect::Slowed { factor, .. } => Some(*factor),
+                _ => None,
+            })
+            .fold(1.0, f32::min)
+    }
+
     #[allow(dead_code)]
     pub fn root(&mut self, duration: f32) {
         self.add(StatusEffect::Rooted {
@@ -48,6 +87,18 @@ impl UnitStatus {
         });
     }
 
+    /// True only for hard roots, unlike `is_rooted` which also counts forced movement
+    pub fn has_root(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|e| matches!(e, StatusEffect::Rooted { .. }))
+    }
+
+    pub fn clear_forced_movement(&mut self) {
+        self.effects
+            .retain(|e| !matches!(e, StatusEffect::ForcedMovement { .. }));
+    }
+
     pub fn is_rooted(&self) -> bool {
         self.effects.iter().any(|e| {
             matches!(
@@ -1,7 +1,9 @@
 use super::physics::{Collider, Velocity};
+use super::player::{CombatStats, HandType};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum WeaponType {
     Shuriken,
@@ -10,6 +12,18 @@ pub enum WeaponType {
     Magic,
 }
 
+impl WeaponType {
+    /// `Locale` key of the display name
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Shuriken => "weapon.shuriken",
+            Self::Sword => "weapon.sword",
+            Self::Gun => "weapon.gun",
+            Self::Magic => "weapon.magic",
+        }
+    }
+}
+
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct Weapon {
@@ -34,7 +48,7 @@ impl Default for Weapon {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum SpellType {
     EnergyBolt,
@@ -44,6 +58,10 @@ pub enum SpellType {
     Global,
     ForcePush,
     ForcePull,
+    Meteor,
+    FrostRing,
+    ChainLightning,
+    ArcaneTurret,
 }
 
 #[derive(Component, Default)]
@@ -52,6 +70,62 @@ pub struct ForcePush;
 #[derive(Component, Default)]
 pub struct ForcePull;
 
+/// Delayed `AoE` strike; the telegraph entity turns into an impact projectile when the timer ends
+#[derive(Component)]
+#[require(Transform, Visibility)]
+pub struct MeteorStrike {
+    pub timer: Timer,
+    pub damage: f32,
+    pub radius: f32,
+    pub owner_entity: Entity,
+    pub hand: Option<HandType>,
+    pub faction: Faction,
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+}
+
+/// Telegraph fill that grows towards the full radius as the meteor approaches
+#[derive(Component)]
+pub struct MeteorTelegraphFill;
+
+/// Expanding `AoE` ring that slows every target it passes over
+#[derive(Component)]
+pub struct FrostRing {
+    pub start_radius: f32,
+    pub max_radius: f32,
+}
+
+/// Lightning that hops between targets, one hop per `hop_timer` tick
+#[derive(Component)]
+pub struct ChainLightning {
+    pub hop_timer: Timer,
+    pub hops_left: u32,
+    pub damage: f32,
+    /// Where the last arc ended (caster hand for the first hop)
+    pub position: Vec2,
+    /// Where to look for the next target (cursor for the first hop)
+    pub search_center: Vec2,
+    pub search_range: f32,
+    pub hit_entities: Vec<Entity>,
+    pub owner_entity: Entity,
+    pub hand: Option<HandType>,
+    pub faction: Faction,
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+}
+
+/// Summoned turret that auto-casts Energy Bolts at the nearest hostile target
+#[derive(Component)]
+#[require(Transform, Visibility)]
+pub struct ArcaneTurret {
+    pub fire_timer: Timer,
+    pub owner_entity: Entity,
+    pub hand: Option<HandType>,
+    pub faction: Faction,
+    pub damage_multiplier: f32,
+    pub combat_stats: CombatStats,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ActiveSpellSlot {
     Primary,
@@ -66,6 +140,22 @@ pub struct MagicLoadout {
     pub active_slot: ActiveSpellSlot,
 }
 
+impl MagicLoadout {
+    pub const fn active_spell(&self) -> SpellType {
+        match self.active_slot {
+            ActiveSpellSlot::Primary => self.primary,
+            ActiveSpellSlot::Secondary => self.secondary,
+        }
+    }
+
+    pub const fn slot_spell(&self, slot: ActiveSpellSlot) -> SpellType {
+        match slot {
+            ActiveSpellSlot::Primary => self.primary,
+            ActiveSpellSlot::Secondary => self.secondary,
+        }
+    }
+}
+
 impl Default for MagicLoadout {
     fn default() -> Self {
         Self {
@@ -105,6 +195,8 @@ pub struct Projectile {
     #[allow(dead_code)]
     pub direction: Vec2,
     pub owner_entity: Entity,
+    /// Hand that fired the projectile; `None` for enemies and relic effects
+    pub hand: Option<HandType>,
     /// AOE projectiles have 50% reduced lifesteal
     pub is_aoe: bool,
     pub faction: Faction,
@@ -149,7 +241,7 @@ pub struct SwordSwing {
     pub crit_damage: f32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SwordMode {
     Normal,
     Shattered,
@@ -167,7 +259,7 @@ impl Default for SwordState {
         }
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GunMode {
     Single,
     Shotgun,
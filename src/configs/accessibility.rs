@@ -0,0 +1,62 @@
+use crate::components::enemy::EnemyKind;
+use crate::configs::visuals::{DAMAGE_TEXT_COLOR_CRIT, DAMAGE_TEXT_OUTLINE_COLOR};
+use crate::resources::game_settings::ColorPalette;
+use bevy::prelude::Color;
+
+// UI text scale, multiplied into the window-height `UiScale`
+pub const TEXT_SCALE_MIN: f32 = 0.8;
+pub const TEXT_SCALE_MAX: f32 = 1.5;
+pub const TEXT_SCALE_STEP: f32 = 0.1;
+
+// Enemy glyphs
+/// Glyph size as a fraction of the enemy's visual radius
+pub const GLYPH_SCALE: f32 = 0.55;
+/// Cross arm thickness relative to its length
+pub const GLYPH_CROSS_THICKNESS: f32 = 0.3;
+pub const GLYPH_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.85);
+/// Drawn just above the enemy body
+pub const GLYPH_Z_OFFSET: f32 = 0.1;
+
+// Okabe-Ito colours, distinguishable under the common colour-vision deficiencies
+const VERMILLION: Color = Color::srgb(0.84, 0.37, 0.0);
+const ORANGE: Color = Color::srgb(0.9, 0.62, 0.0);
+const SKY_BLUE: Color = Color::srgb(0.34, 0.71, 0.91);
+const BLUE: Color = Color::srgb(0.0, 0.45, 0.7);
+const BLUISH_GREEN: Color = Color::srgb(0.0, 0.62, 0.45);
+const YELLOW: Color = Color::srgb(0.94, 0.89, 0.26);
+const REDDISH_PURPLE: Color = Color::srgb(0.8, 0.47, 0.65);
+const OUTLINE_DARK: Color = Color::srgb(0.0, 0.0, 0.0);
+
+/// Body colour of an enemy type
+pub const fn enemy_color(palette: ColorPalette, kind: EnemyKind) -> Color {
+    match (palette, kind) {
+        (ColorPalette::Standard, EnemyKind::Grunt) => Color::srgb(1.0, 0.0, 0.0),
+        (ColorPalette::Standard, EnemyKind::Elite) => Color::srgb(0.5, 0.0, 0.5),
+        (ColorPalette::Standard, EnemyKind::Yellow) => Color::srgb(1.0, 1.0, 0.0),
+        (ColorPalette::RedGreen | ColorPalette::BlueYellow, EnemyKind::Grunt) => VERMILLION,
+        (ColorPalette::RedGreen, EnemyKind::Elite) => BLUE,
+        (ColorPalette::RedGreen, EnemyKind::Yellow) => YELLOW,
+        (ColorPalette::BlueYellow, EnemyKind::Elite) => BLUISH_GREEN,
+        (ColorPalette::BlueYellow, EnemyKind::Yellow) => REDDISH_PURPLE,
+    }
+}
+
+/// Arrow and minimap dot colour; the standard palette keeps its brighter threat tints
+pub const fn indicator_color(palette: ColorPalette, kind: EnemyKind) -> Color {
+    match (palette, kind) {
+        (ColorPalette::Standard, EnemyKind::Grunt) => Color::srgb(1.0, 0.3, 0.3),
+        (ColorPalette::Standard, EnemyKind::Elite) => Color::srgb(1.0, 0.55, 0.1),
+        (ColorPalette::Standard, EnemyKind::Yellow) => Color::srgb(1.0, 0.95, 0.2),
+        (ColorPalette::RedGreen, EnemyKind::Elite) => SKY_BLUE,
+        _ => enemy_color(palette, kind),
+    }
+}
+
+/// (fill, outline) of crit damage numbers
+pub const fn crit_text_colors(palette: ColorPalette) -> (Color, Color) {
+    match palette {
+        ColorPalette::Standard => (DAMAGE_TEXT_COLOR_CRIT, DAMAGE_TEXT_OUTLINE_COLOR),
+        ColorPalette::RedGreen => (ORANGE, OUTLINE_DARK),
+        ColorPalette::BlueYellow => (VERMILLION, OUTLINE_DARK),
+    }
+}
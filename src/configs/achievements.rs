@@ -0,0 +1,89 @@
+use crate::components::weapon::WeaponType;
+use crate::resources::achievements::{Achievement, Requirement};
+
+/// Storage entry of the achievement progress (`ambidex_achievements.json` natively)
+pub const ACHIEVEMENTS_SAVE_NAME: &str = "achievements";
+
+// Unlock toasts
+pub const TOAST_DURATION: f32 = 4.0;
+/// Toasts fade out over the last part of their lifetime
+pub const TOAST_FADE: f32 = 0.6;
+pub const TOAST_WIDTH: f32 = 320.0;
+
+/// Order the achievements screen lists entries in
+pub const ACHIEVEMENT_LIST: [Achievement; 9] = [
+    Achievement::FirstBlood,
+    Achievement::Exterminator,
+    Achievement::Veteran,
+    Achievement::BladeMaster,
+    Achievement::Untouchable,
+    Achievement::GravityWell,
+    Achievement::Deadeye,
+    Achievement::Shopaholic,
+    Achievement::Collector,
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct AchievementConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+    pub requirement: Requirement,
+}
+
+pub const fn get_achievement_config(achievement: Achievement) -> AchievementConfig {
+    match achievement {
+        Achievement::FirstBlood => AchievementConfig {
+            name: "achievement.first_blood.name",
+            description: "achievement.first_blood.description",
+            requirement: Requirement::LifetimeKills(1),
+        },
+        Achievement::Exterminator => AchievementConfig {
+            name: "achievement.exterminator.name",
+            description: "achievement.exterminator.description",
+            requirement: Requirement::LifetimeKills(1000),
+        },
+        Achievement::Veteran => AchievementConfig {
+            name: "achievement.veteran.name",
+            description: "achievement.veteran.description",
+            requirement: Requirement::ClearRound {
+                round: 15,
+                weapon: None,
+            },
+        },
+        Achievement::BladeMaster => AchievementConfig {
+            name: "achievement.blade_master.name",
+            description: "achievement.blade_master.description",
+            requirement: Requirement::ClearRound {
+                round: 10,
+                weapon: Some(WeaponType::Sword),
+            },
+        },
+        Achievement::Untouchable => AchievementConfig {
+            name: "achievement.untouchable.name",
+            description: "achievement.untouchable.description",
+            requirement: Requirement::FlawlessRound,
+        },
+        Achievement::GravityWell => AchievementConfig {
+            name: "achievement.gravity_well.name",
+            description: "achievement.gravity_well.description",
+            requirement: Requirement::PullKills(5),
+        },
+        Achievement::Deadeye => AchievementConfig {
+            name: "achievement.deadeye.name",
+            description: "achievement.deadeye.description",
+            requirement: Requirement::CritChance(100),
+        },
+        Achievement::Shopaholic => AchievementConfig {
+            name: "achievement.shopaholic.name",
+            description: "achievement.shopaholic.description",
+            requirement: Requirement::CardsBought(50),
+        },
+        Achievement::Collector => AchievementConfig {
+            name: "achievement.collector.name",
+            description: "achievement.collector.description",
+            requirement: Requirement::RelicsOwned(5),
+        },
+    }
+}
@@ -0,0 +1,147 @@
+use crate::resources::audio::{Sfx, SynthSound, Waveform};
+
+/// Output rate of every synthesized sound
+pub const SAMPLE_RATE: u32 = 44_100;
+
+// Volume sliders, stored in `GameSettings`
+pub const DEFAULT_MASTER_VOLUME: f32 = 0.8;
+pub const DEFAULT_SFX_VOLUME: f32 = 0.8;
+pub const DEFAULT_MUSIC_VOLUME: f32 = 0.5;
+pub const VOLUME_STEP: f32 = 0.1;
+pub const VOLUME_SLIDER_WIDTH: f32 = 120.0;
+pub const VOLUME_SLIDER_HEIGHT: f32 = 12.0;
+
+// Polyphony
+/// Voices of one effect playing at once; rapid fire past this is dropped
+pub const MAX_VOICES_PER_SFX: usize = 3;
+/// Voices across all effects
+pub const MAX_SFX_VOICES: usize = 12;
+
+// Low health warning
+/// Health fraction under which the heartbeat plays
+pub const LOW_HEALTH_FRACTION: f32 = 0.25;
+/// Seconds between heartbeats
+pub const LOW_HEALTH_BEAT_INTERVAL: f32 = 0.9;
+
+// Background music: a looping arpeggio over a four-chord progression
+pub const MUSIC_BPM: f32 = 96.0;
+pub const MUSIC_GAIN: f32 = 0.12;
+/// Chord tones in Hz: Am, F, C, G
+pub const MUSIC_CHORDS: [[f32; 3]; 4] = [
+    [220.0, 261.63, 329.63],
+    [174.61, 220.0, 261.63],
+    [261.63, 329.63, 392.0],
+    [196.0, 246.94, 293.66],
+];
+/// Chord tone played on each eighth note of a bar
+pub const MUSIC_ARPEGGIO: [usize; 8] = [0, 1, 2, 1, 0, 2, 1, 2];
+/// Exponential decay rate of each arpeggio note
+pub const MUSIC_NOTE_DECAY: f32 = 5.0;
+/// Fade at the edges of each bass note, in seconds, to avoid clicks
+pub const MUSIC_BASS_FADE: f32 = 0.05;
+
+/// Synth recipe of each sound effect
+#[allow(clippy::too_many_lines)]
+pub const fn sfx_recipe(sfx: Sfx) -> SynthSound {
+    match sfx {
+        Sfx::ShurikenThrow => SynthSound {
+            wave: Waveform::Noise,
+            start_hz: 6000.0,
+            end_hz: 1500.0,
+            duration: 0.12,
+            attack: 0.005,
+            gain: 0.5,
+            notes: &[],
+        },
+        Sfx::GunShot => SynthSound {
+            wave: Waveform::Noise,
+            start_hz: 3000.0,
+            end_hz: 300.0,
+            duration: 0.15,
+            attack: 0.002,
+            gain: 0.8,
+            notes: &[],
+        },
+        Sfx::SpellCast => SynthSound {
+            wave: Waveform::Sine,
+            start_hz: 440.0,
+            end_hz: 1320.0,
+            duration: 0.25,
+            attack: 0.02,
+            gain: 0.3,
+            notes: &[],
+        },
+        Sfx::SwordSwing => SynthSound {
+            wave: Waveform::Noise,
+            start_hz: 800.0,
+            end_hz: 4000.0,
+            duration: 0.18,
+            attack: 0.05,
+            gain: 0.6,
+            notes: &[],
+        },
+        Sfx::Hit => SynthSound {
+            wave: Waveform::Square,
+            start_hz: 220.0,
+            end_hz: 110.0,
+            duration: 0.07,
+            attack: 0.002,
+            gain: 0.12,
+            notes: &[],
+        },
+        Sfx::Crit => SynthSound {
+            wave: Waveform::Square,
+            start_hz: 660.0,
+            end_hz: 990.0,
+            duration: 0.14,
+            attack: 0.002,
+            gain: 0.15,
+            notes: &[1.0, 1.5],
+        },
+        Sfx::EnemyDeath => SynthSound {
+            wave: Waveform::Saw,
+            start_hz: 300.0,
+            end_hz: 60.0,
+            duration: 0.3,
+            attack: 0.005,
+            gain: 0.2,
+            notes: &[],
+        },
+        Sfx::Purchase => SynthSound {
+            wave: Waveform::Triangle,
+            start_hz: 988.0,
+            end_hz: 988.0,
+            duration: 0.24,
+            attack: 0.004,
+            gain: 0.35,
+            notes: &[1.0, 1.335],
+        },
+        Sfx::RoundStart => SynthSound {
+            wave: Waveform::Square,
+            start_hz: 330.0,
+            end_hz: 330.0,
+            duration: 0.6,
+            attack: 0.005,
+            gain: 0.12,
+            notes: &[1.0, 1.26, 1.5, 2.0],
+        },
+        Sfx::RoundClear => SynthSound {
+            wave: Waveform::Triangle,
+            start_hz: 523.25,
+            end_hz: 523.25,
+            duration: 0.9,
+            attack: 0.005,
+            gain: 0.35,
+            notes: &[1.0, 1.26, 1.5, 2.0, 1.5, 2.0],
+        },
+        Sfx::LowHealth => SynthSound {
+            wave: Waveform::Sine,
+            start_hz: 70.0,
+            end_hz: 50.0,
+            duration: 0.25,
+            attack: 0.01,
+            gain: 0.6,
+            notes: &[],
+        },
+    }
+}
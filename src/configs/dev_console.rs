@@ -0,0 +1,25 @@
+//! Only `MAX_SPAWN_COUNT` is used without the `dev_console` feature, by the command parser
+#![cfg_attr(not(feature = "dev_console"), allow(dead_code))]
+
+use bevy::prelude::{Color, KeyCode};
+
+/// Opens and closes the console; also closed by Escape
+pub const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+/// Log lines kept on screen
+pub const LOG_LINES: usize = 12;
+pub const INPUT_MAX_CHARS: usize = 64;
+/// Most enemies a single `spawn` command may create
+pub const MAX_SPAWN_COUNT: u32 = 50;
+
+pub const CONSOLE_FONT_SIZE: f32 = 14.0;
+pub const CONSOLE_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.8);
+pub const CONSOLE_TEXT_COLOR: Color = Color::srgb(0.85, 0.95, 0.85);
+pub const CONSOLE_INPUT_COLOR: Color = Color::srgb(1.0, 0.9, 0.4);
+pub const OVERLAY_TEXT_COLOR: Color = Color::srgb(0.6, 1.0, 0.6);
+
+// Debug gizmos
+pub const COLLIDER_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+pub const PLAYER_COLLIDER_COLOR: Color = Color::srgb(0.3, 0.7, 1.0);
+pub const GRID_CELL_COLOR: Color = Color::srgb(1.0, 0.6, 0.1);
+/// Entities in a cell at which its tint is fully opaque
+pub const GRID_FULL_CELL: usize = 8;
@@ -0,0 +1,123 @@
+use crate::resources::run_settings::{Ascension, Difficulty, GameMode};
+
+pub const GAME_MODE_LIST: [GameMode; 2] = [GameMode::Rounds, GameMode::Endless];
+
+/// Order the run setup tab lists presets in
+pub const DIFFICULTY_LIST: [Difficulty; 4] = [
+    Difficulty::Easy,
+    Difficulty::Normal,
+    Difficulty::Hard,
+    Difficulty::Nightmare,
+];
+
+pub const ASCENSION_LIST: [Ascension; 5] = [
+    Ascension::EliteVanguard,
+    Ascension::Frenzy,
+    Ascension::Inflation,
+    Ascension::Bloodless,
+    Ascension::MageCouncil,
+];
+
+// Ascension values
+pub const VANGUARD_EXTRA_ELITES: u32 = 1;
+pub const FRENZY_SPEED_MULTIPLIER: f32 = 1.2;
+pub const INFLATION_PRICE_MULTIPLIER: f32 = 1.25;
+pub const MAGE_COUNCIL_MULTIPLIER: u32 = 2;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DifficultyConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+    pub enemy_health: f32,
+    pub enemy_damage: f32,
+    pub enemy_count: f32,
+}
+
+pub const fn get_difficulty_config(difficulty: Difficulty) -> DifficultyConfig {
+    match difficulty {
+        Difficulty::Easy => DifficultyConfig {
+            name: "difficulty.easy.name",
+            description: "difficulty.easy.description",
+            enemy_health: 0.75,
+            enemy_damage: 0.6,
+            enemy_count: 0.8,
+        },
+        Difficulty::Normal => DifficultyConfig {
+            name: "difficulty.normal.name",
+            description: "difficulty.normal.description",
+            enemy_health: 1.0,
+            enemy_damage: 1.0,
+            enemy_count: 1.0,
+        },
+        Difficulty::Hard => DifficultyConfig {
+            name: "difficulty.hard.name",
+            description: "difficulty.hard.description",
+            enemy_health: 1.3,
+            enemy_damage: 1.25,
+            enemy_count: 1.2,
+        },
+        Difficulty::Nightmare => DifficultyConfig {
+            name: "difficulty.nightmare.name",
+            description: "difficulty.nightmare.description",
+            enemy_health: 1.7,
+            enemy_damage: 1.5,
+            enemy_count: 1.5,
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AscensionConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+}
+
+pub const fn get_ascension_config(ascension: Ascension) -> AscensionConfig {
+    match ascension {
+        Ascension::EliteVanguard => AscensionConfig {
+            name: "ascension.elite_vanguard.name",
+            description: "ascension.elite_vanguard.description",
+        },
+        Ascension::Frenzy => AscensionConfig {
+            name: "ascension.frenzy.name",
+            description: "ascension.frenzy.description",
+        },
+        Ascension::Inflation => AscensionConfig {
+            name: "ascension.inflation.name",
+            description: "ascension.inflation.description",
+        },
+        Ascension::Bloodless => AscensionConfig {
+            name: "ascension.bloodless.name",
+            description: "ascension.bloodless.description",
+        },
+        Ascension::MageCouncil => AscensionConfig {
+            name: "ascension.mage_council.name",
+            description: "ascension.mage_council.description",
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GameModeConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+}
+
+pub const fn get_game_mode_config(mode: GameMode) -> GameModeConfig {
+    match mode {
+        GameMode::Rounds => GameModeConfig {
+            name: "game_mode.rounds.name",
+            description: "game_mode.rounds.description",
+        },
+        GameMode::Endless => GameModeConfig {
+            name: "game_mode.endless.name",
+            description: "game_mode.endless.description",
+        },
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+// Director
+/// Seconds survived per level; the level stands in for the round number when scaling
+/// enemies, prices and run statistics
+pub const SECONDS_PER_LEVEL: f32 = 45.0;
+pub const BASE_SPAWN_INTERVAL: f32 = 1.4;
+pub const MIN_SPAWN_INTERVAL: f32 = 0.2;
+/// Spawn interval multiplier per level survived, applied continuously
+pub const SPAWN_INTERVAL_DECAY: f32 = 0.85;
+pub const ELITE_INTERVAL: f32 = 40.0;
+pub const YELLOW_INTERVAL: f32 = 60.0;
+/// Spawning pauses while this many enemies are alive
+pub const MAX_ALIVE_ENEMIES: usize = 120;
+
+// Shop drones
+pub const DRONE_INTERVAL: f32 = 35.0;
+pub const DRONE_LIFETIME: f32 = 25.0;
+pub const DRONE_SPEED: f32 = 110.0;
+/// Seconds between random heading changes
+pub const DRONE_TURN_INTERVAL: f32 = 2.0;
+/// Drones spawn this far from the player and turn back when they stray past `DRONE_LEASH`
+pub const DRONE_SPAWN_DISTANCE: f32 = 350.0;
+pub const DRONE_LEASH: f32 = 600.0;
+/// Touching a drone buys its card when the player can afford it
+pub const DRONE_COLLECT_RADIUS: f32 = 40.0;
+pub const DRONE_RADIUS: f32 = 16.0;
+pub const DRONE_COLOR: Color = Color::srgb(0.3, 0.85, 1.0);
+pub const DRONE_LABEL_OFFSET: f32 = 30.0;
@@ -1,3 +1,5 @@
+use crate::components::enemy::EnemyKind;
+
 pub const BASE_HEALTH: f32 = 30.0;
 pub const HEALTH_SCALING_PER_ROUND: f32 = 10.0;
 pub const BASE_SPEED: f32 = 150.0;
@@ -42,3 +44,19 @@ pub const YELLOW_COLLIDER_RADIUS: f32 = 20.0;
 // Gold Rewards
 pub const GOLD_REWARD: u32 = 10;
 pub const ELITE_GOLD_REWARD: u32 = 100;
+
+// Yellow Mage spellcasting (uses the player's spell registry with Faction::Enemy)
+pub const YELLOW_SPELL_COOLDOWN: f32 = 5.0;
+/// Damage multiplier on top of the mage's own scaling for registry spells
+pub const YELLOW_SPELL_DAMAGE_SCALE: f32 = 0.5;
+// Yellow casts drain the same mana pool as the player's Magic weapon
+pub const YELLOW_MAX_MANA: f32 = 80.0;
+pub const YELLOW_MANA_REGEN: f32 = 10.0;
+
+pub const fn visual_radius(kind: EnemyKind) -> f32 {
+    match kind {
+        EnemyKind::Grunt => VISUAL_RADIUS,
+        EnemyKind::Elite => ELITE_VISUAL_RADIUS,
+        EnemyKind::Yellow => YELLOW_VISUAL_RADIUS,
+    }
+}
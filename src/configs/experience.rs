@@ -0,0 +1,91 @@
+use crate::components::enemy::EnemyKind;
+use crate::components::perk::Perk;
+use crate::systems::ui::ShopButton;
+use bevy::prelude::Color;
+
+// XP
+pub const GRUNT_XP: u32 = 1;
+pub const ELITE_XP: u32 = 10;
+pub const YELLOW_XP: u32 = 15;
+/// XP needed to leave level 1
+pub const BASE_XP_TO_LEVEL: u32 = 12;
+/// Extra XP needed for every level after the first
+pub const XP_PER_LEVEL: u32 = 8;
+
+pub const fn enemy_xp(kind: EnemyKind) -> u32 {
+    match kind {
+        EnemyKind::Grunt => GRUNT_XP,
+        EnemyKind::Elite => ELITE_XP,
+        EnemyKind::Yellow => YELLOW_XP,
+    }
+}
+
+pub const fn xp_to_next(level: u32) -> u32 {
+    BASE_XP_TO_LEVEL + XP_PER_LEVEL * level.saturating_sub(1)
+}
+
+// Perk draft
+/// Perks offered per level-up
+pub const PERK_CHOICES: usize = 3;
+
+/// Shop cards that can show up as stat perks. Limits and unlocks still apply.
+pub const PERK_UPGRADES: [ShopButton; 10] = [
+    ShopButton::DamageUp,
+    ShopButton::MaxHealthUp,
+    ShopButton::CritDamageUp,
+    ShopButton::CritChanceUp,
+    ShopButton::CooldownReductionUp,
+    ShopButton::MaxManaUp,
+    ShopButton::ManaRegenUp,
+    ShopButton::ArmorUp,
+    ShopButton::MoveSpeedUp,
+    ShopButton::MagnetUp,
+];
+
+/// Behaviour and synergy perks, each can be picked once per run
+pub const PERK_LIST: [Perk; 3] = [Perk::Bloodthirst, Perk::Overcharge, Perk::Ambidextrous];
+
+// Bloodthirst
+pub const BLOODTHIRST_HEAL: f32 = 2.0;
+
+// Overcharge
+pub const OVERCHARGE_MANA: f32 = 3.0;
+
+// Ambidextrous
+/// Damage multiplier on hand-fired hits while the hands hold different weapons
+pub const AMBIDEXTROUS_MULTIPLIER: f32 = 1.2;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PerkConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+    pub color: Color,
+}
+
+/// Names and descriptions of the behaviour perks. Stat perks reuse their shop card's.
+pub const fn get_perk_config(perk: Perk) -> PerkConfig {
+    match perk {
+        Perk::Upgrade(_) => PerkConfig {
+            name: "perk.upgrade.name",
+            description: "",
+            color: Color::WHITE,
+        },
+        Perk::Bloodthirst => PerkConfig {
+            name: "perk.bloodthirst.name",
+            description: "perk.bloodthirst.description",
+            color: Color::srgb(0.9, 0.2, 0.25),
+        },
+        Perk::Overcharge => PerkConfig {
+            name: "perk.overcharge.name",
+            description: "perk.overcharge.description",
+            color: Color::srgb(0.35, 0.65, 1.0),
+        },
+        Perk::Ambidextrous => PerkConfig {
+            name: "perk.ambidextrous.name",
+            description: "perk.ambidextrous.description",
+            color: Color::srgb(0.95, 0.8, 0.3),
+        },
+    }
+}
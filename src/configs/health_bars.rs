@@ -0,0 +1,40 @@
+use crate::components::enemy::EnemyKind;
+use crate::resources::run_settings::Ascension;
+use bevy::prelude::Color;
+
+/// Bar width as a multiple of the enemy's visual radius
+pub const BAR_WIDTH_SCALE: f32 = 2.0;
+pub const BAR_HEIGHT: f32 = 4.0;
+/// Gap between the top of the enemy and its bar
+pub const BAR_GAP: f32 = 8.0;
+/// Drawn above the enemy body and its glyph
+pub const BAR_Z_OFFSET: f32 = 0.2;
+pub const BAR_BACK_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+pub const BAR_FILL_COLOR: Color = Color::srgb(0.85, 0.15, 0.15);
+
+// Nameplates over elites and Yellow mages
+pub const NAMEPLATE_FONT_SIZE: f32 = 12.0;
+pub const NAMEPLATE_AFFIX_FONT_SIZE: f32 = 10.0;
+/// Gap between the bar and the name above it
+pub const NAMEPLATE_GAP: f32 = 10.0;
+pub const NAMEPLATE_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const NAMEPLATE_AFFIX_COLOR: Color = Color::srgb(1.0, 0.75, 0.3);
+
+/// `Locale` key of the nameplate, `None` for enemies that only get a bar
+pub const fn nameplate_name(kind: EnemyKind) -> Option<&'static str> {
+    match kind {
+        EnemyKind::Grunt => None,
+        EnemyKind::Elite => Some("enemy.elite.name"),
+        EnemyKind::Yellow => Some("enemy.yellow.name"),
+    }
+}
+
+/// Whether an ascension changes this enemy type, listed as an affix on its nameplate
+pub const fn is_affix(ascension: Ascension, kind: EnemyKind) -> bool {
+    match ascension {
+        Ascension::Frenzy => true,
+        Ascension::EliteVanguard => matches!(kind, EnemyKind::Elite),
+        Ascension::MageCouncil => matches!(kind, EnemyKind::Yellow),
+        Ascension::Inflation | Ascension::Bloodless => false,
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::Color;
+
+// Off-screen arrows
+/// Gap between an arrow and the edge of the screen
+pub const ARROW_MARGIN: f32 = 28.0;
+pub const ARROW_MAX_SIZE: f32 = 16.0;
+pub const ARROW_MIN_SIZE: f32 = 7.0;
+/// Distance past the screen edge at which arrows reach `ARROW_MIN_SIZE`
+pub const ARROW_FALLOFF: f32 = 900.0;
+pub const ARROW_Z_INDEX: f32 = 20.0;
+
+// Minimap
+pub const MINIMAP_SIZE: f32 = 140.0;
+/// World distance from the player to the minimap edge
+pub const MINIMAP_RANGE: f32 = 1200.0;
+/// Dot nodes kept in the minimap; enemies past this are not plotted
+pub const MINIMAP_MAX_DOTS: usize = 96;
+pub const MINIMAP_DOT_SIZE: f32 = 4.0;
+pub const MINIMAP_THREAT_DOT_SIZE: f32 = 7.0;
+pub const MINIMAP_PLAYER_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
@@ -0,0 +1,11 @@
+/// Storage entry of the high score table (`ambidex_high_scores.json` natively)
+pub const LEADERBOARD_SAVE_NAME: &str = "high_scores";
+
+/// Entries kept in the table; worse runs are dropped
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Mixed into the run seed so shop rolls and wave spawns draw from separate streams
+pub const SHOP_STREAM_SALT: u64 = 0x5EED_5A1E_C0FF_EE00;
+
+/// Separate stream for the level-up perk draft
+pub const PERK_STREAM_SALT: u64 = 0x9E2C_0FFE_E5EE_D001;
@@ -0,0 +1,92 @@
+use crate::resources::profile::Unlock;
+
+/// Storage entry of the profile (`ambidex_profile.json` natively)
+pub const PROFILE_SAVE_NAME: &str = "profile";
+/// Storage entry of the settings screen preferences
+pub const SETTINGS_SAVE_NAME: &str = "settings";
+
+// Loadout presets
+pub const MAX_LOADOUT_PRESETS: usize = 6;
+pub const PRESET_NAME_MAX_CHARS: usize = 16;
+
+// Shards awarded at game over
+pub const SHARDS_PER_ROUND: u32 = 5;
+pub const KILLS_PER_SHARD: u32 = 10;
+
+// Permanent bonus values
+pub const VITALITY_MAX_HEALTH: f32 = 25.0;
+pub const FORTUNE_STARTING_GOLD: u32 = 100;
+pub const FOCUS_MAX_MANA: f32 = 20.0;
+pub const POWER_DAMAGE: f32 = 0.1;
+
+/// Order the unlock screen lists entries in
+pub const UNLOCK_LIST: [Unlock; 9] = [
+    Unlock::Meteor,
+    Unlock::FrostRing,
+    Unlock::ChainLightning,
+    Unlock::ArcaneTurret,
+    Unlock::DashRecharge,
+    Unlock::Vitality,
+    Unlock::Fortune,
+    Unlock::Focus,
+    Unlock::Power,
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnlockConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+    pub cost: u32,
+}
+
+pub const fn get_unlock_config(unlock: Unlock) -> UnlockConfig {
+    match unlock {
+        Unlock::Meteor => UnlockConfig {
+            name: "unlock.meteor.name",
+            description: "unlock.meteor.description",
+            cost: 40,
+        },
+        Unlock::FrostRing => UnlockConfig {
+            name: "unlock.frost_ring.name",
+            description: "unlock.frost_ring.description",
+            cost: 40,
+        },
+        Unlock::ChainLightning => UnlockConfig {
+            name: "unlock.chain_lightning.name",
+            description: "unlock.chain_lightning.description",
+            cost: 50,
+        },
+        Unlock::ArcaneTurret => UnlockConfig {
+            name: "unlock.arcane_turret.name",
+            description: "unlock.arcane_turret.description",
+            cost: 60,
+        },
+        Unlock::DashRecharge => UnlockConfig {
+            name: "unlock.dash_recharge.name",
+            description: "unlock.dash_recharge.description",
+            cost: 40,
+        },
+        Unlock::Vitality => UnlockConfig {
+            name: "unlock.vitality.name",
+            description: "unlock.vitality.description",
+            cost: 75,
+        },
+        Unlock::Fortune => UnlockConfig {
+            name: "unlock.fortune.name",
+            description: "unlock.fortune.description",
+            cost: 75,
+        },
+        Unlock::Focus => UnlockConfig {
+            name: "unlock.focus.name",
+            description: "unlock.focus.description",
+            cost: 60,
+        },
+        Unlock::Power => UnlockConfig {
+            name: "unlock.power.name",
+            description: "unlock.power.description",
+            cost: 120,
+        },
+    }
+}
@@ -1,6 +1,20 @@
+pub mod accessibility;
+pub mod achievements;
+pub mod audio;
+pub mod dev_console;
+pub mod difficulty;
+pub mod endless;
 pub mod enemy;
+pub mod experience;
+pub mod health_bars;
+pub mod indicators;
+pub mod leaderboard;
+pub mod meta;
+pub mod pickup;
 pub mod player;
+pub mod relic;
 pub mod shop;
 pub mod spells;
+pub mod stats;
 pub mod visuals;
 pub mod weapons;
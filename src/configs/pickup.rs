@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+// Lifetime
+pub const LIFETIME: f32 = 12.0;
+/// Pickups blink during their last seconds on the ground
+pub const BLINK_TIME: f32 = 3.0;
+pub const BLINK_INTERVAL: f32 = 0.15;
+
+// Collection
+/// Distance from the player's center at which a pickup is collected
+pub const COLLECT_RADIUS: f32 = 28.0;
+pub const MAGNET_PULL_SPEED: f32 = 450.0;
+/// Speed pickups fly at when the round clears, growing by `VACUUM_ACCELERATION` per second
+pub const VACUUM_SPEED: f32 = 700.0;
+pub const VACUUM_ACCELERATION: f32 = 1500.0;
+/// Random offset so drops from the same spot don't stack exactly
+pub const SCATTER_RADIUS: f32 = 16.0;
+
+// Drops
+pub const HEALTH_ORB_CHANCE: f64 = 0.06;
+pub const HEALTH_ORB_HEAL: f32 = 15.0;
+pub const POWER_UP_CHANCE: f64 = 0.02;
+pub const ELITE_POWER_UP_CHANCE: f64 = 0.25;
+
+// Power-ups
+pub const HASTE_DURATION: f32 = 6.0;
+pub const HASTE_SPEED_MULTIPLIER: f32 = 1.5;
+pub const MAGNET_SURGE_DURATION: f32 = 8.0;
+pub const MAGNET_SURGE_RADIUS: f32 = 1200.0;
+
+// Visuals
+pub const GOLD_RADIUS: f32 = 6.0;
+pub const HEALTH_RADIUS: f32 = 9.0;
+pub const POWER_UP_RADIUS: f32 = 11.0;
+pub const GOLD_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+pub const HEALTH_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+pub const HASTE_COLOR: Color = Color::srgb(0.3, 0.9, 0.8);
+pub const MAGNET_COLOR: Color = Color::srgb(0.9, 0.3, 1.0);
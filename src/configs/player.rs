@@ -3,3 +3,25 @@ use bevy::prelude::*;
 pub const RADIUS: f32 = 20.0;
 pub const COLOR: Color = Color::srgb(0.0, 1.0, 1.0); // AQUA
 pub const STARTING_GOLD: u32 = 300;
+pub const MAX_MANA: f32 = 100.0;
+pub const MANA_REGEN: f32 = 12.0; // Per second
+pub const MANA_STARVED_FLASH: f32 = 0.3;
+/// Radius pickups start flying towards the player from
+pub const MAGNET_RADIUS: f32 = 90.0;
+
+// Armor: reduction = armor / (armor + ARMOR_SCALING), capped
+pub const ARMOR_SCALING: f32 = 100.0;
+pub const MAX_DAMAGE_REDUCTION: f32 = 0.75;
+
+// Hit reaction
+pub const KNOCKBACK_SPEED: f32 = 600.0;
+pub const KNOCKBACK_DURATION: f32 = 0.12;
+pub const IFRAME_FLASH_INTERVAL: f32 = 0.08;
+pub const IFRAME_FLASH_ALPHA: f32 = 0.25;
+
+// Dash
+pub const DASH_SPEED: f32 = 1100.0;
+pub const DASH_DURATION: f32 = 0.15;
+pub const DASH_COOLDOWN: f32 = 1.2;
+/// Minimum invulnerability window granted when a dash starts
+pub const DASH_IFRAMES: f32 = 0.3;
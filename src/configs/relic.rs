@@ -0,0 +1,104 @@
+use crate::components::relic::Relic;
+use crate::configs::shop::Rarity;
+use bevy::prelude::Color;
+
+/// Every relic, in the order the shop pool and elite drops list them
+pub const RELIC_LIST: [Relic; 5] = [
+    Relic::VolatileCore,
+    Relic::StormSigil,
+    Relic::ThornHeart,
+    Relic::MirrorVeil,
+    Relic::Whetstone,
+];
+
+// Volatile Core
+pub const VOLATILE_DAMAGE: f32 = 20.0;
+pub const VOLATILE_RADIUS: f32 = 60.0;
+pub const VOLATILE_LIFETIME: f32 = 0.1;
+
+// Storm Sigil
+pub const STORM_DAMAGE: f32 = 15.0;
+/// Targets hit by the proc, including the first one
+pub const STORM_HOPS: u32 = 3;
+/// Internal cooldown so lightning crits can't chain into each other endlessly
+pub const STORM_COOLDOWN: f32 = 0.5;
+
+// Thorn Heart
+pub const THORN_DAMAGE: f32 = 40.0;
+
+// Mirror Veil
+pub const DECOY_DURATION: f32 = 3.0;
+/// Enemies closer than this to a decoy chase it instead of the player
+pub const DECOY_LURE_RADIUS: f32 = 350.0;
+pub const DECOY_SIZE: f32 = 20.0;
+
+// Whetstone
+pub const WHETSTONE_SWING_INTERVAL: u32 = 5;
+
+// Drops
+pub const ELITE_RELIC_CHANCE: f64 = 0.2;
+/// Gold granted instead when a relic drop is collected with every relic already owned
+pub const RELIC_FALLBACK_GOLD: u32 = 150;
+pub const PICKUP_RADIUS: f32 = 13.0;
+pub const PICKUP_COLOR: Color = Color::srgb(1.0, 0.5, 0.1);
+
+// HUD strip
+pub const STRIP_SLOT_SIZE: f32 = 28.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct RelicConfig {
+    /// `Locale` key of the display name
+    pub name: &'static str,
+    /// Two-letter tag shown in the HUD strip
+    pub tag: &'static str,
+    /// `Locale` key
+    pub description: &'static str,
+    pub price: u32,
+    pub rarity: Rarity,
+    pub color: Color,
+}
+
+pub const fn get_relic_config(relic: Relic) -> RelicConfig {
+    match relic {
+        Relic::VolatileCore => RelicConfig {
+            name: "relic.volatile_core.name",
+            tag: "VC",
+            description: "relic.volatile_core.description",
+            price: 400,
+            rarity: Rarity::Epic,
+            color: Color::srgb(1.0, 0.45, 0.1),
+        },
+        Relic::StormSigil => RelicConfig {
+            name: "relic.storm_sigil.name",
+            tag: "SS",
+            description: "relic.storm_sigil.description",
+            price: 450,
+            rarity: Rarity::Epic,
+            color: Color::srgb(0.5, 0.8, 1.0),
+        },
+        Relic::ThornHeart => RelicConfig {
+            name: "relic.thorn_heart.name",
+            tag: "TH",
+            description: "relic.thorn_heart.description",
+            price: 350,
+            rarity: Rarity::Rare,
+            color: Color::srgb(0.9, 0.3, 0.6),
+        },
+        Relic::MirrorVeil => RelicConfig {
+            name: "relic.mirror_veil.name",
+            tag: "MV",
+            description: "relic.mirror_veil.description",
+            price: 300,
+            rarity: Rarity::Rare,
+            color: Color::srgb(0.0, 0.9, 0.8),
+        },
+        Relic::Whetstone => RelicConfig {
+            name: "relic.whetstone.name",
+            tag: "WS",
+            description: "relic.whetstone.description",
+            price: 300,
+            rarity: Rarity::Rare,
+            color: Color::srgb(0.8, 0.8, 0.9),
+        },
+    }
+}
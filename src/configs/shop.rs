@@ -1,71 +1,225 @@
+use crate::components::relic::Relic;
 use crate::systems::ui::ShopButton;
+use bevy::prelude::Color;
+
+/// Offers rolled per shop visit
+pub const OFFER_COUNT: usize = 5;
+pub const REROLL_BASE_COST: u32 = 20;
+/// Added to the reroll cost for every reroll within the same shop
+pub const REROLL_COST_STEP: u32 = 15;
+/// Card prices grow by this fraction per round after the first
+pub const PRICE_INFLATION_PER_ROUND: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    /// Relative roll weight in the shop pool
+    pub const fn weight(self) -> u32 {
+        match self {
+            Self::Common => 60,
+            Self::Rare => 28,
+            Self::Epic => 10,
+            Self::Legendary => 2,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Common => "rarity.common",
+            Self::Rare => "rarity.rare",
+            Self::Epic => "rarity.epic",
+            Self::Legendary => "rarity.legendary",
+        }
+    }
+
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Common => Color::srgb(0.75, 0.75, 0.75),
+            Self::Rare => Color::srgb(0.3, 0.6, 1.0),
+            Self::Epic => Color::srgb(0.75, 0.35, 1.0),
+            Self::Legendary => Color::srgb(1.0, 0.6, 0.1),
+        }
+    }
+}
+
+/// Every card the shop can roll
+pub const SHOP_POOL: [ShopButton; 19] = [
+    ShopButton::Heal,
+    ShopButton::DamageUp,
+    ShopButton::MaxHealthUp,
+    ShopButton::CritDamageUp,
+    ShopButton::CritChanceUp,
+    ShopButton::LifestealUp,
+    ShopButton::CooldownReductionUp,
+    ShopButton::NovaCore,
+    ShopButton::MaxManaUp,
+    ShopButton::ManaRegenUp,
+    ShopButton::ArmorUp,
+    ShopButton::MoveSpeedUp,
+    ShopButton::DashCooldownDown,
+    ShopButton::MagnetUp,
+    ShopButton::Relic(Relic::VolatileCore),
+    ShopButton::Relic(Relic::StormSigil),
+    ShopButton::Relic(Relic::ThornHeart),
+    ShopButton::Relic(Relic::MirrorVeil),
+    ShopButton::Relic(Relic::Whetstone),
+];
 
 #[allow(dead_code)]
 pub struct CardConfig {
+    /// `Locale` key of the display name
     pub name: &'static str,
+    /// Base price before round inflation, see `card_price`
     pub price: u32,
+    pub rarity: Rarity,
     pub limit: Option<u32>,
     pub value: f32,
+    /// `Locale` key
     pub description: &'static str,
 }
 
+#[allow(clippy::too_many_lines)]
 pub const fn get_card_config(btn_type: ShopButton) -> CardConfig {
     match btn_type {
         ShopButton::Heal => CardConfig {
-            name: "Heal",
+            name: "card.heal.name",
             price: 50,
+            rarity: Rarity::Common,
             limit: None,
             value: 100.0,
-            description: "Restore 30 HP",
+            description: "card.heal.description",
         },
         ShopButton::DamageUp => CardConfig {
-            name: "Damage Up",
+            name: "card.damage_up.name",
             price: 100,
+            rarity: Rarity::Common,
             limit: None,
             value: 0.1,
-            description: "+10% Damage",
+            description: "card.damage_up.description",
         },
         ShopButton::MaxHealthUp => CardConfig {
-            name: "Max Health Up",
+            name: "card.max_health_up.name",
             price: 150,
+            rarity: Rarity::Common,
             limit: None,
             value: 50.0,
-            description: "+50 Max HP",
+            description: "card.max_health_up.description",
         },
         ShopButton::CritDamageUp => CardConfig {
-            name: "Crit Damage",
+            name: "card.crit_damage_up.name",
             price: 200,
+            rarity: Rarity::Rare,
             limit: None,
             value: 0.5,
-            description: "+50% Crit Dmg",
+            description: "card.crit_damage_up.description",
         },
         ShopButton::CritChanceUp => CardConfig {
-            name: "Crit Chance",
+            name: "card.crit_chance_up.name",
             price: 250,
+            rarity: Rarity::Rare,
             limit: Some(10),
             value: 0.1,
-            description: "+10% Crit Chance",
+            description: "card.crit_chance_up.description",
         },
         ShopButton::LifestealUp => CardConfig {
-            name: "Lifesteal",
+            name: "card.lifesteal_up.name",
             price: 300,
+            rarity: Rarity::Epic,
             limit: Some(5),
             value: 0.1,
-            description: "+10% Lifesteal",
+            description: "card.lifesteal_up.description",
         },
         ShopButton::CooldownReductionUp => CardConfig {
-            name: "Magic CDR",
+            name: "card.cooldown_reduction_up.name",
             price: 350,
+            rarity: Rarity::Epic,
             limit: Some(5),
             value: 0.1,
-            description: "+10% CDR",
+            description: "card.cooldown_reduction_up.description",
         },
         ShopButton::NovaCore => CardConfig {
-            name: "Nova Core",
+            name: "card.nova_core.name",
             price: 1000,
+            rarity: Rarity::Legendary,
             limit: Some(1),
             value: 1.0,
-            description: "Nova explodes at cursor",
+            description: "card.nova_core.description",
+        },
+        ShopButton::MaxManaUp => CardConfig {
+            name: "card.max_mana_up.name",
+            price: 150,
+            rarity: Rarity::Common,
+            limit: None,
+            value: 25.0,
+            description: "card.max_mana_up.description",
         },
+        ShopButton::ManaRegenUp => CardConfig {
+            name: "card.mana_regen_up.name",
+            price: 200,
+            rarity: Rarity::Rare,
+            limit: Some(5),
+            value: 4.0,
+            description: "card.mana_regen_up.description",
+        },
+        ShopButton::ArmorUp => CardConfig {
+            name: "card.armor_up.name",
+            price: 200,
+            rarity: Rarity::Rare,
+            limit: Some(8),
+            value: 20.0,
+            description: "card.armor_up.description",
+        },
+        ShopButton::MoveSpeedUp => CardConfig {
+            name: "card.move_speed_up.name",
+            price: 120,
+            rarity: Rarity::Common,
+            limit: Some(5),
+            value: 30.0,
+            description: "card.move_speed_up.description",
+        },
+        ShopButton::DashCooldownDown => CardConfig {
+            name: "card.dash_cooldown_down.name",
+            price: 250,
+            rarity: Rarity::Rare,
+            limit: Some(4),
+            value: 0.15,
+            description: "card.dash_cooldown_down.description",
+        },
+        ShopButton::MagnetUp => CardConfig {
+            name: "card.magnet_up.name",
+            price: 100,
+            rarity: Rarity::Common,
+            limit: Some(5),
+            value: 40.0,
+            description: "card.magnet_up.description",
+        },
+        ShopButton::Relic(relic) => {
+            let relic = crate::configs::relic::get_relic_config(relic);
+            CardConfig {
+                name: relic.name,
+                price: relic.price,
+                rarity: relic.rarity,
+                limit: Some(1),
+                value: 0.0,
+                description: relic.description,
+            }
+        }
     }
 }
+
+/// Card price for the shop opened during `round`, times the run's `markup` (ascensions)
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn card_price(btn_type: ShopButton, round: u32, markup: f32) -> u32 {
+    let inflation = PRICE_INFLATION_PER_ROUND.mul_add(round.saturating_sub(1) as f32, 1.0);
+    (get_card_config(btn_type).price as f32 * inflation * markup).round() as u32
+}
@@ -0,0 +1,11 @@
+pub const DURATION: f32 = 5.0;
+pub const FIRE_INTERVAL: f32 = 0.5;
+/// Targeting range for the turret's bolts
+pub const RANGE: f32 = 450.0;
+/// Max distance from the caster the turret can be placed at
+pub const PLACE_RANGE: f32 = 200.0;
+/// Bolts fired by the turret deal a fraction of a normal Energy Bolt
+pub const DAMAGE_MULTIPLIER: f32 = 0.5;
+pub const VISUAL_RADIUS: f32 = 14.0;
+pub const COOLDOWN: f32 = 6.0;
+pub const MANA_COST: f32 = 35.0;
@@ -1,3 +1,5 @@
 // Blink has no numeric stats in combat.rs other than effect
 // but we can add placeholders if needed.
 // Currently logic handles it.
+pub const COOLDOWN: f32 = 0.8;
+pub const MANA_COST: f32 = 15.0;
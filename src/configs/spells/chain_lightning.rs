@@ -0,0 +1,16 @@
+pub const DAMAGE: f32 = 25.0;
+/// Number of targets hit including the first one
+pub const MAX_HOPS: u32 = 5;
+/// Radius around the cursor searched for the first target
+pub const CAST_RANGE: f32 = 300.0;
+/// Max distance for each following hop
+pub const HOP_RANGE: f32 = 250.0;
+pub const HOP_INTERVAL: f32 = 0.08;
+/// Damage multiplier applied after every hop
+pub const DAMAGE_FALLOFF: f32 = 0.85;
+pub const HIT_RADIUS: f32 = 12.0;
+pub const HIT_LIFETIME: f32 = 0.1;
+pub const ARC_LIFETIME: f32 = 0.15;
+pub const ARC_WIDTH: f32 = 3.0;
+pub const COOLDOWN: f32 = 1.0;
+pub const MANA_COST: f32 = 18.0;
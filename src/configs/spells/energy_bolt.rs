@@ -4,3 +4,5 @@ pub const SPEED: f32 = 500.0;
 pub const RADIUS: f32 = 4.0;
 pub const EXPLOSION_RADIUS: f32 = 80.0;
 pub const LIFETIME: f32 = 3.0;
+pub const COOLDOWN: f32 = 0.8;
+pub const MANA_COST: f32 = 5.0;
@@ -14,3 +14,8 @@ pub const PULL_SPEED_FACTOR: f32 = 1.4;
 
 pub const DAMAGE_BASE: f32 = 5.0;
 pub const DAMAGE_BONUS_MAX: f32 = 30.0;
+
+pub const PUSH_COOLDOWN: f32 = 0.8;
+pub const PULL_COOLDOWN: f32 = 0.8;
+pub const PUSH_MANA_COST: f32 = 12.0;
+pub const PULL_MANA_COST: f32 = 12.0;
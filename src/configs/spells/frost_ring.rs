@@ -0,0 +1,10 @@
+pub const DAMAGE: f32 = 15.0;
+pub const START_RADIUS: f32 = 20.0;
+pub const MAX_RADIUS: f32 = 320.0;
+/// Time for the ring to expand from start to max radius
+pub const EXPAND_DURATION: f32 = 0.5;
+/// Movement speed multiplier applied to hit targets
+pub const SLOW_FACTOR: f32 = 0.5;
+pub const SLOW_DURATION: f32 = 2.5;
+pub const COOLDOWN: f32 = 1.2;
+pub const MANA_COST: f32 = 20.0;
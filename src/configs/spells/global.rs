@@ -1,3 +1,5 @@
 pub const DAMAGE: f32 = 15.0;
 pub const RADIUS: f32 = 800.0;
 pub const LIFETIME: f32 = 0.2;
+pub const COOLDOWN: f32 = 0.8;
+pub const MANA_COST: f32 = 40.0;
@@ -4,3 +4,5 @@ pub const WIDTH: f32 = 4.0;
 #[allow(dead_code)]
 pub const WIDTH_COLLIDER: f32 = 500.0; // Collider half-extents x? No, actually full length is 1000, so 500 center.
 pub const LIFETIME: f32 = 0.1;
+pub const COOLDOWN: f32 = 0.8;
+pub const MANA_COST: f32 = 25.0;
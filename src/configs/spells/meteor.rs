@@ -0,0 +1,8 @@
+pub const DAMAGE: f32 = 90.0;
+pub const RADIUS: f32 = 110.0;
+/// Seconds between the telegraph appearing and the impact
+pub const IMPACT_DELAY: f32 = 1.0;
+pub const IMPACT_LIFETIME: f32 = 0.2;
+pub const SCREEN_SHAKE: f32 = 0.4;
+pub const COOLDOWN: f32 = 1.6;
+pub const MANA_COST: f32 = 30.0;
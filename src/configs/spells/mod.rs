@@ -1,6 +1,10 @@
+pub mod arcane_turret;
 pub mod blink;
+pub mod chain_lightning;
 pub mod energy_bolt;
 pub mod force;
+pub mod frost_ring;
 pub mod global;
 pub mod laser;
+pub mod meteor;
 pub mod nova;
@@ -0,0 +1,5 @@
+/// Storage entry of the last exported run (`ambidex_run_stats.json` natively)
+pub const RUN_STATS_SAVE_NAME: &str = "run_stats";
+
+/// Longest per-round breakdown shown on the game over screen; the export has every round
+pub const GAME_OVER_ROUND_ROWS: usize = 8;
@@ -7,6 +7,15 @@ pub const DAMAGE_TEXT_SIZE_CRIT: f32 = 30.0;
 pub const DAMAGE_TEXT_LIFETIME: f32 = 0.8;
 pub const DAMAGE_TEXT_VELOCITY: Vec2 = Vec2::new(0.0, 50.0);
 pub const DAMAGE_TEXT_Z_INDEX: f32 = 10.0;
+pub const DAMAGE_TEXT_COLOR_NORMAL: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const DAMAGE_TEXT_COLOR_CRIT: Color = Color::srgb(1.0, 0.0, 0.0);
+/// Crit numbers get a white outline drawn as a text shadow on the same entity
+pub const DAMAGE_TEXT_OUTLINE_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const DAMAGE_TEXT_OUTLINE_OFFSET: Vec2 = Vec2::new(1.5, -1.5);
+/// Hits on the same target within this window add up on one number
+pub const DAMAGE_TEXT_MERGE_WINDOW: f32 = 0.3;
+/// Pooled number entities; past this the oldest number is recycled
+pub const DAMAGE_TEXT_POOL_SIZE: usize = 48;
 
 // Magic UI Settings
 pub const MAGIC_PANEL_BG: Color = Color::srgba(0.06, 0.06, 0.08, 0.95);
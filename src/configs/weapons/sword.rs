@@ -1,3 +1,8 @@
+/// Time between swings
+pub const COOLDOWN: f32 = 0.5;
+/// Swings can hit a crowd, so they heal at half the usual lifesteal
+pub const LIFESTEAL_RATE: f32 = 0.5;
+
 pub const NORMAL_DAMAGE: f32 = 60.0;
 pub const NORMAL_RANGE: f32 = 200.0;
 pub const NORMAL_TIMER: f32 = 0.1;
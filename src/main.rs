@@ -19,45 +19,60 @@ use resources::cached_assets::CachedAssets;
 use resources::game_state::GameState;
 
 fn main() {
-    App::new()
-        .add_plugins(
-            DefaultPlugins
-                .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "Ambidex Survival".into(),
-                        ..default()
-                    }),
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Ambidex Survival".into(),
                     ..default()
-                })
-                .set(RenderPlugin {
-                    render_creation: RenderCreation::Automatic(WgpuSettings {
-                        backends: Some(Backends::all()),
-                        ..default()
-                    }),
-                    ..default()
-                })
-                .set(AssetPlugin {
-                    meta_check: AssetMetaCheck::Never,
+                }),
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: RenderCreation::Automatic(WgpuSettings {
+                    backends: Some(Backends::all()),
                     ..default()
                 }),
-        )
-        .add_plugins((
-            plugins::combat::CombatPlugin,
-            plugins::physics::PhysicsPlugin,
-            plugins::ui::UiPlugin,
-            plugins::player::PlayerPlugin,
-            plugins::status::StatusPlugin,
-            plugins::visuals::VisualsPlugin,
-        ))
-        .init_state::<GameState>()
-        .init_resource::<resources::round::RoundManager>()
-        .init_resource::<resources::input_settings::InputSettings>()
-        .init_resource::<resources::input_settings::VirtualInput>()
-        .init_resource::<resources::polish::ScreenShake>()
-        .init_resource::<components::physics::UniformGrid>()
-        .init_resource::<resources::game_state::PreviousMenuState>()
-        .add_systems(Startup, (setup_camera, init_cached_assets))
-        .run();
+                ..default()
+            })
+            .set(AssetPlugin {
+                meta_check: AssetMetaCheck::Never,
+                ..default()
+            }),
+    )
+    .add_plugins((
+        plugins::achievements::AchievementsPlugin,
+        plugins::audio::SoundPlugin,
+        plugins::combat::CombatPlugin,
+        plugins::endless::EndlessPlugin,
+        plugins::experience::ExperiencePlugin,
+        plugins::meta::MetaPlugin,
+        plugins::pause::PausePlugin,
+        plugins::physics::PhysicsPlugin,
+        plugins::ui::UiPlugin,
+        plugins::player::PlayerPlugin,
+        plugins::status::StatusPlugin,
+        plugins::visuals::VisualsPlugin,
+    ))
+    .init_state::<GameState>()
+    .init_resource::<resources::round::RoundManager>()
+    .init_resource::<resources::run_settings::RunSettings>()
+    .init_resource::<resources::run_stats::RunStats>()
+    .init_resource::<resources::input_settings::InputSettings>()
+    .insert_resource(resources::game_settings::GameSettings::load())
+    // Reads the saved language, so it must come after `GameSettings`
+    .add_plugins(plugins::locale::LocalePlugin)
+    .init_resource::<resources::input_settings::VirtualInput>()
+    .init_resource::<resources::polish::ScreenShake>()
+    .init_resource::<components::physics::UniformGrid>()
+    .init_resource::<resources::game_state::PreviousMenuState>()
+    .add_systems(Startup, (setup_camera, init_cached_assets));
+
+    #[cfg(feature = "dev_console")]
+    app.add_plugins(plugins::dev_console::DevConsolePlugin);
+
+    app.run();
 }
 
 fn setup_camera(mut commands: Commands, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
@@ -68,11 +83,13 @@ fn setup_camera(mut commands: Commands, mut windows: Query<&mut Window, With<Pri
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
 fn init_cached_assets(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    settings: Res<resources::game_settings::GameSettings>,
 ) {
-    let cached = CachedAssets::new(&mut meshes, &mut materials);
+    let cached = CachedAssets::new(&mut meshes, &mut materials, settings.palette);
     commands.insert_resource(cached);
 }
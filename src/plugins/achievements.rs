@@ -0,0 +1,24 @@
+use crate::resources::achievements::{AchievementProgress, AchievementTracker};
+use crate::resources::game_state::GameState;
+use crate::systems::achievements::{
+    achievement_collision_observer, achievement_damage_observer, achievement_kill_observer,
+    achievement_purchase_observer, achievement_round_observer, check_stat_achievements,
+    save_achievements,
+};
+use bevy::prelude::*;
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AchievementProgress::load())
+            .init_resource::<AchievementTracker>()
+            .add_observer(achievement_collision_observer)
+            .add_observer(achievement_kill_observer)
+            .add_observer(achievement_damage_observer)
+            .add_observer(achievement_purchase_observer)
+            .add_observer(achievement_round_observer)
+            .add_systems(Update, check_stat_achievements)
+            .add_systems(OnEnter(GameState::GameOver), save_achievements);
+    }
+}
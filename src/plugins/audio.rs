@@ -0,0 +1,32 @@
+use crate::resources::audio::{MusicLoop, SfxQueue, SynthSound};
+use crate::resources::game_state::GameState;
+use crate::systems::audio::{
+    damage_sfx_observer, death_sfx_observer, low_health_sfx, play_queued_sfx,
+    projectile_sfx_observer, purchase_sfx_observer, round_clear_sfx_observer, round_start_sfx,
+    setup_audio, sword_sfx_observer, update_music_volume,
+};
+use bevy::audio::AddAudioSource;
+use bevy::prelude::*;
+
+/// Synthesized sound effects and music; named to avoid clashing with `bevy::audio::AudioPlugin`
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<SynthSound>()
+            .add_audio_source::<MusicLoop>()
+            .init_resource::<SfxQueue>()
+            .add_observer(projectile_sfx_observer)
+            .add_observer(sword_sfx_observer)
+            .add_observer(damage_sfx_observer)
+            .add_observer(death_sfx_observer)
+            .add_observer(purchase_sfx_observer)
+            .add_observer(round_clear_sfx_observer)
+            .add_systems(Startup, setup_audio)
+            .add_systems(
+                Update,
+                (round_start_sfx, low_health_sfx).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(PostUpdate, (play_queued_sfx, update_music_volume));
+    }
+}
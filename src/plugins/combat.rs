@@ -1,10 +1,20 @@
+use crate::systems::combat::magic::{
+    arcane_turret::arcane_turret_system,
+    chain_lightning::chain_lightning_system,
+    frost_ring::{expand_frost_ring_system, frost_slow_observer},
+    mana_regen_system,
+    meteor::meteor_impact_system,
+};
 use crate::systems::combat::{
     cleanup_pending_despawn, collision_detection_system, damage_processing_system,
     elite_ai::elite_ai_system, enemy_death_system, gun_weapon_system, handle_player_collision,
-    magic_weapon_system, manage_lifetime, projectile_effect_system, shuriken_weapon_system,
-    sword_weapon_system, update_enemy_grid, update_sword_mechanics, yellow_ai::yellow_ai_system,
+    magic_weapon_system, manage_lifetime, player_hit_observer, projectile_effect_system,
+    relic_damage_observer, relic_death_observer, relic_teleport_observer, shuriken_weapon_system,
+    sword_weapon_system, tick_relic_triggers, update_enemy_grid, update_sword_mechanics,
+    yellow_ai::yellow_ai_system,
 };
 use crate::systems::enemy::{enemy_chase_player, spawn_waves};
+use crate::systems::pickup::{pickup_blink_system, pickup_system, tick_power_ups};
 
 use bevy::prelude::*;
 
@@ -14,8 +24,13 @@ impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(enemy_death_system)
             .add_observer(damage_processing_system)
+            .add_observer(player_hit_observer)
             .add_observer(projectile_effect_system)
             .add_observer(crate::systems::combat::magic::force_logic::force_effect_observer)
+            .add_observer(frost_slow_observer)
+            .add_observer(relic_death_observer)
+            .add_observer(relic_damage_observer)
+            .add_observer(relic_teleport_observer)
             .add_systems(
                 Update,
                 (
@@ -41,6 +56,27 @@ impl Plugin for CombatPlugin {
                     cleanup_pending_despawn,
                 )
                     .run_if(in_state(crate::resources::game_state::GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    meteor_impact_system,
+                    expand_frost_ring_system,
+                    chain_lightning_system,
+                    arcane_turret_system,
+                    mana_regen_system,
+                )
+                    .run_if(in_state(crate::resources::game_state::GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    pickup_system,
+                    pickup_blink_system,
+                    tick_power_ups,
+                    tick_relic_triggers,
+                )
+                    .run_if(in_state(crate::resources::game_state::GameState::Playing)),
             );
     }
 }
@@ -0,0 +1,40 @@
+use crate::resources::game_state::GameState;
+use crate::systems::dev_console::{
+    DevConsole, apply_god_mode, block_game_keys, dev_console_input, draw_collider_gizmos,
+    draw_grid_gizmos, run_dev_commands, spawn_dev_console, update_dev_console_ui,
+    update_dev_overlay,
+};
+use bevy::diagnostic::{EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+/// Developer console and debug overlay, added only with the `dev_console` feature
+pub struct DevConsolePlugin;
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            FrameTimeDiagnosticsPlugin::default(),
+            EntityCountDiagnosticsPlugin::default(),
+        ))
+        .init_resource::<DevConsole>()
+        .add_systems(Startup, spawn_dev_console)
+        .add_systems(
+            PreUpdate,
+            (dev_console_input, block_game_keys)
+                .chain()
+                .after(bevy::input::InputSystems)
+                .before(crate::systems::input::update_virtual_input),
+        )
+        .add_systems(
+            Update,
+            (
+                run_dev_commands,
+                update_dev_console_ui,
+                update_dev_overlay,
+                draw_collider_gizmos,
+                draw_grid_gizmos,
+            ),
+        )
+        .add_systems(Update, apply_god_mode.run_if(in_state(GameState::Playing)));
+    }
+}
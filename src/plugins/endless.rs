@@ -0,0 +1,23 @@
+use crate::resources::endless::EndlessDirector;
+use crate::resources::game_state::GameState;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::endless::{
+    collect_shop_drones, move_shop_drones, reset_endless_director, spawn_shop_drones,
+};
+use bevy::prelude::*;
+
+pub struct EndlessPlugin;
+
+impl Plugin for EndlessPlugin {
+    fn build(&self, app: &mut App) {
+        // The director itself is driven from `spawn_waves`, shared with round mode
+        app.init_resource::<EndlessDirector>()
+            .add_observer(reset_endless_director)
+            .add_systems(
+                Update,
+                (spawn_shop_drones, move_shop_drones, collect_shop_drones)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(|settings: Res<RunSettings>| settings.is_endless()),
+            );
+    }
+}
@@ -0,0 +1,24 @@
+use crate::resources::game_state::GameState;
+use crate::resources::perks::{PerkOffers, PerkSelection};
+use crate::systems::experience::{
+    apply_perk_choice, gain_experience, open_level_up, perk_damage_observer, perk_kill_observer,
+    reset_experience, roll_perk_offers,
+};
+use bevy::prelude::*;
+
+pub struct ExperiencePlugin;
+
+impl Plugin for ExperiencePlugin {
+    fn build(&self, app: &mut App) {
+        // The draft screen itself lives in `UiPlugin`
+        app.init_resource::<PerkOffers>()
+            .init_resource::<PerkSelection>()
+            .add_observer(reset_experience)
+            .add_observer(gain_experience)
+            .add_observer(apply_perk_choice)
+            .add_observer(perk_kill_observer)
+            .add_observer(perk_damage_observer)
+            .add_systems(OnEnter(GameState::LevelUp), roll_perk_offers)
+            .add_systems(Update, open_level_up.run_if(in_state(GameState::Playing)));
+    }
+}
@@ -0,0 +1,15 @@
+use crate::resources::locale::{Locale, StringTable, StringTableLoader};
+use crate::systems::locale::apply_string_tables;
+use bevy::prelude::*;
+
+/// String table assets and the `Locale` resource serving them
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<StringTable>()
+            .init_asset_loader::<StringTableLoader>()
+            .init_resource::<Locale>()
+            .add_systems(Update, apply_string_tables);
+    }
+}
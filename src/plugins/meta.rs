@@ -0,0 +1,33 @@
+use crate::resources::game_state::GameState;
+use crate::resources::leaderboard::Leaderboard;
+use crate::resources::profile::MetaProfile;
+use crate::resources::run_seed::RunSeed;
+use crate::systems::leaderboard::record_high_score;
+use crate::systems::meta::{apply_meta_bonuses_on_startup, award_meta_shards};
+use crate::systems::run_stats::{
+    finish_run_stats, record_damage_stats, record_kill_stats, record_purchase_stats,
+    record_run_start, tick_run_stats,
+};
+use crate::systems::ui::spawn_game_over_menu;
+use bevy::prelude::*;
+
+pub struct MetaPlugin;
+
+impl Plugin for MetaPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MetaProfile::load())
+            .insert_resource(Leaderboard::load())
+            .init_resource::<RunSeed>()
+            .add_observer(record_run_start)
+            .add_observer(record_damage_stats)
+            .add_observer(record_kill_stats)
+            .add_observer(record_purchase_stats)
+            .add_systems(PostStartup, apply_meta_bonuses_on_startup)
+            .add_systems(Update, tick_run_stats.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (award_meta_shards, finish_run_stats, record_high_score)
+                    .before(spawn_game_over_menu),
+            );
+    }
+}
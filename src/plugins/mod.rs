@@ -1,4 +1,13 @@
+pub mod achievements;
+pub mod audio;
 pub mod combat;
+#[cfg(feature = "dev_console")]
+pub mod dev_console;
+pub mod endless;
+pub mod experience;
+pub mod locale;
+pub mod meta;
+pub mod pause;
 pub mod physics;
 pub mod player;
 pub mod status;
@@ -0,0 +1,18 @@
+use crate::resources::game_state::GameState;
+use crate::systems::pause::sync_game_time;
+use bevy::prelude::*;
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        // The pause overlay itself lives in `UiPlugin`
+        app.add_systems(Update, sync_game_time.run_if(state_changed::<GameState>));
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            Update,
+            crate::systems::pause::auto_pause_on_blur.run_if(in_state(GameState::Playing)),
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::resources::game_state::GameState;
-use crate::systems::player::{aim_player, move_player, spawn_player};
+use crate::systems::player::{aim_player, dash_system, move_player, spawn_player};
 use bevy::prelude::*;
 
 pub struct PlayerPlugin;
@@ -21,6 +21,13 @@ impl Plugin for PlayerPlugin {
                     .run_if(in_state(GameState::Playing))
                     .before(crate::systems::status::tick_status_system),
             )
+            .add_systems(
+                Update,
+                dash_system
+                    .run_if(in_state(GameState::Playing))
+                    .after(move_player)
+                    .after(crate::systems::status::tick_status_system),
+            )
             .add_systems(PostUpdate, aim_player.run_if(in_state(GameState::Playing)));
     }
 }
@@ -1,39 +1,64 @@
 use crate::resources::game_state::GameState;
 
 use crate::systems::ui::{
-    ActiveDescriptionSide, RebindState, SelectedShopCard, despawn_game_over_menu, despawn_hud,
-    despawn_settings_menu, despawn_tutorial_ui, despawn_weapon_menu, handle_card_selection,
-    handle_menu_toggle, handle_purchases, handle_rebind_clicks, rebind_system,
-    setup_buy_button_observer, spawn_game_over_menu, spawn_hud, spawn_settings_menu,
-    spawn_tutorial_ui, spawn_weapon_menu, update_cooldown_indicators, update_gold_ui,
-    update_health_ui, update_hud_indicators, update_hud_magic_ui, update_menu_cdr_text,
-    update_menu_crit_text, update_menu_damage_text, update_menu_gold_text, update_menu_health_text,
+    ActiveDescriptionSide, PresetDraft, RebindState, SelectedShopCard, ShopOffers,
+    despawn_game_over_menu, despawn_hud, despawn_level_up_screen, despawn_pause_menu,
+    despawn_settings_menu, despawn_tutorial_ui, despawn_weapon_menu, edit_preset_name,
+    handle_card_selection, handle_menu_toggle, handle_purchases, handle_rebind_clicks,
+    install_ui_font, navigate_perks, rebind_system, refresh_achievements_ui, refresh_leaderboard_ui,
+    refresh_perk_cards, refresh_preset_list, refresh_run_setup_ui, refresh_shop_offers_ui,
+    refresh_unlocks_ui, roll_shop_offers, setup_buy_button_observer, spawn_achievement_toast,
+    spawn_game_over_menu, spawn_hud, spawn_level_up_screen, spawn_pause_menu, spawn_settings_menu,
+    spawn_toast_stack, spawn_tutorial_ui, spawn_weapon_menu, update_accessibility_settings_ui,
+    update_achievement_summary_text, update_achievement_toasts, update_cooldown_indicators,
+    update_dash_cooldown_ui, update_gold_ui, update_health_ui, update_hud_indicators,
+    update_hud_magic_ui, update_mana_ui, update_menu_cdr_text, update_menu_crit_text,
+    update_menu_damage_text, update_menu_gold_text, update_menu_health_text,
     update_menu_lifesteal_text, update_menu_magic_ui, update_menu_weapon_buttons,
-    update_menu_weapon_details_ui, update_rebind_ui, update_round_text, update_shop_cards_ui,
-    update_shuriken_count_ui, update_sensitivity_ui, update_ui_scale,
+    update_menu_weapon_details_ui, update_minimap, update_perk_highlight, update_power_up_ui,
+    update_preset_name_field, update_rebind_ui, update_relic_strip, update_round_text,
+    update_run_setup_summary_text, update_sensitivity_ui, update_setting_toggles,
+    update_shop_cards_ui, update_shop_reroll_text, update_shop_stat_preview,
+    update_shuriken_count_ui, update_spell_palette_locks, update_ui_scale,
+    update_unlock_shards_text, update_volume_settings_ui, update_xp_ui,
 };
 use bevy::prelude::*;
 
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
+    #[allow(clippy::too_many_lines)]
     fn build(&self, app: &mut App) {
         app.init_resource::<SelectedShopCard>()
+            .init_resource::<ShopOffers>()
             .init_resource::<RebindState>()
             .init_resource::<ActiveDescriptionSide>()
+            .init_resource::<PresetDraft>()
             .init_resource::<crate::systems::ui::menu::components::SelectedSpell>()
             .add_observer(handle_card_selection)
             .add_observer(handle_purchases)
+            .add_observer(spawn_achievement_toast)
             // Settings
             .add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
             .add_systems(OnExit(GameState::Settings), despawn_settings_menu)
             .add_systems(
                 Update,
-                (handle_rebind_clicks, rebind_system, update_rebind_ui, update_sensitivity_ui)
+                (
+                    handle_rebind_clicks,
+                    rebind_system,
+                    update_rebind_ui,
+                    update_sensitivity_ui,
+                    update_setting_toggles,
+                    update_accessibility_settings_ui,
+                    update_volume_settings_ui,
+                )
                     .run_if(in_state(GameState::Settings)),
             )
             // Main Menu / Weapon Menu
-            .add_systems(OnEnter(GameState::WeaponMenu), spawn_weapon_menu)
+            .add_systems(
+                OnEnter(GameState::WeaponMenu),
+                (roll_shop_offers, spawn_weapon_menu).chain(),
+            )
             .add_systems(OnExit(GameState::WeaponMenu), despawn_weapon_menu)
             // HUD (Playing)
             .add_systems(OnEnter(GameState::Playing), spawn_hud)
@@ -41,9 +66,20 @@ impl Plugin for UiPlugin {
             // Tutorial
             .add_systems(OnEnter(GameState::Tutorial), spawn_tutorial_ui)
             .add_systems(OnExit(GameState::Tutorial), despawn_tutorial_ui)
+            // Pause
+            .add_systems(OnEnter(GameState::Paused), spawn_pause_menu)
+            .add_systems(OnExit(GameState::Paused), despawn_pause_menu)
             // Game Over
             .add_systems(OnEnter(GameState::GameOver), spawn_game_over_menu)
             .add_systems(OnExit(GameState::GameOver), despawn_game_over_menu)
+            // Level-up perk draft
+            .add_systems(OnEnter(GameState::LevelUp), spawn_level_up_screen)
+            .add_systems(OnExit(GameState::LevelUp), despawn_level_up_screen)
+            .add_systems(
+                Update,
+                (refresh_perk_cards, update_perk_highlight, navigate_perks)
+                    .run_if(in_state(GameState::LevelUp)),
+            )
             // Update Systems
             .add_systems(
                 Update,
@@ -52,13 +88,23 @@ impl Plugin for UiPlugin {
                     update_hud_magic_ui,
                     update_shuriken_count_ui,
                     update_health_ui,
+                    update_mana_ui,
+                    update_xp_ui,
+                    update_power_up_ui,
+                    update_relic_strip,
                     update_gold_ui,
                     update_round_text,
+                    update_minimap,
                     update_cooldown_indicators,
-                    handle_menu_toggle,
+                    update_dash_cooldown_ui,
                 )
                     .run_if(in_state(GameState::Playing)),
             )
+            .add_systems(
+                Update,
+                handle_menu_toggle
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+            )
             .add_systems(
                 Update,
                 (
@@ -80,10 +126,33 @@ impl Plugin for UiPlugin {
                     update_menu_lifesteal_text,
                     update_menu_cdr_text,
                     update_shop_cards_ui,
+                    refresh_shop_offers_ui,
+                    update_shop_reroll_text,
+                    update_shop_stat_preview,
                     setup_buy_button_observer,
+                    refresh_preset_list,
+                    edit_preset_name,
+                    update_preset_name_field,
+                )
+                    .run_if(in_state(GameState::WeaponMenu)),
+            )
+            .add_systems(
+                Update,
+                (
+                    refresh_unlocks_ui,
+                    update_unlock_shards_text,
+                    update_spell_palette_locks,
+                    refresh_run_setup_ui,
+                    update_run_setup_summary_text,
+                    refresh_achievements_ui,
+                    update_achievement_summary_text,
+                    refresh_leaderboard_ui,
                 )
                     .run_if(in_state(GameState::WeaponMenu)),
             )
-            .add_systems(Update, update_ui_scale);
+            .add_systems(Startup, install_ui_font)
+            // Achievement toasts show over every screen
+            .add_systems(Startup, spawn_toast_stack)
+            .add_systems(Update, (update_ui_scale, update_achievement_toasts));
     }
 }
@@ -1,17 +1,39 @@
 use crate::resources::game_state::GameState;
-use crate::resources::polish::{spawn_trails, update_camera_shake};
-use crate::systems::damage_text::{spawn_damage_text, update_damage_text};
+use crate::resources::polish::{player_iframe_flash, spawn_trails, update_camera_shake};
+use crate::systems::accessibility::{apply_palette, attach_enemy_glyph, update_enemy_glyphs};
+use crate::systems::damage_text::{DamageTextPool, spawn_damage_text, update_damage_text};
+use crate::systems::health_bars::{attach_health_bar, update_health_bars};
+use crate::systems::indicators::{
+    despawn_offscreen_indicators, sync_offscreen_indicators, update_offscreen_indicators,
+};
 use bevy::prelude::*;
 
 pub struct VisualsPlugin;
 
 impl Plugin for VisualsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(spawn_damage_text)
+        app.init_resource::<DamageTextPool>()
+            .add_observer(spawn_damage_text)
+            .add_observer(attach_enemy_glyph)
+            .add_observer(attach_health_bar)
+            .add_systems(Update, (apply_palette, update_enemy_glyphs))
             .add_systems(
                 Update,
-                (spawn_trails, update_damage_text).run_if(in_state(GameState::Playing)),
+                (
+                    spawn_trails,
+                    update_damage_text,
+                    update_health_bars,
+                    player_iframe_flash,
+                )
+                    .run_if(in_state(GameState::Playing)),
             )
+            .add_systems(
+                Update,
+                (sync_offscreen_indicators, update_offscreen_indicators)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), despawn_offscreen_indicators)
             .add_systems(
                 PostUpdate,
                 update_camera_shake.run_if(in_state(GameState::Playing)),
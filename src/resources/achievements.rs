@@ -0,0 +1,247 @@
+//! Achievements: persistent progress and the run-scoped tracking that feeds it.
+//! Requirements are declared per achievement in `configs::achievements`; gameplay hooks
+//! translate combat events into `AchievementSignal`s and `AchievementProgress::apply` does the rest.
+
+use crate::components::weapon::WeaponType;
+use crate::configs::achievements::{
+    ACHIEVEMENT_LIST, ACHIEVEMENTS_SAVE_NAME, get_achievement_config,
+};
+use crate::utils::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Achievement {
+    FirstBlood,
+    Exterminator,
+    BladeMaster,
+    GravityWell,
+    Deadeye,
+    Untouchable,
+    Veteran,
+    Shopaholic,
+    Collector,
+}
+
+/// Condition an achievement waits for, declared in its config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    /// Enemies killed across every run
+    LifetimeKills(u32),
+    /// Enemies killed by a single Force Pull
+    PullKills(u32),
+    /// Clear this round; with `weapon` set, no other weapon may have dealt damage this run
+    ClearRound {
+        round: u32,
+        weapon: Option<WeaponType>,
+    },
+    /// Clear a round without taking damage
+    FlawlessRound,
+    /// Crit chance in percent
+    CritChance(u32),
+    /// Shop cards bought across every run
+    CardsBought(u32),
+    /// Relics owned at the same time
+    RelicsOwned(u32),
+}
+
+/// Gameplay facts reported by the achievement hooks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementSignal {
+    Kill,
+    /// Kills credited to one Force Pull so far
+    PullKills(u32),
+    RoundCleared {
+        round: u32,
+        flawless: bool,
+        /// The only weapon that dealt damage this run, if there was exactly one
+        only_weapon: Option<WeaponType>,
+    },
+    CritChance(f32),
+    CardBought,
+    RelicsOwned(u32),
+}
+
+impl Requirement {
+    pub const fn goal(self) -> u32 {
+        match self {
+            Self::LifetimeKills(goal)
+            | Self::PullKills(goal)
+            | Self::CritChance(goal)
+            | Self::CardsBought(goal)
+            | Self::RelicsOwned(goal) => goal,
+            Self::ClearRound { round, .. } => round,
+            Self::FlawlessRound => 1,
+        }
+    }
+
+    /// Progress after `signal`, or `None` if the signal does not concern this requirement
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn advance(self, progress: u32, signal: AchievementSignal) -> Option<u32> {
+        match (self, signal) {
+            (Self::LifetimeKills(_), AchievementSignal::Kill)
+            | (Self::CardsBought(_), AchievementSignal::CardBought) => Some(progress + 1),
+            (Self::PullKills(_), AchievementSignal::PullKills(count))
+            | (Self::RelicsOwned(_), AchievementSignal::RelicsOwned(count)) => {
+                Some(progress.max(count))
+            }
+            (
+                Self::ClearRound { weapon, .. },
+                AchievementSignal::RoundCleared {
+                    round, only_weapon, ..
+                },
+            ) => (weapon.is_none() || weapon == only_weapon).then(|| progress.max(round)),
+            (Self::FlawlessRound, AchievementSignal::RoundCleared { flawless: true, .. }) => {
+                Some(1)
+            }
+            (Self::CritChance(_), AchievementSignal::CritChance(chance)) => {
+                Some(progress.max((chance * 100.0).round() as u32))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Persistent achievement progress, saved through `utils::storage`
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementProgress {
+    pub progress: HashMap<Achievement, u32>,
+    pub unlocked: Vec<Achievement>,
+}
+
+impl AchievementProgress {
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    pub fn progress(&self, achievement: Achievement) -> u32 {
+        self.progress.get(&achievement).copied().unwrap_or(0)
+    }
+
+    /// Advances every locked achievement the signal concerns and returns the newly unlocked ones.
+    pub fn apply(&mut self, signal: AchievementSignal) -> Vec<Achievement> {
+        let mut unlocked = Vec::new();
+        for achievement in ACHIEVEMENT_LIST {
+            if self.is_unlocked(achievement) {
+                continue;
+            }
+            let requirement = get_achievement_config(achievement).requirement;
+            let Some(progress) = requirement.advance(self.progress(achievement), signal) else {
+                continue;
+            };
+            let progress = progress.min(requirement.goal());
+            self.progress.insert(achievement, progress);
+            if progress >= requirement.goal() {
+                self.unlocked.push(achievement);
+                unlocked.push(achievement);
+            }
+        }
+        unlocked
+    }
+
+    pub fn load() -> Self {
+        storage::read(ACHIEVEMENTS_SAVE_NAME)
+            .and_then(|json| match serde_json::from_str(&json) {
+                Ok(progress) => Some(progress),
+                Err(err) => {
+                    warn!("Discarding unreadable achievements: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => {
+                storage::write(ACHIEVEMENTS_SAVE_NAME, &json);
+            }
+            Err(err) => warn!("Failed to serialize achievements: {err}"),
+        }
+    }
+}
+
+/// Run-scoped bookkeeping behind the signals; reset on new game
+#[derive(Resource, Debug, Default)]
+pub struct AchievementTracker {
+    /// Weapons that dealt damage from a hand this run
+    pub weapons_used: Vec<WeaponType>,
+    pub hurt_this_round: bool,
+    /// Enemy -> Force Pull projectile that last hit it
+    pub pull_marks: HashMap<Entity, Entity>,
+    /// Force Pull projectile -> kills credited to it
+    pub pull_kills: HashMap<Entity, u32>,
+}
+
+impl AchievementTracker {
+    pub fn only_weapon(&self) -> Option<WeaponType> {
+        match self.weapons_used.as_slice() {
+            [weapon] => Some(*weapon),
+            _ => None,
+        }
+    }
+
+    /// Starts a fresh round: no damage taken yet, no pending Force Pull credit
+    pub fn next_round(&mut self) {
+        self.hurt_this_round = false;
+        self.pull_marks.clear();
+        self.pull_kills.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signals_unlock_matching_achievements_once() {
+        let mut progress = AchievementProgress::default();
+
+        assert_eq!(
+            progress.apply(AchievementSignal::Kill),
+            vec![Achievement::FirstBlood]
+        );
+        assert_eq!(progress.progress(Achievement::Exterminator), 1);
+        assert!(progress.apply(AchievementSignal::Kill).is_empty());
+
+        // Using a second weapon rules out the Sword-only clear
+        let unlocked = progress.apply(AchievementSignal::RoundCleared {
+            round: 10,
+            flawless: true,
+            only_weapon: None,
+        });
+        assert!(unlocked.contains(&Achievement::Untouchable));
+        assert!(!unlocked.contains(&Achievement::BladeMaster));
+
+        let unlocked = progress.apply(AchievementSignal::RoundCleared {
+            round: 10,
+            flawless: false,
+            only_weapon: Some(WeaponType::Sword),
+        });
+        assert_eq!(unlocked, vec![Achievement::BladeMaster]);
+
+        assert!(progress.apply(AchievementSignal::PullKills(4)).is_empty());
+        assert_eq!(
+            progress.apply(AchievementSignal::PullKills(5)),
+            vec![Achievement::GravityWell]
+        );
+        assert_eq!(
+            progress.apply(AchievementSignal::CritChance(1.0)),
+            vec![Achievement::Deadeye]
+        );
+    }
+
+    #[test]
+    fn test_progress_roundtrip() {
+        let mut progress = AchievementProgress::default();
+        progress.apply(AchievementSignal::CardBought);
+        progress.apply(AchievementSignal::Kill);
+
+        let json = serde_json::to_string(&progress).unwrap();
+        let loaded: AchievementProgress = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.progress(Achievement::Shopaholic), 1);
+        assert!(loaded.is_unlocked(Achievement::FirstBlood));
+    }
+}
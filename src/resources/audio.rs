@@ -0,0 +1,383 @@
+//! Procedurally synthesized sound effects and music, played through `bevy_audio`
+
+use crate::components::weapon::WeaponType;
+use crate::configs::audio::{
+    MAX_SFX_VOICES, MAX_VOICES_PER_SFX, MUSIC_ARPEGGIO, MUSIC_BASS_FADE, MUSIC_BPM, MUSIC_CHORDS,
+    MUSIC_GAIN, MUSIC_NOTE_DECAY, SAMPLE_RATE, sfx_recipe,
+};
+use bevy::audio::{Decodable, Source};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sfx {
+    ShurikenThrow,
+    GunShot,
+    SpellCast,
+    SwordSwing,
+    Hit,
+    Crit,
+    EnemyDeath,
+    Purchase,
+    RoundStart,
+    RoundClear,
+    LowHealth,
+}
+
+impl Sfx {
+    pub const ALL: [Self; 11] = [
+        Self::ShurikenThrow,
+        Self::GunShot,
+        Self::SpellCast,
+        Self::SwordSwing,
+        Self::Hit,
+        Self::Crit,
+        Self::EnemyDeath,
+        Self::Purchase,
+        Self::RoundStart,
+        Self::RoundClear,
+        Self::LowHealth,
+    ];
+
+    /// Effect played when a weapon of this type attacks
+    pub const fn fire(kind: WeaponType) -> Self {
+        match kind {
+            WeaponType::Shuriken => Self::ShurikenThrow,
+            WeaponType::Sword => Self::SwordSwing,
+            WeaponType::Gun => Self::GunShot,
+            WeaponType::Magic => Self::SpellCast,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Triangle,
+    Square,
+    Saw,
+    /// White noise low-passed at the oscillator frequency
+    Noise,
+}
+
+impl Waveform {
+    /// Tone at `phase` in [0, 1); noise is handled by the decoder
+    fn tone(self, phase: f32) -> f32 {
+        match self {
+            Self::Sine => (phase * TAU).sin(),
+            Self::Triangle => 4.0f32.mul_add((phase - 0.5).abs(), -1.0),
+            Self::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Self::Saw => 2.0f32.mul_add(phase, -1.0),
+            Self::Noise => 0.0,
+        }
+    }
+}
+
+/// One sound effect: an oscillator swept from `start_hz` to `end_hz`, split into
+/// `notes` (frequency multipliers) that each get their own attack and decay
+#[derive(Asset, TypePath, Debug, Clone, Copy)]
+pub struct SynthSound {
+    pub wave: Waveform,
+    pub start_hz: f32,
+    pub end_hz: f32,
+    /// Seconds
+    pub duration: f32,
+    /// Seconds to full volume at the start of each note
+    pub attack: f32,
+    pub gain: f32,
+    /// Empty for a single sweep
+    pub notes: &'static [f32],
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder {
+            sound: *self,
+            index: 0,
+            total: (self.duration * SAMPLE_RATE as f32) as u32,
+            phase: 0.0,
+            filtered: 0.0,
+            noise: 0x9E37_79B9,
+        }
+    }
+}
+
+pub struct SynthDecoder {
+    sound: SynthSound,
+    index: u32,
+    total: u32,
+    phase: f32,
+    /// Low-pass state of `Waveform::Noise`
+    filtered: f32,
+    noise: u32,
+}
+
+impl SynthDecoder {
+    /// Xorshift white noise in [-1, 1]
+    #[allow(clippy::cast_precision_loss)]
+    fn white_noise(&mut self) -> f32 {
+        self.noise ^= self.noise << 13;
+        self.noise ^= self.noise >> 17;
+        self.noise ^= self.noise << 5;
+        (self.noise as f32 / u32::MAX as f32).mul_add(2.0, -1.0)
+    }
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.total {
+            return None;
+        }
+        let sound = self.sound;
+        let rate = SAMPLE_RATE as f32;
+        let t = self.index as f32 / rate;
+        self.index += 1;
+
+        let segments = sound.notes.len().max(1);
+        let segment_len = sound.duration / segments as f32;
+        let segment = ((t / segment_len) as usize).min(segments - 1);
+        let local = (segment as f32).mul_add(-segment_len, t);
+        let step = sound.notes.get(segment).copied().unwrap_or(1.0);
+
+        let sweep = (sound.end_hz / sound.start_hz).powf(t / sound.duration);
+        let frequency = sound.start_hz * sweep * step;
+        self.phase = (self.phase + frequency / rate).fract();
+
+        let raw = if sound.wave == Waveform::Noise {
+            let white = self.white_noise();
+            let alpha = (TAU * frequency / rate).min(1.0);
+            self.filtered += alpha * (white - self.filtered);
+            self.filtered
+        } else {
+            sound.wave.tone(self.phase)
+        };
+
+        let attack = if sound.attack > 0.0 {
+            (local / sound.attack).min(1.0)
+        } else {
+            1.0
+        };
+        let decay = (1.0 - local / segment_len).max(0.0).powi(2);
+        Some(raw * attack * decay * sound.gain)
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.sound.duration))
+    }
+}
+
+/// Background track. The decoder loops on its own and never ends, so it plays with
+/// `PlaybackSettings::ONCE` instead of `LOOP`, which would buffer every sample
+#[derive(Asset, TypePath, Debug, Clone, Copy, Default)]
+pub struct MusicLoop;
+
+impl Decodable for MusicLoop {
+    type DecoderItem = f32;
+    type Decoder = MusicDecoder;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn decoder(&self) -> Self::Decoder {
+        let loop_seconds = bar_seconds() * MUSIC_CHORDS.len() as f32;
+        MusicDecoder {
+            index: 0,
+            loop_len: (loop_seconds * SAMPLE_RATE as f32) as u32,
+        }
+    }
+}
+
+/// Seconds per eighth note
+fn eighth_seconds() -> f32 {
+    30.0 / MUSIC_BPM
+}
+
+/// Seconds per chord; the arpeggio fills one bar
+#[allow(clippy::cast_precision_loss)]
+fn bar_seconds() -> f32 {
+    eighth_seconds() * MUSIC_ARPEGGIO.len() as f32
+}
+
+pub struct MusicDecoder {
+    index: u32,
+    loop_len: u32,
+}
+
+impl Iterator for MusicDecoder {
+    type Item = f32;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn next(&mut self) -> Option<f32> {
+        let t = self.index as f32 / SAMPLE_RATE as f32;
+        self.index = (self.index + 1) % self.loop_len;
+
+        let eighth = eighth_seconds();
+        let step = (t / eighth) as usize;
+        let bar = step / MUSIC_ARPEGGIO.len();
+        let chord = MUSIC_CHORDS[bar % MUSIC_CHORDS.len()];
+
+        let note_time = (step as f32).mul_add(-eighth, t);
+        let note = chord[MUSIC_ARPEGGIO[step % MUSIC_ARPEGGIO.len()]];
+        let arpeggio = Waveform::Triangle.tone((note_time * note * 2.0).fract())
+            * (-note_time * MUSIC_NOTE_DECAY).exp();
+
+        let bar_len = bar_seconds();
+        let bar_time = (bar as f32).mul_add(-bar_len, t);
+        let fade = (bar_time / MUSIC_BASS_FADE)
+            .min((bar_len - bar_time) / MUSIC_BASS_FADE)
+            .clamp(0.0, 1.0);
+        let bass = Waveform::Sine.tone((bar_time * chord[0] * 0.5).fract()) * fade;
+
+        Some(arpeggio.mul_add(0.6, bass * 0.4) * MUSIC_GAIN)
+    }
+}
+
+impl Source for MusicDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Handles of every synthesized sound, built once at startup
+#[derive(Resource)]
+pub struct SoundBank {
+    sounds: HashMap<Sfx, Handle<SynthSound>>,
+    pub music: Handle<MusicLoop>,
+}
+
+impl SoundBank {
+    pub fn new(sounds: &mut Assets<SynthSound>, music: &mut Assets<MusicLoop>) -> Self {
+        Self {
+            sounds: Sfx::ALL
+                .into_iter()
+                .map(|sfx| (sfx, sounds.add(sfx_recipe(sfx))))
+                .collect(),
+            music: music.add(MusicLoop),
+        }
+    }
+
+    pub fn get(&self, sfx: Sfx) -> Option<Handle<SynthSound>> {
+        self.sounds.get(&sfx).cloned()
+    }
+}
+
+/// Effects requested this frame; `play_queued_sfx` drains it and applies the polyphony cap
+#[derive(Resource, Default)]
+pub struct SfxQueue {
+    pending: Vec<Sfx>,
+}
+
+impl SfxQueue {
+    pub fn play(&mut self, sfx: Sfx) {
+        if !self.pending.contains(&sfx) {
+            self.pending.push(sfx);
+        }
+    }
+
+    pub fn drain(&mut self) -> std::vec::Drain<'_, Sfx> {
+        self.pending.drain(..)
+    }
+}
+
+/// Whether another voice may start, given how many of this effect and in total are playing
+pub const fn voice_allowed(same_sfx: usize, total: usize) -> bool {
+    same_sfx < MAX_VOICES_PER_SFX && total < MAX_SFX_VOICES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_sfx_decoders_are_finite_and_bounded() {
+        for sfx in Sfx::ALL {
+            let sound = sfx_recipe(sfx);
+            let samples: Vec<f32> = sound.decoder().collect();
+            let expected = sound.duration * SAMPLE_RATE as f32;
+            assert!((samples.len() as f32 - expected).abs() <= 1.0, "{sfx:?}");
+            assert!(samples.iter().all(|s| s.abs() <= 1.0), "{sfx:?}");
+            assert!(samples[0].abs() < 0.05, "{sfx:?} starts with a click");
+            assert!(
+                samples[samples.len() - 1].abs() < 0.05,
+                "{sfx:?} ends with a click"
+            );
+        }
+    }
+
+    #[test]
+    fn test_music_loops_without_ending() {
+        let mut decoder = MusicLoop.decoder();
+        let loop_len = decoder.loop_len as usize;
+        let first: Vec<f32> = decoder.by_ref().take(64).collect();
+        let again: Vec<f32> = decoder.skip(loop_len - 64).take(64).collect();
+        assert_eq!(first, again);
+    }
+
+    #[test]
+    fn test_rapid_fire_is_capped() {
+        let mut queue = SfxQueue::default();
+        for _ in 0..10 {
+            queue.play(Sfx::GunShot);
+        }
+        assert_eq!(queue.drain().count(), 1);
+        assert!(voice_allowed(MAX_VOICES_PER_SFX - 1, 0));
+        assert!(!voice_allowed(MAX_VOICES_PER_SFX, 0));
+        assert!(!voice_allowed(0, MAX_SFX_VOICES));
+    }
+}
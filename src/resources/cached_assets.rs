@@ -3,6 +3,11 @@
 
 use bevy::prelude::*;
 
+use crate::components::enemy::EnemyKind;
+use crate::configs::accessibility::{GLYPH_COLOR, enemy_color, indicator_color};
+use crate::configs::health_bars::{BAR_BACK_COLOR, BAR_FILL_COLOR};
+use crate::resources::game_settings::ColorPalette;
+
 /// Resource containing pre-cached mesh and material handles
 #[derive(Resource)]
 #[allow(dead_code)]
@@ -46,10 +51,36 @@ pub struct CachedAssets {
     pub mat_bolt_glow: Handle<ColorMaterial>,     // Energy Bolt Glow
     pub mat_blue_50: Handle<ColorMaterial>,       // Force Pull Spiral
     pub mat_blue_dark: Handle<ColorMaterial>,     // Force Pull Void
+    pub mat_frost_20: Handle<ColorMaterial>,      // Frost Ring Body
+    pub mat_frost_70: Handle<ColorMaterial>,      // Frost Ring Shards
+    pub mat_telegraph_20: Handle<ColorMaterial>,  // Meteor Warning Area
+    pub mat_telegraph_50: Handle<ColorMaterial>,  // Meteor Warning Fill
+    pub mat_lava_90: Handle<ColorMaterial>,       // Meteor Impact Core
+    pub mat_lightning_glow: Handle<ColorMaterial>, // Chain Lightning Glow
+    pub mat_pickup_gold: Handle<ColorMaterial>,
+    pub mat_pickup_health: Handle<ColorMaterial>,
+    pub mat_pickup_haste: Handle<ColorMaterial>,
+    pub mat_pickup_magnet: Handle<ColorMaterial>,
+    pub mat_pickup_relic: Handle<ColorMaterial>,
+    pub mat_shop_drone: Handle<ColorMaterial>,
+    pub mat_indicator_elite: Handle<ColorMaterial>,
+    pub mat_indicator_yellow: Handle<ColorMaterial>,
+
+    // Enemy bodies, recoloured in place when the palette changes
+    pub mat_enemy_grunt: Handle<ColorMaterial>,
+    pub mat_enemy_elite: Handle<ColorMaterial>,
+    pub mat_enemy_yellow: Handle<ColorMaterial>,
+    pub mat_enemy_glyph: Handle<ColorMaterial>,
+    pub mat_health_bar_back: Handle<ColorMaterial>,
+    pub mat_health_bar_fill: Handle<ColorMaterial>,
 }
 
 impl CachedAssets {
-    pub fn new(meshes: &mut Assets<Mesh>, materials: &mut Assets<ColorMaterial>) -> Self {
+    pub fn new(
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<ColorMaterial>,
+        palette: ColorPalette,
+    ) -> Self {
         Self {
             // Circle meshes
             // Unit meshes
@@ -90,6 +121,51 @@ impl CachedAssets {
             mat_bolt_glow: materials.add(Color::srgba(0.7, 0.0, 1.0, 0.4)),    // Purple Glow
             mat_blue_50: materials.add(Color::srgba(0.4, 0.4, 1.0, 0.5)),      // Blue/Indigo
             mat_blue_dark: materials.add(Color::srgba(0.08, 0.08, 0.24, 1.0)), // Dark Blue Void
+            mat_frost_20: materials.add(Color::srgba(0.6, 0.9, 1.0, 0.2)),     // Pale Ice
+            mat_frost_70: materials.add(Color::srgba(0.8, 0.95, 1.0, 0.7)),    // Bright Ice
+            mat_telegraph_20: materials.add(Color::srgba(1.0, 0.25, 0.1, 0.2)), // Warning Red
+            mat_telegraph_50: materials.add(Color::srgba(1.0, 0.35, 0.1, 0.5)), // Warning Fill
+            mat_lava_90: materials.add(Color::srgba(1.0, 0.45, 0.1, 0.9)),     // Molten Orange
+            mat_lightning_glow: materials.add(Color::srgba(0.5, 0.7, 1.0, 0.5)), // Electric Blue
+            mat_pickup_gold: materials.add(crate::configs::pickup::GOLD_COLOR),
+            mat_pickup_health: materials.add(crate::configs::pickup::HEALTH_COLOR),
+            mat_pickup_haste: materials.add(crate::configs::pickup::HASTE_COLOR),
+            mat_pickup_magnet: materials.add(crate::configs::pickup::MAGNET_COLOR),
+            mat_pickup_relic: materials.add(crate::configs::relic::PICKUP_COLOR),
+            mat_shop_drone: materials.add(crate::configs::endless::DRONE_COLOR),
+            mat_indicator_elite: materials.add(indicator_color(palette, EnemyKind::Elite)),
+            mat_indicator_yellow: materials.add(indicator_color(palette, EnemyKind::Yellow)),
+            mat_enemy_grunt: materials.add(enemy_color(palette, EnemyKind::Grunt)),
+            mat_enemy_elite: materials.add(enemy_color(palette, EnemyKind::Elite)),
+            mat_enemy_yellow: materials.add(enemy_color(palette, EnemyKind::Yellow)),
+            mat_enemy_glyph: materials.add(GLYPH_COLOR),
+            mat_health_bar_back: materials.add(BAR_BACK_COLOR),
+            mat_health_bar_fill: materials.add(BAR_FILL_COLOR),
+        }
+    }
+
+    pub const fn enemy_material(&self, kind: EnemyKind) -> &Handle<ColorMaterial> {
+        match kind {
+            EnemyKind::Grunt => &self.mat_enemy_grunt,
+            EnemyKind::Elite => &self.mat_enemy_elite,
+            EnemyKind::Yellow => &self.mat_enemy_yellow,
+        }
+    }
+
+    /// Rewrites the palette-dependent materials, so live enemies and arrows change too
+    pub fn apply_palette(&self, materials: &mut Assets<ColorMaterial>, palette: ColorPalette) {
+        for kind in [EnemyKind::Grunt, EnemyKind::Elite, EnemyKind::Yellow] {
+            if let Some(material) = materials.get_mut(self.enemy_material(kind)) {
+                material.color = enemy_color(palette, kind);
+            }
+        }
+        for (handle, kind) in [
+            (&self.mat_indicator_elite, EnemyKind::Elite),
+            (&self.mat_indicator_yellow, EnemyKind::Yellow),
+        ] {
+            if let Some(material) = materials.get_mut(handle) {
+                material.color = indicator_color(palette, kind);
+            }
         }
     }
 }
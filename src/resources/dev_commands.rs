@@ -0,0 +1,188 @@
+//! Developer console commands and their parser. The console itself is behind the
+//! `dev_console` feature; the parser is always compiled so its tests run by default.
+#![cfg_attr(not(feature = "dev_console"), allow(dead_code))]
+
+use crate::components::enemy::EnemyKind;
+use crate::components::player::{CombatStats, Health, PlayerStats};
+use crate::configs::dev_console::MAX_SPAWN_COUNT;
+
+/// Player stats the `set` command can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevStat {
+    Damage,
+    Speed,
+    Magnet,
+    CritChance,
+    CritDamage,
+    Lifesteal,
+    CooldownReduction,
+    Armor,
+    MaxHealth,
+}
+
+impl DevStat {
+    pub const ALL: [Self; 9] = [
+        Self::Damage,
+        Self::Speed,
+        Self::Magnet,
+        Self::CritChance,
+        Self::CritDamage,
+        Self::Lifesteal,
+        Self::CooldownReduction,
+        Self::Armor,
+        Self::MaxHealth,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Damage => "damage",
+            Self::Speed => "speed",
+            Self::Magnet => "magnet",
+            Self::CritChance => "crit",
+            Self::CritDamage => "critdmg",
+            Self::Lifesteal => "lifesteal",
+            Self::CooldownReduction => "cdr",
+            Self::Armor => "armor",
+            Self::MaxHealth => "hp",
+        }
+    }
+
+    pub const fn apply(
+        self,
+        value: f32,
+        stats: &mut PlayerStats,
+        combat: &mut CombatStats,
+        health: &mut Health,
+    ) {
+        match self {
+            Self::Damage => stats.damage_multiplier = value,
+            Self::Speed => stats.speed = value,
+            Self::Magnet => stats.magnet_radius = value,
+            Self::CritChance => combat.crit_chance = value,
+            Self::CritDamage => combat.crit_damage = value,
+            Self::Lifesteal => combat.lifesteal = value,
+            Self::CooldownReduction => combat.cooldown_reduction = value,
+            Self::Armor => combat.armor = value,
+            Self::MaxHealth => {
+                health.max = value;
+                health.current = value;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DevCommand {
+    Help,
+    SetRound(u32),
+    GiveGold(u32),
+    Spawn { kind: EnemyKind, count: u32 },
+    KillAll,
+    GodMode,
+    SetStat { stat: DevStat, value: f32 },
+    Colliders,
+    Grid,
+    Clear,
+}
+
+fn parse_number<T: std::str::FromStr>(arg: Option<&str>, usage: &str) -> Result<T, String> {
+    arg.and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| format!("usage: {usage}"))
+}
+
+/// Parses one console line; the error is the message to log
+pub fn parse_command(line: &str) -> Result<DevCommand, String> {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Err(String::new());
+    };
+    let command = match name.to_lowercase().as_str() {
+        "help" => DevCommand::Help,
+        "round" => match parse_number(words.next(), "round <n>")? {
+            0 => return Err("usage: round <n>, rounds start at 1".to_string()),
+            round => DevCommand::SetRound(round),
+        },
+        "gold" => DevCommand::GiveGold(parse_number(words.next(), "gold <n>")?),
+        "spawn" => {
+            let kind = match words.next() {
+                Some("grunt") => EnemyKind::Grunt,
+                Some("elite") => EnemyKind::Elite,
+                Some("mage" | "yellow") => EnemyKind::Yellow,
+                _ => return Err("usage: spawn <grunt|elite|mage> [count]".to_string()),
+            };
+            let count = match words.next() {
+                Some(arg) => parse_number(Some(arg), "spawn <grunt|elite|mage> [count]")?,
+                None => 1,
+            };
+            DevCommand::Spawn {
+                kind,
+                count: count.min(MAX_SPAWN_COUNT),
+            }
+        }
+        "killall" => DevCommand::KillAll,
+        "god" => DevCommand::GodMode,
+        "set" => {
+            let stat_names: Vec<&str> = DevStat::ALL.iter().map(|stat| stat.name()).collect();
+            let usage = format!("set <{}> <value>", stat_names.join("|"));
+            let stat = words
+                .next()
+                .and_then(|word| DevStat::ALL.into_iter().find(|stat| stat.name() == word))
+                .ok_or_else(|| format!("usage: {usage}"))?;
+            DevCommand::SetStat {
+                stat,
+                value: parse_number(words.next(), &usage)?,
+            }
+        }
+        "colliders" => DevCommand::Colliders,
+        "grid" => DevCommand::Grid,
+        "clear" => DevCommand::Clear,
+        other => return Err(format!("unknown command '{other}', try help")),
+    };
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse_command("round 12"), Ok(DevCommand::SetRound(12)));
+        assert!(parse_command("round 0").is_err());
+        assert_eq!(
+            parse_command("spawn elite 3"),
+            Ok(DevCommand::Spawn {
+                kind: EnemyKind::Elite,
+                count: 3
+            })
+        );
+        assert_eq!(
+            parse_command("spawn mage"),
+            Ok(DevCommand::Spawn {
+                kind: EnemyKind::Yellow,
+                count: 1
+            })
+        );
+        assert_eq!(
+            parse_command("set crit 0.5"),
+            Ok(DevCommand::SetStat {
+                stat: DevStat::CritChance,
+                value: 0.5
+            })
+        );
+        assert!(parse_command("gold lots").is_err());
+        assert!(parse_command("set luck 1").is_err());
+        assert!(parse_command("fly").is_err());
+    }
+
+    #[test]
+    fn test_spawn_count_is_capped() {
+        assert_eq!(
+            parse_command("spawn grunt 9999"),
+            Ok(DevCommand::Spawn {
+                kind: EnemyKind::Grunt,
+                count: MAX_SPAWN_COUNT
+            })
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Spawn director for endless runs: continuous spawning whose pace ramps with time survived
+
+use crate::configs::endless::{
+    BASE_SPAWN_INTERVAL, DRONE_INTERVAL, ELITE_INTERVAL, MIN_SPAWN_INTERVAL, SECONDS_PER_LEVEL,
+    SPAWN_INTERVAL_DECAY, YELLOW_INTERVAL,
+};
+use crate::resources::run_settings::RunSettings;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct EndlessDirector {
+    /// Seconds survived so far
+    pub elapsed: f32,
+    pub spawn_timer: Timer,
+    pub elite_timer: Timer,
+    pub yellow_timer: Timer,
+    pub drone_timer: Timer,
+}
+
+impl Default for EndlessDirector {
+    fn default() -> Self {
+        Self {
+            elapsed: 0.0,
+            spawn_timer: Timer::from_seconds(BASE_SPAWN_INTERVAL, TimerMode::Repeating),
+            elite_timer: Timer::from_seconds(ELITE_INTERVAL, TimerMode::Repeating),
+            yellow_timer: Timer::from_seconds(YELLOW_INTERVAL, TimerMode::Repeating),
+            drone_timer: Timer::from_seconds(DRONE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl EndlessDirector {
+    /// Starts at 1 and rises every `SECONDS_PER_LEVEL`
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn level(&self) -> u32 {
+        1 + (self.elapsed / SECONDS_PER_LEVEL) as u32
+    }
+
+    /// Seconds between regular spawns; shrinks smoothly with time and the difficulty's enemy count
+    pub fn spawn_interval(&self, settings: &RunSettings) -> f32 {
+        let ramp = SPAWN_INTERVAL_DECAY.powf(self.elapsed / SECONDS_PER_LEVEL);
+        (BASE_SPAWN_INTERVAL * ramp / settings.enemy_count_multiplier()).max(MIN_SPAWN_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::run_settings::Difficulty;
+
+    #[test]
+    fn test_intensity_ramps_with_time() {
+        let settings = RunSettings::default();
+        let mut director = EndlessDirector::default();
+        assert_eq!(director.level(), 1);
+        let start = director.spawn_interval(&settings);
+        assert!((start - BASE_SPAWN_INTERVAL).abs() < f32::EPSILON);
+
+        director.elapsed = SECONDS_PER_LEVEL * 2.5;
+        assert_eq!(director.level(), 3);
+        let later = director.spawn_interval(&settings);
+        assert!(later < start);
+
+        let nightmare = RunSettings {
+            difficulty: Difficulty::Nightmare,
+            ..default()
+        };
+        assert!(director.spawn_interval(&nightmare) < later);
+
+        director.elapsed = 3600.0;
+        assert!((director.spawn_interval(&settings) - MIN_SPAWN_INTERVAL).abs() < f32::EPSILON);
+    }
+}
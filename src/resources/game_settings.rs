@@ -0,0 +1,137 @@
+//! Player preferences from the settings screen, persisted through `utils::storage`
+
+use crate::configs::audio::{DEFAULT_MASTER_VOLUME, DEFAULT_MUSIC_VOLUME, DEFAULT_SFX_VOLUME};
+use crate::configs::meta::SETTINGS_SAVE_NAME;
+use crate::resources::locale::Language;
+use crate::utils::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Enemy, indicator and crit number colours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorPalette {
+    #[default]
+    Standard,
+    /// Deuteranopia and protanopia
+    RedGreen,
+    /// Tritanopia
+    BlueYellow,
+}
+
+impl ColorPalette {
+    pub const ALL: [Self; 3] = [Self::Standard, Self::RedGreen, Self::BlueYellow];
+
+    /// `Locale` key of the name shown in settings
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Standard => "settings.palette.standard",
+            Self::RedGreen => "settings.palette.red_green",
+            Self::BlueYellow => "settings.palette.blue_yellow",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|palette| *palette == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct GameSettings {
+    /// Floating damage numbers over hit targets
+    pub damage_numbers: bool,
+    /// Screen-edge arrows pointing at off-screen elites and mages
+    pub offscreen_indicators: bool,
+    pub minimap: bool,
+    pub language: Language,
+    pub palette: ColorPalette,
+    /// Shape drawn on each enemy so types don't rely on colour alone
+    pub enemy_glyphs: bool,
+    /// Disables screen shake and projectile trails
+    pub reduced_motion: bool,
+    /// Multiplier on the window-height UI scale
+    pub text_scale: f32,
+    /// Volumes in [0, 1]; effects and music are both scaled by the master volume
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            damage_numbers: true,
+            offscreen_indicators: true,
+            minimap: true,
+            language: Language::English,
+            palette: ColorPalette::Standard,
+            enemy_glyphs: false,
+            reduced_motion: false,
+            text_scale: 1.0,
+            master_volume: DEFAULT_MASTER_VOLUME,
+            sfx_volume: DEFAULT_SFX_VOLUME,
+            music_volume: DEFAULT_MUSIC_VOLUME,
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn sfx_gain(&self) -> f32 {
+        self.master_volume * self.sfx_volume
+    }
+
+    pub fn music_gain(&self) -> f32 {
+        self.master_volume * self.music_volume
+    }
+
+    pub fn load() -> Self {
+        storage::read(SETTINGS_SAVE_NAME)
+            .and_then(|json| match serde_json::from_str(&json) {
+                Ok(settings) => Some(settings),
+                Err(err) => {
+                    warn!("Discarding unreadable settings: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => {
+                storage::write(SETTINGS_SAVE_NAME, &json);
+            }
+            Err(err) => warn!("Failed to serialize settings: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_fields_fall_back_to_defaults() {
+        let settings: GameSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, GameSettings::default());
+    }
+
+    #[test]
+    fn test_palette_cycle_visits_every_palette() {
+        let mut palette = ColorPalette::Standard;
+        for expected in ColorPalette::ALL
+            .iter()
+            .cycle()
+            .skip(1)
+            .take(ColorPalette::ALL.len())
+        {
+            palette = palette.next();
+            assert_eq!(palette, *expected);
+        }
+    }
+}
@@ -11,4 +11,8 @@ pub enum GameState {
     Tutorial,
     GameOver,
     Settings,
+    /// Simulation paused while the player drafts a level-up perk
+    LevelUp,
+    /// Plain pause overlay; `Time<Virtual>` is frozen here and in `LevelUp`
+    Paused,
 }
@@ -9,6 +9,7 @@ pub struct InputSettings {
 
     pub left_skill: ActionInput,
     pub right_skill: ActionInput,
+    pub dash: ActionInput,
     pub touch_cursor_sensitivity: f32,
 }
 
@@ -24,6 +25,8 @@ pub struct VirtualInput {
     pub right_skill: bool,
     pub left_skill_clicked: bool,
     pub right_skill_clicked: bool,
+    pub dash: bool,
+    pub dash_clicked: bool,
     pub joystick_start: Option<Vec2>,
     pub touch_cursor_last_pos: Option<Vec2>,
 }
@@ -44,6 +47,7 @@ impl Default for InputSettings {
 
             left_skill: ActionInput::Keyboard(KeyCode::KeyQ),
             right_skill: ActionInput::Keyboard(KeyCode::KeyE),
+            dash: ActionInput::Keyboard(KeyCode::Space),
             touch_cursor_sensitivity: 1.5,
         }
     }
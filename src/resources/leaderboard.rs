@@ -0,0 +1,238 @@
+//! Local high score table, persisted through `utils::storage`.
+//! Entries are keyed by seed, difficulty, ascensions and starting loadout: replaying the same
+//! setup only replaces its own entry when the new run does better.
+
+use crate::components::weapon::WeaponType;
+use crate::configs::leaderboard::{LEADERBOARD_SAVE_NAME, LEADERBOARD_SIZE};
+use crate::resources::locale::Locale;
+use crate::resources::run_settings::{Ascension, Difficulty, GameMode, RunSettings};
+use crate::utils::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Weapons held when the first wave started
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Loadout {
+    pub left: Option<WeaponType>,
+    pub right: Option<WeaponType>,
+}
+
+impl Loadout {
+    pub fn label(self, locale: &Locale) -> String {
+        let name = |weapon: Option<WeaponType>| weapon.map_or("-", |w| locale.get(w.name()));
+        format!("{} / {}", name(self.left), name(self.right))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    #[serde(default)]
+    pub mode: GameMode,
+    /// Round the run died on; the level reached in endless runs
+    pub round: u32,
+    pub kills: u32,
+    /// Seconds spent fighting
+    pub time: f32,
+    pub loadout: Loadout,
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub ascensions: Vec<Ascension>,
+    /// Game version the run was played on
+    pub version: String,
+}
+
+impl ScoreEntry {
+    /// Run settings the entry was played with, for labels and replays
+    pub fn settings(&self) -> RunSettings {
+        RunSettings {
+            mode: self.mode,
+            difficulty: self.difficulty,
+            ascensions: self.ascensions.clone(),
+        }
+    }
+
+    /// Same mode, seed, difficulty, ascensions (in any order) and starting loadout
+    pub fn same_setup(&self, other: &Self) -> bool {
+        self.mode == other.mode
+            && self.seed == other.seed
+            && self.difficulty == other.difficulty
+            && self.loadout == other.loadout
+            && self.ascensions.len() == other.ascensions.len()
+            && self.ascensions.iter().all(|a| other.ascensions.contains(a))
+    }
+
+    /// Round runs come before endless runs. Within rounds: higher round, then more kills,
+    /// then the faster run. Endless runs are scored by time survived, then kills.
+    pub fn rank_cmp(&self, other: &Self) -> Ordering {
+        self.mode.cmp(&other.mode).then_with(|| match self.mode {
+            GameMode::Rounds => other
+                .round
+                .cmp(&self.round)
+                .then(other.kills.cmp(&self.kills))
+                .then(self.time.total_cmp(&other.time)),
+            GameMode::Endless => other
+                .time
+                .total_cmp(&self.time)
+                .then(other.kills.cmp(&self.kills)),
+        })
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Leaderboard {
+    /// Best first, at most `LEADERBOARD_SIZE` per game mode
+    pub entries: Vec<ScoreEntry>,
+    /// Rank the last finished run placed at, for the game over screen
+    #[serde(skip)]
+    pub last_rank: Option<usize>,
+}
+
+impl Leaderboard {
+    /// Inserts the run if it makes the table and beats any entry with the same setup.
+    /// Returns its zero-based rank among runs of the same mode when it was recorded.
+    pub fn submit(&mut self, entry: ScoreEntry) -> Option<usize> {
+        if let Some(index) = self.entries.iter().position(|e| e.same_setup(&entry)) {
+            if entry.rank_cmp(&self.entries[index]) != Ordering::Less {
+                return None;
+            }
+            self.entries.remove(index);
+        }
+
+        let position = self
+            .entries
+            .iter()
+            .position(|e| entry.rank_cmp(e) == Ordering::Less)
+            .unwrap_or(self.entries.len());
+        let mode = entry.mode;
+        let rank = self.entries[..position]
+            .iter()
+            .filter(|e| e.mode == mode)
+            .count();
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        self.entries.insert(position, entry);
+
+        let mut kept = 0;
+        self.entries.retain(|e| {
+            if e.mode != mode {
+                return true;
+            }
+            kept += 1;
+            kept <= LEADERBOARD_SIZE
+        });
+        Some(rank)
+    }
+
+    /// Entries of one mode, best first, with their index into `entries`
+    pub fn mode_entries(&self, mode: GameMode) -> impl Iterator<Item = (usize, &ScoreEntry)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(move |(_, entry)| entry.mode == mode)
+    }
+
+    pub fn load() -> Self {
+        storage::read(LEADERBOARD_SAVE_NAME)
+            .and_then(|json| match serde_json::from_str(&json) {
+                Ok(leaderboard) => Some(leaderboard),
+                Err(err) => {
+                    warn!("Discarding unreadable high scores: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => {
+                storage::write(LEADERBOARD_SAVE_NAME, &json);
+            }
+            Err(err) => warn!("Failed to serialize high scores: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(seed: u64, round: u32, kills: u32) -> ScoreEntry {
+        ScoreEntry {
+            mode: GameMode::Rounds,
+            round,
+            kills,
+            time: 60.0,
+            loadout: Loadout {
+                left: Some(WeaponType::Sword),
+                right: Some(WeaponType::Gun),
+            },
+            seed,
+            difficulty: Difficulty::Normal,
+            ascensions: vec![Ascension::Frenzy, Ascension::Inflation],
+            version: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_submit_orders_and_caps_table() {
+        let mut leaderboard = Leaderboard::default();
+        assert_eq!(leaderboard.submit(entry(1, 5, 10)), Some(0));
+        assert_eq!(leaderboard.submit(entry(2, 8, 10)), Some(0));
+        assert_eq!(leaderboard.submit(entry(3, 5, 20)), Some(1));
+        assert_eq!(leaderboard.entries[2].seed, 1);
+
+        for seed in 10..20 {
+            leaderboard.submit(entry(seed, 20, 0));
+        }
+        assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(leaderboard.submit(entry(99, 1, 0)), None);
+    }
+
+    #[test]
+    fn test_same_setup_keeps_only_the_best() {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.submit(entry(7, 6, 30));
+        assert_eq!(leaderboard.submit(entry(7, 4, 50)), None);
+
+        let mut reordered = entry(7, 9, 0);
+        reordered.ascensions.reverse();
+        assert_eq!(leaderboard.submit(reordered), Some(0));
+        assert_eq!(leaderboard.entries.len(), 1);
+        assert_eq!(leaderboard.entries[0].round, 9);
+
+        let mut other_loadout = entry(7, 2, 0);
+        other_loadout.loadout.right = None;
+        assert_eq!(leaderboard.submit(other_loadout), Some(1));
+    }
+
+    #[test]
+    fn test_endless_runs_rank_by_time_in_their_own_table() {
+        let mut leaderboard = Leaderboard::default();
+        for seed in 0..LEADERBOARD_SIZE as u64 {
+            leaderboard.submit(entry(seed, 30, 0));
+        }
+
+        let endless = |seed, time| ScoreEntry {
+            mode: GameMode::Endless,
+            time,
+            ..entry(seed, 1, 0)
+        };
+        assert_eq!(leaderboard.submit(endless(100, 90.0)), Some(0));
+        assert_eq!(leaderboard.submit(endless(101, 300.0)), Some(0));
+        assert_eq!(leaderboard.submit(endless(102, 120.0)), Some(1));
+
+        let times: Vec<f32> = leaderboard
+            .mode_entries(GameMode::Endless)
+            .map(|(_, e)| e.time)
+            .collect();
+        assert_eq!(times, vec![300.0, 120.0, 90.0]);
+        assert_eq!(
+            leaderboard.mode_entries(GameMode::Rounds).count(),
+            LEADERBOARD_SIZE
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Keyed UI string tables, one flat JSON object per language under `assets/locales/`.
+//! Every table is loaded through the `AssetServer` at startup, so `Locale` can switch
+//! languages without waiting. English is also compiled in and serves the first frames,
+//! before the assets arrive, and any key no loaded table has.
+
+use crate::resources::game_settings::GameSettings;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Vietnamese,
+}
+
+impl Language {
+    /// Order of the settings language selector
+    pub const ALL: [Self; 2] = [Self::English, Self::Vietnamese];
+
+    /// Name shown in the selector, always written in the language itself
+    pub const fn native_name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Vietnamese => "Tiếng Việt",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|lang| *lang == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    const fn asset_path(self) -> &'static str {
+        match self {
+            Self::English => "locales/en.json",
+            Self::Vietnamese => "locales/vi.json",
+        }
+    }
+}
+
+/// One language's strings by key
+#[derive(Asset, TypePath, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct StringTable(pub HashMap<String, String>);
+
+#[derive(Default)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<StringTable, BevyError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
+
+#[derive(Resource)]
+pub struct Locale {
+    language: Language,
+    /// Tables loaded so far, filled in by `apply_string_tables`
+    tables: HashMap<Language, HashMap<String, String>>,
+    /// Keeps every table loaded and maps asset events back to their language
+    handles: Vec<(Language, Handle<StringTable>)>,
+    /// Compiled-in English, used until the assets load and for keys no table has
+    fallback: HashMap<String, String>,
+}
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let language = world
+            .get_resource::<GameSettings>()
+            .map(|settings| settings.language)
+            .unwrap_or_default();
+        let mut locale = Self::new(language);
+        if let Some(asset_server) = world.get_resource::<AssetServer>() {
+            locale.handles = Language::ALL
+                .iter()
+                .map(|language| (*language, asset_server.load(language.asset_path())))
+                .collect();
+        }
+        locale
+    }
+}
+
+impl Locale {
+    /// A locale serving only the compiled-in English until tables are inserted
+    pub fn new(language: Language) -> Self {
+        let fallback = serde_json::from_str(include_str!("../../assets/locales/en.json"))
+            .unwrap_or_else(|err| {
+                error!("Unreadable built-in English strings: {err}");
+                HashMap::new()
+            });
+        Self {
+            language,
+            tables: HashMap::new(),
+            handles: Vec::new(),
+            fallback,
+        }
+    }
+
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    pub const fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    /// Language of a loaded table asset, if it is one of ours
+    pub fn table_language(&self, id: AssetId<StringTable>) -> Option<Language> {
+        self.handles
+            .iter()
+            .find(|(_, handle)| handle.id() == id)
+            .map(|(language, _)| *language)
+    }
+
+    /// Stores a loaded table; true if it changes what that language was being served
+    pub fn insert_table(&mut self, language: Language, table: &StringTable) -> bool {
+        let previous = self.tables.insert(language, table.0.clone());
+        previous.as_ref().unwrap_or(&self.fallback) != &table.0
+    }
+
+    /// The string for `key`, or the key itself when no table has it
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        [self.language, Language::English]
+            .iter()
+            .find_map(|language| self.tables.get(language)?.get(key))
+            .or_else(|| self.fallback.get(key))
+            .map_or_else(
+                || {
+                    warn_once!("Missing localization key {key}");
+                    key
+                },
+                String::as_str,
+            )
+    }
+
+    /// `get` with every `{name}` placeholder replaced by its argument
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter()
+            .fold(self.get(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_table(language: Language) -> StringTable {
+        let path = format!(
+            "{}/assets/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            language.asset_path()
+        );
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_missing_keys_fall_back_to_english() {
+        let mut locale = Locale::new(Language::Vietnamese);
+        let mut table = file_table(Language::Vietnamese);
+        table.0.remove("common.back");
+        locale.insert_table(Language::Vietnamese, &table);
+        assert_eq!(locale.get("common.back"), "BACK");
+        assert_eq!(locale.get("no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn test_loaded_table_serves_its_language() {
+        let mut locale = Locale::new(Language::Vietnamese);
+        assert_eq!(locale.get("common.back"), "BACK");
+
+        let table = file_table(Language::Vietnamese);
+        assert!(locale.insert_table(Language::Vietnamese, &table));
+        assert!(!locale.insert_table(Language::Vietnamese, &table));
+        assert!(!locale.insert_table(Language::English, &file_table(Language::English)));
+        assert_eq!(locale.get("common.back"), table.0["common.back"]);
+
+        locale.set_language(Language::English);
+        assert_eq!(locale.get("common.back"), "BACK");
+    }
+
+    #[test]
+    fn test_format_fills_placeholders() {
+        let locale = Locale::new(Language::English);
+        assert_eq!(locale.format("hud.level", &[("level", &7)]), "Lv 7");
+    }
+
+    #[test]
+    fn test_translations_only_use_english_keys() {
+        let english = file_table(Language::English).0;
+        for language in Language::ALL {
+            let table = file_table(language).0;
+            assert!(!table.is_empty(), "{language:?} table failed to load");
+            for key in table.keys() {
+                assert!(
+                    english.contains_key(key),
+                    "{language:?} has unknown key {key}"
+                );
+            }
+        }
+    }
+}
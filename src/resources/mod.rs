@@ -1,5 +1,17 @@
+pub mod achievements;
+pub mod audio;
 pub mod cached_assets;
+pub mod dev_commands;
+pub mod endless;
+pub mod game_settings;
 pub mod game_state;
 pub mod input_settings;
+pub mod leaderboard;
+pub mod locale;
+pub mod perks;
 pub mod polish;
+pub mod profile;
 pub mod round;
+pub mod run_seed;
+pub mod run_settings;
+pub mod run_stats;
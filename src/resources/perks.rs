@@ -0,0 +1,85 @@
+//! Level-up perk draft: the perks on offer and the highlighted card
+
+use crate::components::perk::Perk;
+use crate::configs::experience::{PERK_CHOICES, PERK_LIST, PERK_UPGRADES};
+use bevy::prelude::*;
+use rand::Rng;
+
+#[derive(Resource, Default)]
+pub struct PerkOffers {
+    pub perks: Vec<Perk>,
+}
+
+impl PerkOffers {
+    /// Replaces the offers with `PERK_CHOICES` distinct perks that pass `is_available`
+    pub fn roll(&mut self, rng: &mut impl Rng, is_available: impl Fn(Perk) -> bool) {
+        let mut candidates: Vec<Perk> = PERK_UPGRADES
+            .into_iter()
+            .map(Perk::Upgrade)
+            .chain(PERK_LIST)
+            .filter(|perk| is_available(*perk))
+            .collect();
+
+        self.perks.clear();
+        while self.perks.len() < PERK_CHOICES && !candidates.is_empty() {
+            let index = rng.gen_range(0..candidates.len());
+            self.perks.push(candidates.swap_remove(index));
+        }
+    }
+}
+
+/// Card highlighted by keyboard or hover, confirmed with Enter/Space
+#[derive(Resource, Default)]
+pub struct PerkSelection(pub usize);
+
+impl PerkSelection {
+    /// Moves the highlight by `step`, wrapping around `count` cards
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn step(&mut self, step: isize, count: usize) {
+        if count == 0 {
+            return;
+        }
+        self.0 = (self.0 as isize + step).rem_euclid(count as isize) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::ui::ShopButton;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_roll_offers_distinct_available_perks() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut offers = PerkOffers::default();
+        for _ in 0..50 {
+            offers.roll(&mut rng, |perk| perk != Perk::Bloodthirst);
+            assert_eq!(offers.perks.len(), PERK_CHOICES);
+            assert!(!offers.perks.contains(&Perk::Bloodthirst));
+            for (i, perk) in offers.perks.iter().enumerate() {
+                assert!(!offers.perks[i + 1..].contains(perk));
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_offers_fewer_when_pool_runs_dry() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut offers = PerkOffers::default();
+        offers.roll(&mut rng, |perk| {
+            matches!(perk, Perk::Overcharge | Perk::Upgrade(ShopButton::ArmorUp))
+        });
+        assert_eq!(offers.perks.len(), 2);
+    }
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut selection = PerkSelection::default();
+        selection.step(-1, 3);
+        assert_eq!(selection.0, 2);
+        selection.step(1, 3);
+        assert_eq!(selection.0, 0);
+    }
+}
@@ -13,6 +13,7 @@ pub fn update_camera_shake(
     camera: Single<&mut Transform, With<crate::components::player::GameCamera>>,
     mut shake: ResMut<ScreenShake>,
     time: Res<Time>,
+    settings: Res<crate::resources::game_settings::GameSettings>,
     player: Single<
         &Transform,
         (
@@ -21,8 +22,12 @@ pub fn update_camera_shake(
         ),
     >,
 ) {
-    // Decay trauma
-    shake.trauma = (shake.trauma - time.delta_secs()).max(0.0);
+    // Decay trauma, reduced motion drops it outright so the camera stays locked to the player
+    shake.trauma = if settings.reduced_motion {
+        0.0
+    } else {
+        (shake.trauma - time.delta_secs()).max(0.0)
+    };
 
     let shake_amount = shake.trauma.powi(2) * 20.0; // Max 20px shake
 
@@ -43,7 +48,12 @@ pub fn spawn_trails(
     projectile_query: Query<(&Transform, &crate::components::weapon::Projectile)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    settings: Res<crate::resources::game_settings::GameSettings>,
 ) {
+    if settings.reduced_motion {
+        return;
+    }
+
     // Basic interval check? Or every frame?
     // Every frame might be too much. Let's rely on frame rate.
     // Actually, distinct Trail component on projectile to configure it?
@@ -65,3 +75,40 @@ pub fn spawn_trails(
         }
     }
 }
+
+/// Blinks the player's material while the invulnerability timer runs
+#[allow(clippy::needless_pass_by_value)]
+pub fn player_iframe_flash(
+    player: Single<
+        (
+            &crate::components::player::Health,
+            &MeshMaterial2d<ColorMaterial>,
+        ),
+        With<crate::components::player::Player>,
+    >,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let (health, material) = *player;
+    let Some(material) = materials.get_mut(&material.0) else {
+        return;
+    };
+
+    let timer = &health.invulnerability_timer;
+    let alpha = if timer.is_finished() {
+        1.0
+    } else {
+        // Alternate visible/faded phases at a fixed interval
+        let interval = crate::configs::player::IFRAME_FLASH_INTERVAL;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let phase = (timer.elapsed_secs() / interval) as u32;
+        if phase.is_multiple_of(2) {
+            crate::configs::player::IFRAME_FLASH_ALPHA
+        } else {
+            1.0
+        }
+    };
+    // Avoid touching the asset (and re-uploading it) when nothing changed
+    if (material.color.alpha() - alpha).abs() > f32::EPSILON {
+        material.color.set_alpha(alpha);
+    }
+}
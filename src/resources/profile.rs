@@ -0,0 +1,290 @@
+//! Persistent meta-progression profile
+//! Survives game over and app restarts through `utils::storage`.
+
+use crate::components::player::HandType;
+use crate::components::weapon::{GunMode, MagicLoadout, SpellType, SwordMode, WeaponType};
+use crate::configs::meta::{
+    KILLS_PER_SHARD, MAX_LOADOUT_PRESETS, PROFILE_SAVE_NAME, SHARDS_PER_ROUND, get_unlock_config,
+};
+use crate::systems::ui::ShopButton;
+use crate::utils::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Content and bonuses bought with shards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Unlock {
+    // Spells
+    Meteor,
+    FrostRing,
+    ChainLightning,
+    ArcaneTurret,
+    // Shop cards
+    DashRecharge,
+    // Permanent bonuses
+    Vitality,
+    Fortune,
+    Focus,
+    Power,
+}
+
+impl Unlock {
+    /// Unlock gating a weapon, `None` if it is available from the start
+    pub const fn for_weapon(kind: WeaponType) -> Option<Self> {
+        match kind {
+            WeaponType::Shuriken | WeaponType::Sword | WeaponType::Gun | WeaponType::Magic => None,
+        }
+    }
+
+    pub const fn for_spell(spell: SpellType) -> Option<Self> {
+        match spell {
+            SpellType::Meteor => Some(Self::Meteor),
+            SpellType::FrostRing => Some(Self::FrostRing),
+            SpellType::ChainLightning => Some(Self::ChainLightning),
+            SpellType::ArcaneTurret => Some(Self::ArcaneTurret),
+            _ => None,
+        }
+    }
+
+    pub const fn for_card(card: ShopButton) -> Option<Self> {
+        match card {
+            ShopButton::DashCooldownDown => Some(Self::DashRecharge),
+            _ => None,
+        }
+    }
+}
+
+/// What one hand holds in a saved loadout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandPreset {
+    pub weapon: WeaponType,
+    pub primary: SpellType,
+    pub secondary: SpellType,
+    pub gun_mode: GunMode,
+    pub sword_mode: SwordMode,
+}
+
+impl HandPreset {
+    pub const fn capture(
+        weapon: WeaponType,
+        loadout: &MagicLoadout,
+        gun_mode: GunMode,
+        sword_mode: SwordMode,
+    ) -> Self {
+        Self {
+            weapon,
+            primary: loadout.primary,
+            secondary: loadout.secondary,
+            gun_mode,
+            sword_mode,
+        }
+    }
+}
+
+/// Named weapons, spells and modes for both hands, equipped from the arsenal in one click
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadoutPreset {
+    pub name: String,
+    pub left: HandPreset,
+    pub right: HandPreset,
+}
+
+impl LoadoutPreset {
+    pub const fn hand(&self, side: HandType) -> &HandPreset {
+        match side {
+            HandType::Left => &self.left,
+            HandType::Right => &self.right,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetaProfile {
+    pub shards: u32,
+    pub lifetime_shards: u32,
+    pub runs: u32,
+    pub best_round: u32,
+    /// Most ascension modifiers a finished run was played with
+    pub best_ascension: u32,
+    pub unlocks: Vec<Unlock>,
+    pub presets: Vec<LoadoutPreset>,
+    /// Shards granted by the last finished run, shown on the game over screen
+    #[serde(skip)]
+    pub last_reward: u32,
+}
+
+/// Shards granted for a run that ended on `round` with `kills` kills
+pub const fn run_reward(round: u32, kills: u32) -> u32 {
+    round * SHARDS_PER_ROUND + kills / KILLS_PER_SHARD
+}
+
+impl MetaProfile {
+    pub fn is_unlocked(&self, unlock: Unlock) -> bool {
+        self.unlocks.contains(&unlock)
+    }
+
+    pub fn weapon_unlocked(&self, kind: WeaponType) -> bool {
+        Unlock::for_weapon(kind).is_none_or(|unlock| self.is_unlocked(unlock))
+    }
+
+    pub fn spell_unlocked(&self, spell: SpellType) -> bool {
+        Unlock::for_spell(spell).is_none_or(|unlock| self.is_unlocked(unlock))
+    }
+
+    pub fn card_unlocked(&self, card: ShopButton) -> bool {
+        Unlock::for_card(card).is_none_or(|unlock| self.is_unlocked(unlock))
+    }
+
+    /// Whether every weapon and spell of `preset` is unlocked, so it may be equipped
+    pub fn preset_allowed(&self, preset: &LoadoutPreset) -> bool {
+        [&preset.left, &preset.right].into_iter().all(|hand| {
+            self.weapon_unlocked(hand.weapon)
+                && self.spell_unlocked(hand.primary)
+                && self.spell_unlocked(hand.secondary)
+        })
+    }
+
+    /// Saves `preset`, overwriting one with the same name. Returns false when all slots are taken.
+    pub fn store_preset(&mut self, preset: LoadoutPreset) -> bool {
+        if let Some(existing) = self.presets.iter_mut().find(|p| p.name == preset.name) {
+            *existing = preset;
+            return true;
+        }
+        if self.presets.len() >= MAX_LOADOUT_PRESETS {
+            return false;
+        }
+        self.presets.push(preset);
+        true
+    }
+
+    /// Spends shards on `unlock`. Returns false if already owned or unaffordable.
+    pub fn try_unlock(&mut self, unlock: Unlock) -> bool {
+        let cost = get_unlock_config(unlock).cost;
+        if self.is_unlocked(unlock) || self.shards < cost {
+            return false;
+        }
+        self.shards -= cost;
+        self.unlocks.push(unlock);
+        true
+    }
+
+    /// Books a finished run and returns the shards it earned.
+    pub fn record_run(&mut self, round: u32, kills: u32) -> u32 {
+        let reward = run_reward(round, kills);
+        self.shards += reward;
+        self.lifetime_shards += reward;
+        self.runs += 1;
+        self.best_round = self.best_round.max(round);
+        self.last_reward = reward;
+        reward
+    }
+
+    /// Loads the saved profile, falling back to a fresh one if missing or unreadable.
+    pub fn load() -> Self {
+        storage::read(PROFILE_SAVE_NAME)
+            .and_then(|json| match serde_json::from_str(&json) {
+                Ok(profile) => Some(profile),
+                Err(err) => {
+                    warn!("Discarding unreadable profile: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => {
+                storage::write(PROFILE_SAVE_NAME, &json);
+            }
+            Err(err) => warn!("Failed to serialize profile: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_spends_shards_once() {
+        let mut profile = MetaProfile {
+            shards: 100,
+            ..default()
+        };
+        assert!(!profile.spell_unlocked(SpellType::Meteor));
+        assert!(profile.spell_unlocked(SpellType::Nova));
+
+        assert!(profile.try_unlock(Unlock::Meteor));
+        assert!(profile.spell_unlocked(SpellType::Meteor));
+        assert_eq!(profile.shards, 100 - get_unlock_config(Unlock::Meteor).cost);
+
+        let shards = profile.shards;
+        assert!(!profile.try_unlock(Unlock::Meteor));
+        assert_eq!(profile.shards, shards);
+    }
+
+    fn preset(name: &str, weapon: WeaponType, primary: SpellType) -> LoadoutPreset {
+        let hand = HandPreset {
+            weapon,
+            primary,
+            secondary: SpellType::Blink,
+            gun_mode: GunMode::Single,
+            sword_mode: SwordMode::Normal,
+        };
+        LoadoutPreset {
+            name: name.to_string(),
+            left: hand,
+            right: hand,
+        }
+    }
+
+    #[test]
+    fn test_store_preset_overwrites_by_name_and_caps() {
+        let mut profile = MetaProfile::default();
+        assert!(profile.store_preset(preset("A", WeaponType::Sword, SpellType::Nova)));
+        assert!(profile.store_preset(preset("A", WeaponType::Magic, SpellType::Nova)));
+        assert_eq!(profile.presets.len(), 1);
+        assert_eq!(profile.presets[0].left.weapon, WeaponType::Magic);
+
+        for index in 1..MAX_LOADOUT_PRESETS {
+            assert!(profile.store_preset(preset(
+                &index.to_string(),
+                WeaponType::Sword,
+                SpellType::Nova
+            )));
+        }
+        assert!(!profile.store_preset(preset("extra", WeaponType::Sword, SpellType::Nova)));
+        assert_eq!(profile.presets.len(), MAX_LOADOUT_PRESETS);
+    }
+
+    #[test]
+    fn test_preset_needs_unlocked_content() {
+        let mut profile = MetaProfile::default();
+        assert!(profile.preset_allowed(&preset("A", WeaponType::Sword, SpellType::Nova)));
+        assert!(profile.preset_allowed(&preset("B", WeaponType::Gun, SpellType::Nova)));
+        assert!(!profile.preset_allowed(&preset("C", WeaponType::Magic, SpellType::Meteor)));
+
+        profile.unlocks.push(Unlock::Meteor);
+        assert!(profile.preset_allowed(&preset("C", WeaponType::Magic, SpellType::Meteor)));
+    }
+
+    #[test]
+    fn test_profile_roundtrip() {
+        let mut profile = MetaProfile::default();
+        let reward = profile.record_run(4, 37);
+        assert_eq!(reward, run_reward(4, 37));
+        profile.unlocks.push(Unlock::Meteor);
+        profile.store_preset(preset("Mage", WeaponType::Magic, SpellType::Meteor));
+
+        let json = serde_json::to_string(&profile).unwrap();
+        let loaded: MetaProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.shards, reward);
+        assert_eq!(loaded.best_round, 4);
+        assert_eq!(loaded.runs, 1);
+        assert!(loaded.spell_unlocked(SpellType::Meteor));
+        assert_eq!(loaded.last_reward, 0);
+        assert_eq!(loaded.presets, profile.presets);
+    }
+}
@@ -10,14 +10,27 @@ pub struct RoundManager {
 
     pub round_state: RoundState,
     pub has_started: bool,
+    /// Enemies killed this run
+    pub kills: u32,
+}
+
+/// Fired when the first wave of a run is launched from the menu
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct RunStartedEvent;
+
+/// Fired when the last enemy of a round dies, before the loot vacuum starts
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct RoundClearedEvent {
+    pub round: u32,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum RoundState {
     #[default]
     Spawning, // Active round, spawning enemies
-    Fighting, // Spawning finished, waiting for clear
-    Shop,     // Round cleared, shop open
+    Fighting,   // Spawning finished, waiting for clear
+    Collecting, // Round cleared, remaining pickups are vacuumed to the player
+    Shop,       // Round cleared, shop open
 }
 
 impl Default for RoundManager {
@@ -34,6 +47,7 @@ impl Default for RoundManager {
 
             round_state: RoundState::Spawning,
             has_started: false,
+            kills: 0,
         }
     }
 }
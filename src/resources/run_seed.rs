@@ -0,0 +1,45 @@
+//! Per-run seed behind wave spawns, shop offers and level-up perks, so a run can be replayed
+//! from its seed.
+//! Combat rolls (crits, drops, enemy AI) stay unseeded.
+
+use crate::configs::leaderboard::{PERK_STREAM_SALT, SHOP_STREAM_SALT};
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[derive(Resource)]
+pub struct RunSeed {
+    pub seed: u64,
+    /// Enemy spawn positions
+    pub waves: StdRng,
+    /// Shop offers and rerolls
+    pub shop: StdRng,
+    /// Level-up perk drafts
+    pub perks: StdRng,
+}
+
+impl RunSeed {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            waves: StdRng::seed_from_u64(seed),
+            shop: StdRng::seed_from_u64(seed ^ SHOP_STREAM_SALT),
+            perks: StdRng::seed_from_u64(seed ^ PERK_STREAM_SALT),
+        }
+    }
+
+    pub fn random() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Short form shown in the UI
+    pub fn label(seed: u64) -> String {
+        format!("{seed:016X}")
+    }
+}
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        Self::random()
+    }
+}
@@ -0,0 +1,176 @@
+//! Game mode, difficulty preset and ascension modifiers picked before a run.
+//! Locked once the run has started; kept across new games so the same setup can be retried.
+
+use crate::configs::difficulty::{
+    FRENZY_SPEED_MULTIPLIER, INFLATION_PRICE_MULTIPLIER, MAGE_COUNCIL_MULTIPLIER,
+    VANGUARD_EXTRA_ELITES, get_difficulty_config,
+};
+use crate::resources::locale::Locale;
+use crate::resources::round::RoundManager;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+/// Rounds with shop breaks, or one continuous fight scored by time survived
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    #[default]
+    Rounds,
+    Endless,
+}
+
+/// Stackable challenge modifiers on top of the difficulty preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ascension {
+    EliteVanguard,
+    Frenzy,
+    Inflation,
+    Bloodless,
+    MageCouncil,
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunSettings {
+    pub mode: GameMode,
+    pub difficulty: Difficulty,
+    pub ascensions: Vec<Ascension>,
+}
+
+impl RunSettings {
+    pub fn is_endless(&self) -> bool {
+        self.mode == GameMode::Endless
+    }
+
+    pub fn has(&self, ascension: Ascension) -> bool {
+        self.ascensions.contains(&ascension)
+    }
+
+    pub fn toggle(&mut self, ascension: Ascension) {
+        if self.has(ascension) {
+            self.ascensions.retain(|a| *a != ascension);
+        } else {
+            self.ascensions.push(ascension);
+        }
+    }
+
+    /// Number of active ascension modifiers
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn level(&self) -> u32 {
+        self.ascensions.len() as u32
+    }
+
+    pub const fn enemy_health_multiplier(&self) -> f32 {
+        get_difficulty_config(self.difficulty).enemy_health
+    }
+
+    pub const fn enemy_damage_multiplier(&self) -> f32 {
+        get_difficulty_config(self.difficulty).enemy_damage
+    }
+
+    pub const fn enemy_count_multiplier(&self) -> f32 {
+        get_difficulty_config(self.difficulty).enemy_count
+    }
+
+    pub fn enemy_speed_multiplier(&self) -> f32 {
+        if self.has(Ascension::Frenzy) {
+            FRENZY_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn price_multiplier(&self) -> f32 {
+        if self.has(Ascension::Inflation) {
+            INFLATION_PRICE_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn lifesteal_allowed(&self) -> bool {
+        !self.has(Ascension::Bloodless)
+    }
+
+    /// Applies the difficulty and ascensions to the wave about to spawn.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn scale_wave(&self, round_manager: &mut RoundManager) {
+        round_manager.enemies_to_spawn =
+            (round_manager.enemies_to_spawn as f32 * self.enemy_count_multiplier()).round() as u32;
+        if self.has(Ascension::EliteVanguard) {
+            round_manager.elites_to_spawn += VANGUARD_EXTRA_ELITES;
+        }
+        if self.has(Ascension::MageCouncil) {
+            round_manager.yellow_enemies_to_spawn *= MAGE_COUNCIL_MULTIPLIER;
+        }
+    }
+
+    /// Short label such as "Hard", "Hard A2" or "Endless Hard A2"
+    pub fn label(&self, locale: &Locale) -> String {
+        let name = locale.get(get_difficulty_config(self.difficulty).name);
+        let label = if self.ascensions.is_empty() {
+            name.to_string()
+        } else {
+            locale.format(
+                "run.label.ascended",
+                &[("name", &name), ("level", &self.level())],
+            )
+        };
+        if self.is_endless() {
+            locale.format("run.label.endless", &[("label", &label)])
+        } else {
+            label
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_wave_applies_preset_and_ascensions() {
+        let mut settings = RunSettings {
+            difficulty: Difficulty::Hard,
+            ..default()
+        };
+        settings.toggle(Ascension::EliteVanguard);
+        settings.toggle(Ascension::MageCouncil);
+        settings.toggle(Ascension::Frenzy);
+        settings.toggle(Ascension::Frenzy);
+        assert_eq!(settings.level(), 2);
+        assert!((settings.enemy_speed_multiplier() - 1.0).abs() < f32::EPSILON);
+
+        let mut round_manager = RoundManager::default();
+        let base = round_manager.enemies_to_spawn;
+        let elites = round_manager.elites_to_spawn;
+        let yellows = round_manager.yellow_enemies_to_spawn;
+        settings.scale_wave(&mut round_manager);
+
+        assert!(round_manager.enemies_to_spawn > base);
+        assert_eq!(
+            round_manager.elites_to_spawn,
+            elites + VANGUARD_EXTRA_ELITES
+        );
+        assert_eq!(
+            round_manager.yellow_enemies_to_spawn,
+            yellows * MAGE_COUNCIL_MULTIPLIER
+        );
+        let locale = Locale::new(crate::resources::locale::Language::English);
+        assert_eq!(settings.label(&locale), "Hard A2");
+        settings.mode = GameMode::Endless;
+        assert_eq!(settings.label(&locale), "Endless Hard A2");
+    }
+}
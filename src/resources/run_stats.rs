@@ -0,0 +1,203 @@
+//! Per-run statistics shown on the game over screen and exportable as JSON
+
+use crate::components::enemy::EnemyKind;
+use crate::components::player::HandType;
+use crate::components::weapon::WeaponType;
+use crate::resources::leaderboard::Loadout;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::combat::DamageSource;
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Totals for a single round
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RoundStats {
+    pub round: u32,
+    /// Seconds spent fighting this round
+    pub time: f32,
+    pub damage_dealt: f32,
+    pub damage_taken: f32,
+    pub kills: u32,
+    pub gold_earned: u32,
+    pub gold_spent: u32,
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize)]
+pub struct RunStats {
+    /// `RunSeed` the run was played on
+    pub seed: u64,
+    pub loadout: Loadout,
+    /// Rounds fully cleared, filled in at game over
+    pub rounds_survived: u32,
+    /// Seconds spent fighting, shop time excluded
+    pub time: f32,
+    pub damage_by_weapon: BTreeMap<WeaponType, f32>,
+    pub damage_by_hand: BTreeMap<HandType, f32>,
+    /// Player-owned damage that no hand fired (relic effects)
+    pub other_damage: f32,
+    pub kills_by_enemy: BTreeMap<EnemyKind, u32>,
+    pub gold_earned: u32,
+    pub gold_spent: u32,
+    pub crits: u32,
+    pub lifesteal_healed: f32,
+    pub contact_damage_taken: BTreeMap<EnemyKind, f32>,
+    pub projectile_damage_taken: BTreeMap<WeaponType, f32>,
+    pub rounds: Vec<RoundStats>,
+}
+
+/// Export layout: the run's settings next to its statistics
+#[derive(Serialize)]
+struct RunExport<'a> {
+    settings: &'a RunSettings,
+    stats: &'a RunStats,
+}
+
+impl RunStats {
+    /// Entry for `round`, creating it (and any skipped rounds) on first use.
+    /// Rounds are numbered from 1, so round 0 is counted as round 1.
+    pub fn round_mut(&mut self, round: u32) -> &mut RoundStats {
+        let round = round.max(1);
+        while self.rounds.last().is_none_or(|last| last.round < round) {
+            let next = self.rounds.last().map_or(1, |last| last.round + 1);
+            self.rounds.push(RoundStats {
+                round: next,
+                ..default()
+            });
+        }
+        self.rounds
+            .iter_mut()
+            .rev()
+            .find(|entry| entry.round == round)
+            .expect("round entries are contiguous")
+    }
+
+    pub fn record_damage_dealt(
+        &mut self,
+        round: u32,
+        source: DamageSource,
+        damage: f32,
+        crit: bool,
+        healed: f32,
+    ) {
+        if let DamageSource::Weapon { kind, hand } = source {
+            *self.damage_by_weapon.entry(kind).or_default() += damage;
+            match hand {
+                Some(hand) => *self.damage_by_hand.entry(hand).or_default() += damage,
+                None => self.other_damage += damage,
+            }
+        }
+        if crit {
+            self.crits += 1;
+        }
+        self.lifesteal_healed += healed;
+        self.round_mut(round).damage_dealt += damage;
+    }
+
+    pub fn record_damage_taken(&mut self, round: u32, source: DamageSource, damage: f32) {
+        match source {
+            DamageSource::Weapon { kind, .. } => {
+                *self.projectile_damage_taken.entry(kind).or_default() += damage;
+            }
+            DamageSource::Contact(kind) => {
+                *self.contact_damage_taken.entry(kind).or_default() += damage;
+            }
+        }
+        self.round_mut(round).damage_taken += damage;
+    }
+
+    pub fn record_kill(&mut self, round: u32, kind: EnemyKind) {
+        *self.kills_by_enemy.entry(kind).or_default() += 1;
+        self.round_mut(round).kills += 1;
+    }
+
+    pub fn record_gold_earned(&mut self, round: u32, amount: u32) {
+        self.gold_earned += amount;
+        self.round_mut(round).gold_earned += amount;
+    }
+
+    pub fn record_gold_spent(&mut self, round: u32, amount: u32) {
+        self.gold_spent += amount;
+        self.round_mut(round).gold_spent += amount;
+    }
+
+    pub fn add_time(&mut self, round: u32, seconds: f32) {
+        self.time += seconds;
+        self.round_mut(round).time += seconds;
+    }
+
+    pub fn total_damage_dealt(&self) -> f32 {
+        self.damage_by_weapon.values().sum()
+    }
+
+    pub fn total_damage_taken(&self) -> f32 {
+        self.contact_damage_taken.values().sum::<f32>()
+            + self.projectile_damage_taken.values().sum::<f32>()
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills_by_enemy.values().sum()
+    }
+
+    pub fn to_json(&self, settings: &RunSettings) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&RunExport {
+            settings,
+            stats: self,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_totals_and_round_breakdown() {
+        let mut stats = RunStats::default();
+        let left_gun = DamageSource::Weapon {
+            kind: WeaponType::Gun,
+            hand: Some(HandType::Left),
+        };
+        let relic = DamageSource::Weapon {
+            kind: WeaponType::Magic,
+            hand: None,
+        };
+
+        stats.record_damage_dealt(1, left_gun, 30.0, true, 3.0);
+        stats.record_kill(1, EnemyKind::Grunt);
+        stats.record_gold_earned(1, 10);
+        // Round 2 is skipped entirely, its entry must still exist
+        stats.record_damage_dealt(3, relic, 20.0, false, 0.0);
+        stats.record_damage_taken(3, DamageSource::Contact(EnemyKind::Elite), 15.0);
+        stats.record_gold_spent(3, 8);
+
+        assert_eq!(stats.rounds.len(), 3);
+        assert_eq!(stats.rounds[1].round, 2);
+        assert!((stats.rounds[0].damage_dealt - 30.0).abs() < f32::EPSILON);
+        assert!((stats.rounds[2].damage_taken - 15.0).abs() < f32::EPSILON);
+        assert_eq!(stats.rounds[2].gold_spent, 8);
+
+        assert!((stats.total_damage_dealt() - 50.0).abs() < f32::EPSILON);
+        assert!((stats.damage_by_hand[&HandType::Left] - 30.0).abs() < f32::EPSILON);
+        assert!((stats.other_damage - 20.0).abs() < f32::EPSILON);
+        assert_eq!(stats.crits, 1);
+        assert_eq!(stats.total_kills(), 1);
+
+        let json = stats.to_json(&RunSettings::default()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["stats"]["damage_by_weapon"]["Gun"], 30.0);
+        assert_eq!(value["stats"]["contact_damage_taken"]["Elite"], 15.0);
+        assert_eq!(value["settings"]["difficulty"], "Normal");
+    }
+
+    #[test]
+    fn test_round_zero_counts_as_round_one() {
+        let mut stats = RunStats::default();
+        stats.record_kill(0, EnemyKind::Grunt);
+        stats.record_kill(1, EnemyKind::Grunt);
+
+        assert_eq!(stats.rounds.len(), 1);
+        assert_eq!(stats.rounds[0].round, 1);
+        assert_eq!(stats.rounds[0].kills, 2);
+    }
+}
@@ -0,0 +1,186 @@
+//! Colour-blind palettes and per-type enemy glyphs
+
+use crate::components::enemy::{EliteEnemy, Enemy, EnemyKind, YellowEnemy};
+use crate::configs::accessibility::{GLYPH_CROSS_THICKNESS, GLYPH_SCALE, GLYPH_Z_OFFSET};
+use crate::configs::enemy::visual_radius;
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::game_settings::{ColorPalette, GameSettings};
+use bevy::prelude::*;
+
+/// Shape drawn over an enemy: triangle for grunts, diamond for elites, cross for mages
+#[derive(Component)]
+pub struct EnemyGlyph;
+
+/// Mesh and local transform of each piece of a kind's glyph
+fn glyph_parts(kind: EnemyKind, cached: &CachedAssets) -> Vec<(Handle<Mesh>, Transform)> {
+    let size = visual_radius(kind) * GLYPH_SCALE;
+    let base = Transform::from_xyz(0.0, 0.0, GLYPH_Z_OFFSET);
+    match kind {
+        EnemyKind::Grunt => vec![(
+            cached.unit_triangle.clone(),
+            base.with_scale(Vec3::new(size, size, 1.0)),
+        )],
+        EnemyKind::Elite => vec![(
+            cached.unit_square.clone(),
+            base.with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4))
+                .with_scale(Vec3::new(size * 1.4, size * 1.4, 1.0)),
+        )],
+        EnemyKind::Yellow => {
+            let arm = Vec3::new(size * 2.0, size * 2.0 * GLYPH_CROSS_THICKNESS, 1.0);
+            vec![
+                (cached.unit_square.clone(), base.with_scale(arm)),
+                (
+                    cached.unit_square.clone(),
+                    base.with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2))
+                        .with_scale(arm),
+                ),
+            ]
+        }
+    }
+}
+
+/// Gives every new enemy its glyph, hidden unless `enemy_glyphs` is on
+#[allow(clippy::needless_pass_by_value)]
+pub fn attach_enemy_glyph(
+    add: On<Add, Enemy>,
+    mut commands: Commands,
+    cached: Res<CachedAssets>,
+    settings: Res<GameSettings>,
+    kinds: Query<(Has<EliteEnemy>, Has<YellowEnemy>)>,
+) {
+    let Ok((elite, yellow)) = kinds.get(add.entity) else {
+        return;
+    };
+    let visibility = if settings.enemy_glyphs {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    commands.entity(add.entity).with_children(|parent| {
+        for (mesh, transform) in glyph_parts(EnemyKind::from_markers(elite, yellow), &cached) {
+            parent.spawn((
+                Mesh2d(mesh),
+                MeshMaterial2d(cached.mat_enemy_glyph.clone()),
+                transform,
+                visibility,
+                EnemyGlyph,
+            ));
+        }
+    });
+}
+
+/// Follows the glyph toggle for enemies already on the field
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_enemy_glyphs(
+    settings: Res<GameSettings>,
+    mut glyphs: Query<&mut Visibility, With<EnemyGlyph>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let visibility = if settings.enemy_glyphs {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut glyph in &mut glyphs {
+        glyph.set_if_neq(visibility);
+    }
+}
+
+/// Recolours the shared enemy and indicator materials when the palette changes
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_palette(
+    settings: Res<GameSettings>,
+    cached: Res<CachedAssets>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut applied: Local<Option<ColorPalette>>,
+) {
+    if *applied == Some(settings.palette) {
+        return;
+    }
+    cached.apply_palette(&mut materials, settings.palette);
+    *applied = Some(settings.palette);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::accessibility::enemy_color;
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<ColorMaterial>>();
+        app.init_resource::<GameSettings>();
+        let world = app.world_mut();
+        let cached = world.resource_scope(|world, mut meshes: Mut<Assets<Mesh>>| {
+            let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+            CachedAssets::new(&mut meshes, &mut materials, ColorPalette::Standard)
+        });
+        world.insert_resource(cached);
+        app.add_observer(attach_enemy_glyph);
+        app.add_systems(Update, (apply_palette, update_enemy_glyphs));
+        app
+    }
+
+    fn enemy() -> Enemy {
+        Enemy {
+            health: 10.0,
+            speed: 0.0,
+            damage: 0.0,
+        }
+    }
+
+    fn glyph_count(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query::<&EnemyGlyph>().iter(world).count()
+    }
+
+    #[test]
+    fn test_each_enemy_gets_a_glyph() {
+        let mut app = setup();
+        app.world_mut().spawn(enemy());
+        app.world_mut().spawn((enemy(), EliteEnemy));
+        app.world_mut().spawn((enemy(), YellowEnemy));
+
+        // Triangle, diamond, and the two arms of the cross
+        assert_eq!(glyph_count(&mut app), 4);
+    }
+
+    #[test]
+    fn test_glyphs_follow_the_setting() {
+        let mut app = setup();
+        app.world_mut().spawn(enemy());
+        app.update();
+        app.world_mut().resource_mut::<GameSettings>().enemy_glyphs = true;
+        app.update();
+
+        let world = app.world_mut();
+        let visibility = world
+            .query_filtered::<&Visibility, With<EnemyGlyph>>()
+            .single(world)
+            .ok()
+            .copied();
+        assert_eq!(visibility, Some(Visibility::Inherited));
+    }
+
+    #[test]
+    fn test_palette_change_recolours_cached_materials() {
+        let mut app = setup();
+        app.update();
+        app.world_mut().resource_mut::<GameSettings>().palette = ColorPalette::RedGreen;
+        app.update();
+
+        let handle = app
+            .world()
+            .resource::<CachedAssets>()
+            .mat_enemy_grunt
+            .clone();
+        let materials = app.world().resource::<Assets<ColorMaterial>>();
+        assert_eq!(
+            materials.get(&handle).map(|material| material.color),
+            Some(enemy_color(ColorPalette::RedGreen, EnemyKind::Grunt))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Achievement hooks: translate combat, shop and round events into `AchievementSignal`s
+
+use crate::components::enemy::Enemy;
+use crate::components::player::{CombatStats, Player, Progression};
+use crate::components::weapon::ForcePull;
+use crate::resources::achievements::{
+    Achievement, AchievementProgress, AchievementSignal, AchievementTracker,
+};
+use crate::resources::round::RoundClearedEvent;
+use crate::systems::combat::{CollisionEvent, DamageEvent, DamageSource, EnemyDeathEvent};
+use crate::systems::ui::menu::CardPurchasedEvent;
+use bevy::prelude::*;
+
+/// Fired once per achievement, when it unlocks
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct AchievementUnlockedEvent {
+    pub achievement: Achievement,
+}
+
+/// Applies `signal`, announcing and saving any unlocks it caused.
+fn report(progress: &mut AchievementProgress, commands: &mut Commands, signal: AchievementSignal) {
+    let unlocked = progress.apply(signal);
+    if unlocked.is_empty() {
+        return;
+    }
+    for achievement in unlocked {
+        info!("Achievement unlocked: {achievement:?}");
+        commands.trigger(AchievementUnlockedEvent { achievement });
+    }
+    progress.save();
+}
+
+/// Remembers which enemies a Force Pull hit last, so their deaths can be credited to it
+#[allow(clippy::needless_pass_by_value)]
+pub fn achievement_collision_observer(
+    trigger: On<CollisionEvent>,
+    mut tracker: ResMut<AchievementTracker>,
+    pull_query: Query<(), With<ForcePull>>,
+    enemy_query: Query<(), With<Enemy>>,
+) {
+    let event = trigger.event();
+    if !enemy_query.contains(event.target) {
+        return;
+    }
+    if pull_query.contains(event.projectile) {
+        tracker.pull_marks.insert(event.target, event.projectile);
+    } else {
+        tracker.pull_marks.remove(&event.target);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn achievement_kill_observer(
+    trigger: On<EnemyDeathEvent>,
+    mut commands: Commands,
+    mut progress: ResMut<AchievementProgress>,
+    mut tracker: ResMut<AchievementTracker>,
+) {
+    report(&mut progress, &mut commands, AchievementSignal::Kill);
+
+    if let Some(pull) = tracker.pull_marks.remove(&trigger.event().entity) {
+        let kills = tracker.pull_kills.entry(pull).or_default();
+        *kills += 1;
+        let kills = *kills;
+        report(
+            &mut progress,
+            &mut commands,
+            AchievementSignal::PullKills(kills),
+        );
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn achievement_damage_observer(
+    trigger: On<DamageEvent>,
+    mut tracker: ResMut<AchievementTracker>,
+    player: Single<Entity, With<Player>>,
+) {
+    let event = trigger.event();
+    if event.entity == *player {
+        if event.damage > 0.0 {
+            tracker.hurt_this_round = true;
+        }
+    } else if let DamageSource::Weapon {
+        kind,
+        hand: Some(_),
+    } = event.source
+        && !tracker.weapons_used.contains(&kind)
+    {
+        tracker.weapons_used.push(kind);
+    }
+}
+
+pub fn achievement_purchase_observer(
+    _trigger: On<CardPurchasedEvent>,
+    mut commands: Commands,
+    mut progress: ResMut<AchievementProgress>,
+) {
+    report(&mut progress, &mut commands, AchievementSignal::CardBought);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn achievement_round_observer(
+    trigger: On<RoundClearedEvent>,
+    mut commands: Commands,
+    mut progress: ResMut<AchievementProgress>,
+    mut tracker: ResMut<AchievementTracker>,
+) {
+    report(
+        &mut progress,
+        &mut commands,
+        AchievementSignal::RoundCleared {
+            round: trigger.event().round,
+            flawless: !tracker.hurt_this_round,
+            only_weapon: tracker.only_weapon(),
+        },
+    );
+    tracker.next_round();
+}
+
+/// Stat thresholds are checked whenever the player's stats change, whatever changed them
+#[allow(clippy::needless_pass_by_value, clippy::cast_possible_truncation)]
+pub fn check_stat_achievements(
+    mut commands: Commands,
+    mut progress: ResMut<AchievementProgress>,
+    player: Single<(Ref<CombatStats>, Ref<Progression>), With<Player>>,
+) {
+    let (combat_stats, progression) = player.into_inner();
+    if combat_stats.is_changed() {
+        report(
+            &mut progress,
+            &mut commands,
+            AchievementSignal::CritChance(combat_stats.crit_chance),
+        );
+    }
+    if progression.is_changed() {
+        report(
+            &mut progress,
+            &mut commands,
+            AchievementSignal::RelicsOwned(progression.relics.len() as u32),
+        );
+    }
+}
+
+/// Lifetime counters advance every kill; persist them when the run ends
+#[allow(clippy::needless_pass_by_value)]
+pub fn save_achievements(progress: Res<AchievementProgress>) {
+    progress.save();
+}
@@ -0,0 +1,167 @@
+//! Sound effect hooks: translate combat, shop and round events into queued `Sfx`
+
+use crate::components::player::{Health, Player};
+use crate::components::weapon::{Faction, Projectile, SwordSwing};
+use crate::configs::audio::{LOW_HEALTH_BEAT_INTERVAL, LOW_HEALTH_FRACTION};
+use crate::resources::audio::{MusicLoop, Sfx, SfxQueue, SoundBank, SynthSound, voice_allowed};
+use crate::resources::game_settings::GameSettings;
+use crate::resources::round::{RoundClearedEvent, RoundManager, RoundState};
+use crate::systems::combat::{DamageEvent, EnemyDeathEvent};
+use crate::systems::ui::menu::CardPurchasedEvent;
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+/// A playing sound effect; despawned by `bevy_audio` once it finishes
+#[derive(Component)]
+pub struct SfxVoice(pub Sfx);
+
+#[derive(Component)]
+pub struct MusicTrack;
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn setup_audio(
+    mut commands: Commands,
+    mut sounds: ResMut<Assets<SynthSound>>,
+    mut music: ResMut<Assets<MusicLoop>>,
+    settings: Res<GameSettings>,
+) {
+    let bank = SoundBank::new(&mut sounds, &mut music);
+    commands.spawn((
+        AudioPlayer(bank.music.clone()),
+        PlaybackSettings::ONCE.with_volume(Volume::Linear(settings.music_gain())),
+        MusicTrack,
+    ));
+    commands.insert_resource(bank);
+}
+
+/// Starts the effects queued this frame, dropping any past the polyphony cap
+#[allow(clippy::needless_pass_by_value)]
+pub fn play_queued_sfx(
+    mut commands: Commands,
+    mut queue: ResMut<SfxQueue>,
+    bank: Res<SoundBank>,
+    settings: Res<GameSettings>,
+    voices: Query<&SfxVoice>,
+) {
+    let mut total = voices.iter().count();
+    let volume = settings.sfx_gain();
+    for sfx in queue.drain() {
+        let same = voices.iter().filter(|voice| voice.0 == sfx).count();
+        if volume <= 0.0 || !voice_allowed(same, total) {
+            continue;
+        }
+        let Some(handle) = bank.get(sfx) else {
+            continue;
+        };
+        commands.spawn((
+            AudioPlayer(handle),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+            SfxVoice(sfx),
+        ));
+        total += 1;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_music_volume(
+    settings: Res<GameSettings>,
+    mut sinks: Query<&mut AudioSink, With<MusicTrack>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut sink in &mut sinks {
+        sink.set_volume(Volume::Linear(settings.music_gain()));
+    }
+}
+
+/// Player weapon fire, including sword projectiles and spells
+#[allow(clippy::needless_pass_by_value)]
+pub fn projectile_sfx_observer(
+    add: On<Add, Projectile>,
+    projectiles: Query<&Projectile>,
+    mut queue: ResMut<SfxQueue>,
+) {
+    let Ok(projectile) = projectiles.get(add.entity) else {
+        return;
+    };
+    if projectile.faction == Faction::Player && projectile.hand.is_some() {
+        queue.play(Sfx::fire(projectile.kind));
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn sword_sfx_observer(
+    add: On<Add, SwordSwing>,
+    swings: Query<&SwordSwing>,
+    mut queue: ResMut<SfxQueue>,
+) {
+    if swings
+        .get(add.entity)
+        .is_ok_and(|swing| swing.faction == Faction::Player)
+    {
+        queue.play(Sfx::SwordSwing);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn damage_sfx_observer(trigger: On<DamageEvent>, mut queue: ResMut<SfxQueue>) {
+    queue.play(if trigger.event().crit {
+        Sfx::Crit
+    } else {
+        Sfx::Hit
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn death_sfx_observer(_trigger: On<EnemyDeathEvent>, mut queue: ResMut<SfxQueue>) {
+    queue.play(Sfx::EnemyDeath);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn purchase_sfx_observer(_trigger: On<CardPurchasedEvent>, mut queue: ResMut<SfxQueue>) {
+    queue.play(Sfx::Purchase);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn round_clear_sfx_observer(_trigger: On<RoundClearedEvent>, mut queue: ResMut<SfxQueue>) {
+    queue.play(Sfx::RoundClear);
+}
+
+/// Fanfare once per wave, when its spawning starts
+#[allow(clippy::needless_pass_by_value)]
+pub fn round_start_sfx(
+    round_manager: Res<RoundManager>,
+    mut announced: Local<Option<u32>>,
+    mut queue: ResMut<SfxQueue>,
+) {
+    if !round_manager.has_started {
+        *announced = None;
+        return;
+    }
+    if round_manager.round_state == RoundState::Spawning
+        && *announced != Some(round_manager.current_round)
+    {
+        *announced = Some(round_manager.current_round);
+        queue.play(Sfx::RoundStart);
+    }
+}
+
+/// Heartbeat while the player is under `LOW_HEALTH_FRACTION`
+#[allow(clippy::needless_pass_by_value)]
+pub fn low_health_sfx(
+    time: Res<Time>,
+    player: Single<&Health, With<Player>>,
+    mut until_beat: Local<f32>,
+    mut queue: ResMut<SfxQueue>,
+) {
+    if player.current <= 0.0 || player.current > player.max * LOW_HEALTH_FRACTION {
+        *until_beat = 0.0;
+        return;
+    }
+    *until_beat -= time.delta_secs();
+    if *until_beat <= 0.0 {
+        *until_beat = LOW_HEALTH_BEAT_INTERVAL;
+        queue.play(Sfx::LowHealth);
+    }
+}
@@ -1,10 +1,15 @@
 //! Damage processing when collisions occur
 
 use crate::components::enemy::Enemy;
-use crate::components::player::{CombatStats, Health, Player};
+use crate::components::perk::Perk;
+use crate::components::player::{CombatStats, Hand, Health, Player, Progression};
+use crate::components::status::{ForceType, StatusEffect, UnitStatus};
 use crate::components::weapon::Projectile;
+use crate::configs::experience::AMBIDEXTROUS_MULTIPLIER;
+use crate::configs::player as player_config;
 use crate::resources::game_state::GameState;
-use crate::systems::combat::{CollisionEvent, DamageEvent};
+use crate::systems::combat::{CollisionEvent, DamageEvent, DamageSource, PlayerHitEvent};
+use crate::systems::experience::hands_differ;
 use bevy::prelude::*;
 use rand::Rng;
 
@@ -21,11 +26,11 @@ pub fn damage_processing_system(
         Option<&crate::components::weapon::DistanceDamageBonus>,
     )>,
     mut enemy_query: Query<(&mut Enemy, &Transform)>,
-    player: Single<(Entity, &mut Health, &CombatStats), With<Player>>,
-    mut next_state: ResMut<NextState<GameState>>,
+    player: Single<(Entity, &mut Health, &CombatStats, &Progression), With<Player>>,
+    hands: Query<&Hand>,
 ) {
     let event = trigger.event();
-    let (player_entity, mut player_health, player_stats) = player.into_inner();
+    let (player_entity, mut player_health, player_stats, progression) = player.into_inner();
 
     // Retrieve projectile data
     let Ok(projectile_data) = projectile_query.get(event.projectile) else {
@@ -34,31 +39,25 @@ pub fn damage_processing_system(
     let projectile = projectile_data.0;
 
     if event.target == player_entity {
-        // Apply damage to player
-        if player_health.invulnerability_timer.is_finished() {
-            let mut final_damage = projectile.damage;
-            let mut is_crit = false;
-
-            let mut rng = rand::thread_rng();
-            if rng.gen_range(0.0..1.0) < projectile.crit_chance {
-                final_damage *= projectile.crit_damage;
-                is_crit = true;
-            }
-
-            player_health.current -= final_damage;
-            player_health.invulnerability_timer.reset();
-
-            commands.trigger(DamageEvent {
-                entity: player_entity,
-                damage: final_damage,
-                crit: is_crit,
-            });
-
-            if player_health.current <= 0.0 {
-                player_health.current = 0.0;
-                next_state.set(GameState::GameOver);
-            }
+        // Roll crit here; mitigation and i-frames are handled by `player_hit_observer`
+        let mut final_damage = projectile.damage;
+        let mut is_crit = false;
+
+        let mut rng = rand::thread_rng();
+        if rng.gen_range(0.0..1.0) < projectile.crit_chance {
+            final_damage *= projectile.crit_damage;
+            is_crit = true;
         }
+
+        commands.trigger(PlayerHitEvent {
+            damage: final_damage,
+            crit: is_crit,
+            source: projectile_data.1.translation.truncate(),
+            attacker: DamageSource::Weapon {
+                kind: projectile.kind,
+                hand: None,
+            },
+        });
         return;
     }
 
@@ -93,6 +92,13 @@ pub fn damage_processing_system(
         final_damage += multiplier * bonus.max_bonus;
     }
 
+    if projectile.hand.is_some()
+        && progression.has_perk(Perk::Ambidextrous)
+        && hands_differ(hands.iter())
+    {
+        final_damage *= AMBIDEXTROUS_MULTIPLIER;
+    }
+
     let mut is_crit = false;
 
     let mut rng = rand::thread_rng();
@@ -100,18 +106,27 @@ pub fn damage_processing_system(
         final_damage *= projectile.crit_damage;
         is_crit = true;
     }
-    if player_stats.lifesteal > 0.0 {
+    let healed = if player_stats.lifesteal > 0.0 {
         let aoe_penalty = if projectile.is_aoe { 0.5 } else { 1.0 };
         let heal_amount =
             final_damage * player_stats.lifesteal * projectile.lifesteal_efficiency * aoe_penalty;
+        let before = player_health.current;
         player_health.current = (player_health.current + heal_amount).min(player_health.max);
-    }
+        player_health.current - before
+    } else {
+        0.0
+    };
 
     enemy.health -= final_damage;
     commands.trigger(DamageEvent {
         entity: event.target,
         damage: final_damage,
         crit: is_crit,
+        source: DamageSource::Weapon {
+            kind: projectile.kind,
+            hand: projectile.hand,
+        },
+        healed,
     });
 
     if enemy.health <= 0.0 {
@@ -122,9 +137,64 @@ pub fn damage_processing_system(
     }
 }
 
+/// Applies a hit to the player: i-frames, armor mitigation, knockback and game over.
+/// Both contact and projectile damage route through here so they behave identically.
+#[allow(clippy::needless_pass_by_value)]
+pub fn player_hit_observer(
+    trigger: On<PlayerHitEvent>,
+    mut commands: Commands,
+    player: Single<
+        (
+            Entity,
+            &Transform,
+            &mut Health,
+            &CombatStats,
+            &mut UnitStatus,
+        ),
+        With<Player>,
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let event = trigger.event();
+    let (player_entity, transform, mut health, combat_stats, mut status) = player.into_inner();
+
+    if !health.invulnerability_timer.is_finished() {
+        return;
+    }
+
+    let final_damage = event.damage * (1.0 - combat_stats.damage_reduction());
+    health.current -= final_damage;
+    health.invulnerability_timer.reset();
+
+    let direction = (transform.translation.truncate() - event.source).normalize_or_zero();
+    if direction != Vec2::ZERO {
+        status.add(StatusEffect::ForcedMovement {
+            timer: Timer::from_seconds(player_config::KNOCKBACK_DURATION, TimerMode::Once),
+            direction,
+            speed: player_config::KNOCKBACK_SPEED,
+            move_type: ForceType::Knockback,
+        });
+    }
+
+    commands.trigger(DamageEvent {
+        entity: player_entity,
+        damage: final_damage,
+        crit: event.crit,
+        source: event.attacker,
+        healed: 0.0,
+    });
+
+    if health.current <= 0.0 {
+        health.current = 0.0;
+        next_state.set(GameState::GameOver);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::enemy::EnemyKind;
+    use crate::components::player::HandType;
     use crate::components::weapon::{DistanceDamageBonus, Faction, Projectile, WeaponType};
 
     #[test]
@@ -171,6 +241,7 @@ mod tests {
                     speed: 0.0,
                     direction: Vec2::ZERO,
                     owner_entity: player,
+                    hand: None,
                     is_aoe: false,
                     faction: Faction::Player,
                     crit_chance: 0.0,
@@ -247,6 +318,7 @@ mod tests {
                     speed: 0.0,
                     direction: Vec2::ZERO,
                     owner_entity: player,
+                    hand: None,
                     is_aoe: false,
                     faction: Faction::Player,
                     crit_chance: 0.0,
@@ -278,4 +350,136 @@ mod tests {
             enemy_comp.health
         );
     }
+
+    #[test]
+    fn test_ambidextrous_boosts_hand_damage_with_mixed_weapons() {
+        let mut app = App::new();
+        app.add_message::<CollisionEvent>();
+        app.add_message::<DamageEvent>();
+        app.add_observer(damage_processing_system);
+        app.init_resource::<NextState<GameState>>();
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Progression {
+                    perks: vec![Perk::Ambidextrous],
+                    ..default()
+                },
+            ))
+            .id();
+        for (side, weapon) in [
+            (HandType::Left, WeaponType::Sword),
+            (HandType::Right, WeaponType::Gun),
+        ] {
+            app.world_mut().spawn(Hand {
+                side,
+                equipped_weapon: Some(weapon),
+            });
+        }
+
+        let enemy = app
+            .world_mut()
+            .spawn(Enemy {
+                health: 100.0,
+                ..default()
+            })
+            .id();
+
+        let projectile = app
+            .world_mut()
+            .spawn((
+                Projectile {
+                    kind: WeaponType::Gun,
+                    damage: 10.0,
+                    speed: 0.0,
+                    direction: Vec2::ZERO,
+                    owner_entity: player,
+                    hand: Some(HandType::Right),
+                    is_aoe: false,
+                    faction: Faction::Player,
+                    crit_chance: 0.0,
+                    crit_damage: 2.0,
+                    lifesteal_efficiency: 1.0,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        app.world_mut().trigger(CollisionEvent {
+            projectile,
+            target: enemy,
+            position: Vec2::ZERO,
+        });
+
+        // 10 damage * AMBIDEXTROUS_MULTIPLIER
+        let enemy_comp = app.world().get::<Enemy>(enemy).unwrap();
+        let expected = 10.0f32.mul_add(-AMBIDEXTROUS_MULTIPLIER, 100.0);
+        assert!(
+            (enemy_comp.health - expected).abs() < 0.001,
+            "Health should be {expected}, got {}",
+            enemy_comp.health
+        );
+    }
+
+    #[test]
+    fn test_player_hit_applies_armor_and_knockback() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>();
+        app.add_observer(player_hit_observer);
+        app.init_resource::<NextState<GameState>>();
+
+        let mut health = Health::default();
+        health.invulnerability_timer.finish();
+
+        // Armor equal to ARMOR_SCALING halves incoming damage
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                health,
+                CombatStats {
+                    armor: player_config::ARMOR_SCALING,
+                    ..default()
+                },
+                UnitStatus::default(),
+                Transform::default(),
+            ))
+            .id();
+
+        app.world_mut().trigger(PlayerHitEvent {
+            damage: 40.0,
+            crit: false,
+            source: Vec2::new(-10.0, 0.0),
+            attacker: DamageSource::Contact(EnemyKind::Grunt),
+        });
+
+        let health = app.world().get::<Health>(player).unwrap();
+        assert!(
+            (health.current - 80.0).abs() < 0.001,
+            "Health should be 80.0, got {}",
+            health.current
+        );
+
+        let status = app.world().get::<UnitStatus>(player).unwrap();
+        assert!(status.effects.iter().any(|e| matches!(
+            e,
+            StatusEffect::ForcedMovement {
+                direction,
+                move_type: ForceType::Knockback,
+                ..
+            } if *direction == Vec2::X
+        )));
+
+        // Second hit lands inside the i-frame window and is ignored
+        app.world_mut().trigger(PlayerHitEvent {
+            damage: 40.0,
+            crit: false,
+            source: Vec2::new(-10.0, 0.0),
+            attacker: DamageSource::Contact(EnemyKind::Grunt),
+        });
+        let health = app.world().get::<Health>(player).unwrap();
+        assert!((health.current - 80.0).abs() < 0.001);
+    }
 }
@@ -49,6 +49,7 @@ pub fn projectile_effect_system(
                     speed: 0.0,
                     direction: Vec2::ZERO,
                     owner_entity: projectile.owner_entity,
+                    hand: projectile.hand,
                     is_aoe: true, // Explosion is AOE
                     faction: projectile.faction,
                     crit_chance: projectile.crit_chance,
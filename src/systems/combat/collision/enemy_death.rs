@@ -1,7 +1,6 @@
 //! Enemy death handling, loot dropping, and death effects
 
 use crate::components::physics::Velocity;
-use crate::components::player::{Currency, Player};
 use crate::components::weapon::Lifetime;
 use bevy::prelude::*;
 use rand::Rng;
@@ -13,22 +12,23 @@ use rand::Rng;
 pub fn enemy_death_system(
     trigger: On<crate::systems::combat::EnemyDeathEvent>,
     mut commands: Commands,
-    mut player_query: Query<&mut Currency, With<Player>>,
     elite_query: Query<&crate::components::enemy::EliteEnemy>,
     res: Res<crate::resources::cached_assets::CachedAssets>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    mut round_manager: ResMut<crate::resources::round::RoundManager>,
 ) {
     let event = trigger.event();
 
-    // Give Gold
-    if let Some(mut currency) = player_query.iter_mut().next() {
-        let gold_reward = if elite_query.get(event.entity).is_ok() {
-            crate::configs::enemy::ELITE_GOLD_REWARD
-        } else {
-            crate::configs::enemy::GOLD_REWARD
-        };
-        currency.gold += gold_reward;
-    }
+    // Drop Loot
+    let elite = elite_query.get(event.entity).is_ok();
+    let gold_reward = if elite {
+        crate::configs::enemy::ELITE_GOLD_REWARD
+    } else {
+        crate::configs::enemy::GOLD_REWARD
+    };
+    crate::systems::pickup::drop_loot(&mut commands, &res, event.position, gold_reward, elite);
+
+    round_manager.kills += 1;
 
     commands.entity(event.entity).despawn();
 
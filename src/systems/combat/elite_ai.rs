@@ -121,6 +121,7 @@ fn elite_fire_shuriken(
                 speed: shuriken::SPEED,
                 direction,
                 owner_entity: ctx.elite_entity,
+                hand: None,
                 is_aoe: false,
                 faction: Faction::Enemy,
                 crit_chance: ctx.player_crit_chance * crate::configs::enemy::ELITE_CRIT_CHANCE,
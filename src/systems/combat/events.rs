@@ -12,3 +12,21 @@ pub struct EnemyDeathEvent {
     pub entity: Entity,
     pub position: Vec2,
 }
+
+/// Incoming hit on the player from any source (contact or projectile).
+/// Armor mitigation, i-frames and knockback are applied by `player_hit_observer`.
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct PlayerHitEvent {
+    pub damage: f32,
+    pub crit: bool,
+    /// World position the hit came from, used for knockback direction
+    pub source: Vec2,
+    pub attacker: super::DamageSource,
+}
+
+/// Player teleported to one of their shurikens
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct ShurikenTeleportEvent {
+    /// Where the player stood before the teleport
+    pub from: Vec2,
+}
@@ -63,6 +63,7 @@ pub fn gun_weapon_system(
                 gun_state.mode,
                 &CombatContext {
                     owner_entity: player_entity,
+                    hand: Some(hand.side),
                     transform: &mut *player_transform,
                     cursor_pos,
                     spawn_pos: hand_pos,
@@ -126,6 +127,7 @@ fn fire_gun(params: &mut CombatInputParams, gun_mode: GunMode, ctx: &CombatConte
                     speed,
                     direction: dir,
                     owner_entity: ctx.owner_entity,
+                    hand: ctx.hand,
                     is_aoe: false,
                     faction: Faction::Player,
                     crit_chance: ctx.combat_stats.crit_chance,
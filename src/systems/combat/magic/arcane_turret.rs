@@ -0,0 +1,125 @@
+use super::energy_bolt::spawn_energy_bolt;
+use super::nearest_enemy;
+use super::registry::{SpellDefinition, aim};
+use crate::components::enemy::Enemy;
+use crate::components::physics::UniformGrid;
+use crate::components::player::{Player, Progression};
+use crate::components::weapon::{ArcaneTurret, Faction, Lifetime, SpellType};
+use crate::configs::spells::{arcane_turret, energy_bolt};
+use crate::systems::combat::stat_calc::HitProfile;
+use crate::systems::combat::{CombatContext, CombatInputParams};
+use crate::visuals::world::spawn_arcane_turret_visuals;
+use bevy::prelude::*;
+
+pub fn spawn_arcane_turret(params: &mut CombatInputParams, ctx: &CombatContext, faction: Faction) {
+    // Placed towards the cursor, but never further than PLACE_RANGE from the caster
+    let offset = (ctx.cursor_pos - ctx.spawn_pos).clamp_length_max(arcane_turret::PLACE_RANGE);
+
+    params
+        .commands
+        .spawn((
+            Transform::from_translation((ctx.spawn_pos + offset).extend(0.0)),
+            Visibility::Visible,
+            ArcaneTurret {
+                fire_timer: Timer::from_seconds(arcane_turret::FIRE_INTERVAL, TimerMode::Repeating),
+                owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
+                faction,
+                damage_multiplier: ctx.damage_multiplier * arcane_turret::DAMAGE_MULTIPLIER,
+                combat_stats: *ctx.combat_stats,
+            },
+            Lifetime {
+                timer: Timer::from_seconds(arcane_turret::DURATION, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            spawn_arcane_turret_visuals(parent, &params.cached_assets);
+        });
+}
+
+/// Turrets fire Energy Bolts at the nearest hostile target in range
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn arcane_turret_system(
+    mut params: CombatInputParams,
+    grid: Res<UniformGrid>,
+    enemy_query: Query<&Transform, (With<Enemy>, Without<ArcaneTurret>)>,
+    player: Single<&Transform, (With<Player>, Without<ArcaneTurret>)>,
+    mut turret_query: Query<(&mut Transform, &mut ArcaneTurret), (Without<Player>, Without<Enemy>)>,
+) {
+    let player_pos = player.translation.truncate();
+    // Turrets don't carry the caster's progression (e.g. Nova Core)
+    let progression = Progression::default();
+
+    for (mut transform, mut turret) in &mut turret_query {
+        turret.fire_timer.tick(params.time.delta());
+        if !turret.fire_timer.just_finished() {
+            continue;
+        }
+
+        let turret_pos = transform.translation.truncate();
+        let target_pos = match turret.faction {
+            Faction::Player => nearest_enemy(&grid, turret_pos, arcane_turret::RANGE, &[], |e| {
+                enemy_query.get(e).ok().map(|t| t.translation.truncate())
+            })
+            .map(|(_, pos)| pos),
+            Faction::Enemy => {
+                (turret_pos.distance(player_pos) <= arcane_turret::RANGE).then_some(player_pos)
+            }
+        };
+
+        let Some(target_pos) = target_pos else {
+            continue;
+        };
+
+        let combat_stats = turret.combat_stats;
+        let ctx = CombatContext {
+            owner_entity: turret.owner_entity,
+            hand: turret.hand,
+            transform: &mut transform,
+            cursor_pos: target_pos,
+            spawn_pos: turret_pos,
+            damage_multiplier: turret.damage_multiplier,
+            combat_stats: &combat_stats,
+            progression: &progression,
+        };
+        let (direction, angle) = aim(&ctx);
+        spawn_energy_bolt(&mut params, &ctx, direction, angle, turret.faction);
+    }
+}
+
+pub struct ArcaneTurretSpell;
+
+impl SpellDefinition for ArcaneTurretSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::ArcaneTurret
+    }
+    fn name(&self) -> &'static str {
+        "spell.arcane_turret.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.arcane_turret.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.arcane_turret.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_turret.png"
+    }
+    fn cooldown(&self) -> f32 {
+        arcane_turret::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        arcane_turret::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        // Each bolt is an Energy Bolt at the turret's damage share
+        Some(HitProfile {
+            damage: energy_bolt::DAMAGE * arcane_turret::DAMAGE_MULTIPLIER,
+            hits: arcane_turret::DURATION / arcane_turret::FIRE_INTERVAL,
+            lifesteal_rate: 1.0,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_arcane_turret(params, ctx, faction);
+    }
+}
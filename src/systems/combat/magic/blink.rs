@@ -1,5 +1,41 @@
-use crate::systems::combat::CombatContext;
+use super::registry::SpellDefinition;
+use crate::components::weapon::{Faction, SpellType};
+use crate::configs::spells::blink;
+use crate::systems::combat::stat_calc::HitProfile;
+use crate::systems::combat::{CombatContext, CombatInputParams};
 
 pub const fn perform_blink(ctx: &mut CombatContext) {
     ctx.transform.translation = ctx.cursor_pos.extend(0.0);
 }
+
+pub struct BlinkSpell;
+
+impl SpellDefinition for BlinkSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::Blink
+    }
+    fn name(&self) -> &'static str {
+        "spell.blink.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.blink.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.blink.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_blink.png"
+    }
+    fn cooldown(&self) -> f32 {
+        blink::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        blink::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        None
+    }
+    fn cast(&self, _params: &mut CombatInputParams, ctx: &mut CombatContext, _faction: Faction) {
+        perform_blink(ctx);
+    }
+}
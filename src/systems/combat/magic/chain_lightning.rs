@@ -0,0 +1,160 @@
+use super::nearest_enemy;
+use super::registry::SpellDefinition;
+use crate::components::enemy::Enemy;
+use crate::components::physics::{Collider, UniformGrid};
+use crate::components::player::Player;
+use crate::components::weapon::{
+    ChainLightning, Faction, Lifetime, Projectile, SpellType, WeaponType,
+};
+use crate::configs::spells::chain_lightning;
+use crate::resources::cached_assets::CachedAssets;
+use crate::systems::combat::stat_calc::HitProfile;
+use crate::systems::combat::{CombatContext, CombatInputParams};
+use crate::visuals::world::spawn_chain_lightning_visuals;
+use bevy::prelude::*;
+
+pub fn spawn_chain_lightning(
+    params: &mut CombatInputParams,
+    ctx: &CombatContext,
+    faction: Faction,
+) {
+    params.commands.spawn(ChainLightning {
+        hop_timer: Timer::from_seconds(chain_lightning::HOP_INTERVAL, TimerMode::Repeating),
+        hops_left: chain_lightning::MAX_HOPS,
+        damage: chain_lightning::DAMAGE * ctx.damage_multiplier,
+        position: ctx.spawn_pos,
+        search_center: ctx.cursor_pos,
+        search_range: chain_lightning::CAST_RANGE,
+        hit_entities: Vec::new(),
+        owner_entity: ctx.owner_entity,
+        hand: ctx.hand,
+        faction,
+        crit_chance: ctx.combat_stats.crit_chance,
+        crit_damage: ctx.combat_stats.crit_damage,
+    });
+}
+
+/// Resolves one hop per tick: finds the next target, spawns a hit projectile on it and draws the arc.
+/// Player lightning hops between enemies found through `UniformGrid`, enemy lightning can only reach the player.
+#[allow(clippy::needless_pass_by_value)]
+pub fn chain_lightning_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    grid: Res<UniformGrid>,
+    cached_assets: Res<CachedAssets>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    player: Single<(Entity, &Transform), With<Player>>,
+    mut chain_query: Query<(Entity, &mut ChainLightning)>,
+) {
+    let (player_entity, player_transform) = *player;
+    let player_pos = player_transform.translation.truncate();
+
+    for (entity, mut chain) in &mut chain_query {
+        chain.hop_timer.tick(time.delta());
+        if !chain.hop_timer.just_finished() {
+            continue;
+        }
+
+        let target = match chain.faction {
+            Faction::Player => nearest_enemy(
+                &grid,
+                chain.search_center,
+                chain.search_range,
+                &chain.hit_entities,
+                |e| enemy_query.get(e).ok().map(|t| t.translation.truncate()),
+            ),
+            Faction::Enemy => (!chain.hit_entities.contains(&player_entity)
+                && player_pos.distance(chain.search_center) <= chain.search_range)
+                .then_some((player_entity, player_pos)),
+        };
+
+        let Some((target_entity, target_pos)) = target else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        // Damage goes through the regular collision pipeline
+        commands.spawn((
+            Transform::from_translation(target_pos.extend(0.0)),
+            Visibility::Visible,
+            Collider::ball(chain_lightning::HIT_RADIUS),
+            Projectile {
+                kind: WeaponType::Magic,
+                damage: chain.damage,
+                speed: 0.0,
+                direction: Vec2::ZERO,
+                owner_entity: chain.owner_entity,
+                hand: chain.hand,
+                is_aoe: false,
+                faction: chain.faction,
+                crit_chance: chain.crit_chance,
+                crit_damage: chain.crit_damage,
+                lifesteal_efficiency: 1.0,
+            },
+            Lifetime {
+                timer: Timer::from_seconds(chain_lightning::HIT_LIFETIME, TimerMode::Once),
+            },
+        ));
+
+        let delta = target_pos - chain.position;
+        commands
+            .spawn((
+                Transform::from_translation(chain.position.extend(0.0))
+                    .with_rotation(Quat::from_rotation_z(delta.y.atan2(delta.x))),
+                Visibility::Visible,
+                Lifetime {
+                    timer: Timer::from_seconds(chain_lightning::ARC_LIFETIME, TimerMode::Once),
+                },
+            ))
+            .with_children(|parent| {
+                spawn_chain_lightning_visuals(parent, &cached_assets, delta.length());
+            });
+
+        chain.hit_entities.push(target_entity);
+        chain.position = target_pos;
+        chain.search_center = target_pos;
+        chain.search_range = chain_lightning::HOP_RANGE;
+        chain.damage *= chain_lightning::DAMAGE_FALLOFF;
+        chain.hops_left = chain.hops_left.saturating_sub(1);
+
+        if chain.hops_left == 0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct ChainLightningSpell;
+
+impl SpellDefinition for ChainLightningSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::ChainLightning
+    }
+    fn name(&self) -> &'static str {
+        "spell.chain_lightning.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.chain_lightning.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.chain_lightning.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_chain_lightning.png"
+    }
+    fn cooldown(&self) -> f32 {
+        chain_lightning::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        chain_lightning::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: chain_lightning::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: 1.0,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_chain_lightning(params, ctx, faction);
+    }
+}
@@ -1,6 +1,10 @@
+use super::registry::{SpellDefinition, aim};
 use crate::components::physics::{Collider, Velocity};
-use crate::components::weapon::{ExplodingProjectile, Faction, Lifetime, Projectile, WeaponType};
+use crate::components::weapon::{
+    ExplodingProjectile, Faction, Lifetime, Projectile, SpellType, WeaponType,
+};
 use crate::configs::spells::energy_bolt;
+use crate::systems::combat::stat_calc::HitProfile;
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use crate::visuals::world::spawn_energy_bolt_visuals;
 use bevy::prelude::*;
@@ -10,6 +14,7 @@ pub fn spawn_energy_bolt(
     ctx: &CombatContext,
     direction: Vec2,
     angle: f32,
+    faction: Faction,
 ) {
     params
         .commands
@@ -28,8 +33,9 @@ pub fn spawn_energy_bolt(
                 speed: energy_bolt::SPEED,
                 direction,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: false, // Initial hit is single-target, explosion is AOE
-                faction: Faction::Player,
+                faction,
                 crit_chance: ctx.combat_stats.crit_chance,
                 crit_damage: ctx.combat_stats.crit_damage,
                 lifesteal_efficiency: 1.0,
@@ -46,3 +52,40 @@ pub fn spawn_energy_bolt(
             spawn_energy_bolt_visuals(parent, &params.cached_assets);
         });
 }
+
+pub struct EnergyBoltSpell;
+
+impl SpellDefinition for EnergyBoltSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::EnergyBolt
+    }
+    fn name(&self) -> &'static str {
+        "spell.energy_bolt.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.energy_bolt.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.energy_bolt.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_bolt.png"
+    }
+    fn cooldown(&self) -> f32 {
+        energy_bolt::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        energy_bolt::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: energy_bolt::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: 1.0,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        let (direction, angle) = aim(ctx);
+        spawn_energy_bolt(params, ctx, direction, angle, faction);
+    }
+}
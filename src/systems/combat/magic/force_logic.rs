@@ -1,10 +1,12 @@
 // use crate::components::enemy::Enemy; // Removed unused import
+use super::registry::SpellDefinition;
 use crate::components::physics::{Collider, IgnoreGrid, Velocity};
 use crate::components::status::UnitStatus;
 use crate::components::weapon::{
-    AoEProjectile, Faction, ForcePull, ForcePush, Lifetime, Projectile, WeaponType,
+    AoEProjectile, Faction, ForcePull, ForcePush, Lifetime, Projectile, SpellType, WeaponType,
 };
 use crate::configs::spells::force;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
 use crate::systems::combat::{CollisionEvent, CombatContext, CombatInputParams};
 use crate::visuals::world::{spawn_force_pull_visuals, spawn_force_push_visuals};
 use bevy::prelude::*;
@@ -22,6 +24,7 @@ pub fn spawn_force_push(params: &mut CombatInputParams, ctx: &CombatContext, fac
                 speed: 0.0,
                 direction: Vec2::ZERO,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: true,
                 faction,
                 crit_chance: ctx.combat_stats.crit_chance,
@@ -58,6 +61,7 @@ pub fn spawn_force_pull(params: &mut CombatInputParams, ctx: &CombatContext, fac
                 speed: 0.0,
                 direction: Vec2::ZERO,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: true,
                 faction,
                 crit_chance: ctx.combat_stats.crit_chance,
@@ -134,6 +138,78 @@ pub fn force_effect_observer(
     }
 }
 
+pub struct ForcePushSpell;
+
+impl SpellDefinition for ForcePushSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::ForcePush
+    }
+    fn name(&self) -> &'static str {
+        "spell.force_push.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.force_push.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.force_push.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_push.png"
+    }
+    fn cooldown(&self) -> f32 {
+        force::PUSH_COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        force::PUSH_MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: force::DAMAGE_BASE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_force_push(params, ctx, faction);
+    }
+}
+
+pub struct ForcePullSpell;
+
+impl SpellDefinition for ForcePullSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::ForcePull
+    }
+    fn name(&self) -> &'static str {
+        "spell.force_pull.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.force_pull.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.force_pull.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_pull.png"
+    }
+    fn cooldown(&self) -> f32 {
+        force::PULL_COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        force::PULL_MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: force::DAMAGE_BASE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_force_pull(params, ctx, faction);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,110 @@
+use super::registry::SpellDefinition;
+use crate::components::physics::{Collider, IgnoreGrid};
+use crate::components::status::UnitStatus;
+use crate::components::weapon::{
+    AoEProjectile, Faction, FrostRing, Lifetime, Projectile, SpellType, WeaponType,
+};
+use crate::configs::spells::frost_ring;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
+use crate::systems::combat::{CollisionEvent, CombatContext, CombatInputParams};
+use crate::visuals::world::spawn_frost_ring_visuals;
+use bevy::prelude::*;
+
+pub fn spawn_frost_ring(params: &mut CombatInputParams, ctx: &CombatContext, faction: Faction) {
+    params
+        .commands
+        .spawn((
+            Transform::from_translation(ctx.transform.translation)
+                .with_scale(Vec3::splat(frost_ring::START_RADIUS)),
+            Visibility::Visible,
+            Collider::ball(frost_ring::START_RADIUS),
+            Projectile {
+                kind: WeaponType::Magic,
+                damage: frost_ring::DAMAGE * ctx.damage_multiplier,
+                speed: 0.0,
+                direction: Vec2::ZERO,
+                owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
+                is_aoe: true,
+                faction,
+                crit_chance: ctx.combat_stats.crit_chance,
+                crit_damage: ctx.combat_stats.crit_damage,
+                lifesteal_efficiency: 0.5,
+            },
+            Lifetime {
+                timer: Timer::from_seconds(frost_ring::EXPAND_DURATION, TimerMode::Once),
+            },
+            AoEProjectile::default(),
+            IgnoreGrid,
+            FrostRing {
+                start_radius: frost_ring::START_RADIUS,
+                max_radius: frost_ring::MAX_RADIUS,
+            },
+        ))
+        .with_children(|parent| {
+            spawn_frost_ring_visuals(parent, &params.cached_assets);
+        });
+}
+
+/// Grows the ring's collider and visuals over its lifetime
+pub fn expand_frost_ring_system(
+    mut query: Query<(&mut Transform, &mut Collider, &FrostRing, &Lifetime)>,
+) {
+    for (mut transform, mut collider, ring, lifetime) in &mut query {
+        let radius = (ring.max_radius - ring.start_radius)
+            .mul_add(lifetime.timer.fraction(), ring.start_radius);
+        *collider = Collider::ball(radius);
+        transform.scale = Vec3::splat(radius);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn frost_slow_observer(
+    trigger: On<CollisionEvent>,
+    ring_query: Query<(), With<FrostRing>>,
+    mut status_query: Query<&mut UnitStatus>,
+) {
+    let event = trigger.event();
+    if !ring_query.contains(event.projectile) {
+        return;
+    }
+    if let Ok(mut status) = status_query.get_mut(event.target) {
+        status.slow(frost_ring::SLOW_FACTOR, frost_ring::SLOW_DURATION);
+    }
+}
+
+pub struct FrostRingSpell;
+
+impl SpellDefinition for FrostRingSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::FrostRing
+    }
+    fn name(&self) -> &'static str {
+        "spell.frost_ring.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.frost_ring.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.frost_ring.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_frost_ring.png"
+    }
+    fn cooldown(&self) -> f32 {
+        frost_ring::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        frost_ring::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: frost_ring::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_frost_ring(params, ctx, faction);
+    }
+}
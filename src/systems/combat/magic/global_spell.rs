@@ -1,6 +1,10 @@
+use super::registry::SpellDefinition;
 use crate::components::physics::{Collider, IgnoreGrid};
-use crate::components::weapon::{AoEProjectile, Faction, Lifetime, Projectile, WeaponType};
+use crate::components::weapon::{
+    AoEProjectile, Faction, Lifetime, Projectile, SpellType, WeaponType,
+};
 use crate::configs::spells::global;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use crate::visuals::world::spawn_global_visuals;
 use bevy::prelude::*;
@@ -18,6 +22,7 @@ pub fn spawn_global_spell(params: &mut CombatInputParams, ctx: &CombatContext, f
                 speed: 0.0,
                 direction: Vec2::ZERO,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: true,
                 faction,
                 crit_chance: ctx.combat_stats.crit_chance,
@@ -34,3 +39,39 @@ pub fn spawn_global_spell(params: &mut CombatInputParams, ctx: &CombatContext, f
             spawn_global_visuals(parent, &params.cached_assets);
         });
 }
+
+pub struct GlobalSpell;
+
+impl SpellDefinition for GlobalSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::Global
+    }
+    fn name(&self) -> &'static str {
+        "spell.global.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.global.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.global.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_global.png"
+    }
+    fn cooldown(&self) -> f32 {
+        global::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        global::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: global::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_global_spell(params, ctx, faction);
+    }
+}
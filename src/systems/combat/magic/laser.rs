@@ -1,6 +1,10 @@
+use super::registry::{SpellDefinition, aim};
 use crate::components::physics::{Collider, IgnoreGrid};
-use crate::components::weapon::{AoEProjectile, Faction, Lifetime, Projectile, WeaponType};
+use crate::components::weapon::{
+    AoEProjectile, Faction, Lifetime, Projectile, SpellType, WeaponType,
+};
 use crate::configs::spells::laser;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use crate::visuals::world::spawn_laser_visuals;
 use bevy::prelude::*;
@@ -10,6 +14,7 @@ pub fn spawn_laser(
     ctx: &CombatContext,
     direction: Vec2,
     angle: f32,
+    faction: Faction,
 ) {
     params
         .commands
@@ -24,8 +29,9 @@ pub fn spawn_laser(
                 speed: 0.0,
                 direction,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: true,
-                faction: Faction::Player,
+                faction,
                 crit_chance: ctx.combat_stats.crit_chance,
                 crit_damage: ctx.combat_stats.crit_damage,
                 lifesteal_efficiency: 0.5,
@@ -40,3 +46,40 @@ pub fn spawn_laser(
             spawn_laser_visuals(parent, &params.cached_assets);
         });
 }
+
+pub struct LaserSpell;
+
+impl SpellDefinition for LaserSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::Laser
+    }
+    fn name(&self) -> &'static str {
+        "spell.laser.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.laser.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.laser.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_laser.png"
+    }
+    fn cooldown(&self) -> f32 {
+        laser::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        laser::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: laser::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        let (direction, angle) = aim(ctx);
+        spawn_laser(params, ctx, direction, angle, faction);
+    }
+}
@@ -0,0 +1,127 @@
+use super::registry::SpellDefinition;
+use crate::components::physics::{Collider, IgnoreGrid};
+use crate::components::weapon::{
+    AoEProjectile, Faction, Lifetime, MeteorStrike, MeteorTelegraphFill, Projectile, SpellType,
+    WeaponType,
+};
+use crate::configs::spells::meteor;
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::polish::ScreenShake;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
+use crate::systems::combat::{CombatContext, CombatInputParams};
+use crate::visuals::world::{spawn_meteor_impact_visuals, spawn_meteor_telegraph_visuals};
+use bevy::prelude::*;
+
+pub fn spawn_meteor(params: &mut CombatInputParams, ctx: &CombatContext, faction: Faction) {
+    params
+        .commands
+        .spawn((
+            Transform::from_translation(ctx.cursor_pos.extend(0.0)),
+            Visibility::Visible,
+            MeteorStrike {
+                timer: Timer::from_seconds(meteor::IMPACT_DELAY, TimerMode::Once),
+                damage: meteor::DAMAGE * ctx.damage_multiplier,
+                radius: meteor::RADIUS,
+                owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
+                faction,
+                crit_chance: ctx.combat_stats.crit_chance,
+                crit_damage: ctx.combat_stats.crit_damage,
+            },
+        ))
+        .with_children(|parent| {
+            spawn_meteor_telegraph_visuals(parent, &params.cached_assets, meteor::RADIUS);
+        });
+}
+
+/// Grows the telegraph fill and replaces the telegraph with an `AoE` impact when the delay ends
+#[allow(clippy::needless_pass_by_value)]
+pub fn meteor_impact_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    cached_assets: Res<CachedAssets>,
+    mut shake: ResMut<ScreenShake>,
+    mut strike_query: Query<(Entity, &Transform, &mut MeteorStrike, &Children)>,
+    mut fill_query: Query<&mut Transform, (With<MeteorTelegraphFill>, Without<MeteorStrike>)>,
+) {
+    for (entity, transform, mut strike, children) in &mut strike_query {
+        strike.timer.tick(time.delta());
+
+        for &child in children {
+            if let Ok(mut fill) = fill_query.get_mut(child) {
+                fill.scale = Vec3::splat(strike.radius * strike.timer.fraction());
+            }
+        }
+
+        if !strike.timer.is_finished() {
+            continue;
+        }
+
+        commands
+            .spawn((
+                Transform::from_translation(transform.translation),
+                Visibility::Visible,
+                Collider::ball(strike.radius),
+                Projectile {
+                    kind: WeaponType::Magic,
+                    damage: strike.damage,
+                    speed: 0.0,
+                    direction: Vec2::ZERO,
+                    owner_entity: strike.owner_entity,
+                    hand: strike.hand,
+                    is_aoe: true,
+                    faction: strike.faction,
+                    crit_chance: strike.crit_chance,
+                    crit_damage: strike.crit_damage,
+                    lifesteal_efficiency: 0.5,
+                },
+                Lifetime {
+                    timer: Timer::from_seconds(meteor::IMPACT_LIFETIME, TimerMode::Once),
+                },
+                AoEProjectile::default(),
+                IgnoreGrid,
+            ))
+            .with_children(|parent| {
+                spawn_meteor_impact_visuals(parent, &cached_assets, strike.radius);
+            });
+
+        shake.trauma = (shake.trauma + meteor::SCREEN_SHAKE).min(1.0);
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct MeteorSpell;
+
+impl SpellDefinition for MeteorSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::Meteor
+    }
+    fn name(&self) -> &'static str {
+        "spell.meteor.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.meteor.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.meteor.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_meteor.png"
+    }
+    fn cooldown(&self) -> f32 {
+        meteor::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        meteor::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: meteor::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        spawn_meteor(params, ctx, faction);
+    }
+}
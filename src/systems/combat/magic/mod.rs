@@ -1,26 +1,36 @@
-use crate::components::player::{CombatStats, Hand, HandType, Player, PlayerStats, Progression};
-use crate::components::weapon::{
-    ActiveSpellSlot, Faction, MagicLoadout, SpellType, Weapon, WeaponType,
+use crate::components::physics::UniformGrid;
+use crate::components::player::{
+    CombatStats, Hand, HandType, Mana, Player, PlayerStats, Progression,
 };
+use crate::components::weapon::{ActiveSpellSlot, Faction, MagicLoadout, Weapon, WeaponType};
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use bevy::prelude::*;
 
+pub mod arcane_turret;
 pub mod blink;
+pub mod chain_lightning;
 pub mod energy_bolt;
 pub mod force_logic;
+pub mod frost_ring;
 pub mod global_spell;
 pub mod laser;
+pub mod meteor;
 pub mod nova;
+pub mod registry;
 
+pub use registry::{SPELL_REGISTRY, SpellDefinition};
+
+#[allow(clippy::type_complexity)]
 pub fn magic_weapon_system(
     mut params: CombatInputParams,
-    mut player: Single<
+    player: Single<
         (
             Entity,
             &mut Transform,
             &PlayerStats,
             &CombatStats,
             &Progression,
+            &mut Mana,
         ),
         With<Player>,
     >,
@@ -28,11 +38,9 @@ pub fn magic_weapon_system(
 ) {
     let cursor_pos = params.virtual_input.cursor_world;
 
-    let player_entity = player.0;
-    let stats = player.2;
-    let combat_stats = player.3;
-    let progression = player.4;
-    let player_transform = &mut *player.1;
+    let (player_entity, mut player_transform, stats, combat_stats, progression, mut mana) =
+        player.into_inner();
+    let player_transform = &mut *player_transform;
 
     let q_just_pressed = params.virtual_input.left_skill;
     let e_just_pressed = params.virtual_input.right_skill;
@@ -62,20 +70,16 @@ pub fn magic_weapon_system(
         }
 
         let now = params.time.elapsed_secs();
-        let effective_cooldown = weapon_data.cooldown * (1.0 - combat_stats.cooldown_reduction);
+        let spell = magic_loadout.active_spell().definition();
+        let effective_cooldown = spell.cooldown() * (1.0 - combat_stats.cooldown_reduction);
 
         // Fire logic
-        if now - weapon_data.last_shot >= effective_cooldown {
-            let spell_to_cast = match magic_loadout.active_slot {
-                ActiveSpellSlot::Primary => magic_loadout.primary,
-                ActiveSpellSlot::Secondary => magic_loadout.secondary,
-            };
-
-            cast_spell(
+        if now - weapon_data.last_shot >= effective_cooldown && mana.try_spend(spell.mana_cost()) {
+            spell.cast(
                 &mut params,
-                spell_to_cast,
-                CombatContext {
+                &mut CombatContext {
                     owner_entity: player_entity,
+                    hand: Some(hand.side),
                     transform: &mut *player_transform,
                     cursor_pos,
                     spawn_pos: hand_pos,
@@ -83,43 +87,40 @@ pub fn magic_weapon_system(
                     combat_stats,
                     progression,
                 },
+                Faction::Player,
             );
             weapon_data.last_shot = now;
         }
     }
 }
 
+/// Regenerates every mana pool (player and enemy casters alike).
 #[allow(clippy::needless_pass_by_value)]
-fn cast_spell(params: &mut CombatInputParams, spell: SpellType, mut ctx: CombatContext) {
-    let direction = (ctx.cursor_pos - ctx.spawn_pos).normalize_or_zero();
-    let angle = direction.y.atan2(direction.x);
-
-    match spell {
-        SpellType::EnergyBolt => {
-            energy_bolt::spawn_energy_bolt(params, &ctx, direction, angle);
-        }
-        SpellType::Laser => {
-            laser::spawn_laser(params, &ctx, direction, angle);
-        }
-        SpellType::Nova => {
-            let explosion_pos = if ctx.progression.nova_core > 0 {
-                ctx.cursor_pos.extend(ctx.transform.translation.z)
-            } else {
-                ctx.transform.translation
-            };
-            nova::spawn_nova(params, &ctx, explosion_pos);
-        }
-        SpellType::Blink => {
-            blink::perform_blink(&mut ctx);
-        }
-        SpellType::Global => {
-            global_spell::spawn_global_spell(params, &ctx, Faction::Player);
-        }
-        SpellType::ForcePush => {
-            force_logic::spawn_force_push(params, &ctx, Faction::Player);
-        }
-        SpellType::ForcePull => {
-            force_logic::spawn_force_pull(params, &ctx, Faction::Player);
-        }
+pub fn mana_regen_system(time: Res<Time>, mut query: Query<&mut Mana>) {
+    for mut mana in &mut query {
+        mana.current = mana
+            .regen
+            .mul_add(time.delta_secs(), mana.current)
+            .min(mana.max);
+        mana.starved_timer.tick(time.delta());
     }
 }
+
+/// Nearest entity in `grid` within `range` of `center`, skipping `exclude`.
+/// `position_of` resolves grid entries to positions (returning `None` drops the candidate).
+pub fn nearest_enemy(
+    grid: &UniformGrid,
+    center: Vec2,
+    range: f32,
+    exclude: &[Entity],
+    position_of: impl Fn(Entity) -> Option<Vec2>,
+) -> Option<(Entity, Vec2)> {
+    grid.query_aabb(center - Vec2::splat(range), center + Vec2::splat(range))
+        .into_iter()
+        .filter(|entity| !exclude.contains(entity))
+        .filter_map(|entity| position_of(entity).map(|pos| (entity, pos)))
+        .map(|(entity, pos)| (entity, pos, pos.distance_squared(center)))
+        .filter(|(_, _, dist_sq)| *dist_sq <= range * range)
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(entity, pos, _)| (entity, pos))
+}
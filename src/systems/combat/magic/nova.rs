@@ -1,10 +1,19 @@
-use crate::components::weapon::{AoEProjectile, Faction, Lifetime, Projectile, WeaponType};
+use super::registry::SpellDefinition;
+use crate::components::weapon::{
+    AoEProjectile, Faction, Lifetime, Projectile, SpellType, WeaponType,
+};
 use crate::configs::spells::nova;
+use crate::systems::combat::stat_calc::{AOE_LIFESTEAL_RATE, HitProfile};
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use crate::visuals::world::spawn_nova_visuals;
 use bevy::prelude::*;
 
-pub fn spawn_nova(params: &mut CombatInputParams, ctx: &CombatContext, explosion_pos: Vec3) {
+pub fn spawn_nova(
+    params: &mut CombatInputParams,
+    ctx: &CombatContext,
+    explosion_pos: Vec3,
+    faction: Faction,
+) {
     params
         .commands
         .spawn((
@@ -17,8 +26,9 @@ pub fn spawn_nova(params: &mut CombatInputParams, ctx: &CombatContext, explosion
                 speed: 0.0,
                 direction: Vec2::ZERO,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: true,
-                faction: Faction::Player,
+                faction,
                 crit_chance: ctx.combat_stats.crit_chance,
                 crit_damage: ctx.combat_stats.crit_damage,
                 lifesteal_efficiency: 0.5,
@@ -32,3 +42,45 @@ pub fn spawn_nova(params: &mut CombatInputParams, ctx: &CombatContext, explosion
             spawn_nova_visuals(parent, &params.cached_assets);
         });
 }
+
+pub struct NovaSpell;
+
+impl SpellDefinition for NovaSpell {
+    fn kind(&self) -> SpellType {
+        SpellType::Nova
+    }
+    fn name(&self) -> &'static str {
+        "spell.nova.name"
+    }
+    fn short_name(&self) -> &'static str {
+        "spell.nova.short"
+    }
+    fn description(&self) -> &'static str {
+        "spell.nova.description"
+    }
+    fn icon(&self) -> &'static str {
+        "ui/icons/magic_nova.png"
+    }
+    fn cooldown(&self) -> f32 {
+        nova::COOLDOWN
+    }
+    fn mana_cost(&self) -> f32 {
+        nova::MANA_COST
+    }
+    fn hit_profile(&self) -> Option<HitProfile> {
+        Some(HitProfile {
+            damage: nova::DAMAGE,
+            hits: 1.0,
+            lifesteal_rate: AOE_LIFESTEAL_RATE,
+        })
+    }
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction) {
+        // Nova Core moves the burst from the caster to the cursor
+        let explosion_pos = if ctx.progression.nova_core > 0 {
+            ctx.cursor_pos.extend(ctx.transform.translation.z)
+        } else {
+            ctx.transform.translation
+        };
+        spawn_nova(params, ctx, explosion_pos, faction);
+    }
+}
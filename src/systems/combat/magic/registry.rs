@@ -0,0 +1,78 @@
+//! Spell registry
+//! Every spell bundles its display data (as `Locale` keys) and cast behaviour behind `SpellDefinition`.
+//! The magic system, arsenal palette and HUD all read from `SPELL_REGISTRY`,
+//! so adding a spell means adding a `SpellType` variant and one definition here.
+
+use super::{
+    arcane_turret, blink, chain_lightning, energy_bolt, force_logic, frost_ring, global_spell,
+    laser, meteor, nova,
+};
+use crate::components::weapon::{Faction, SpellType};
+use crate::systems::combat::stat_calc::HitProfile;
+use crate::systems::combat::{CombatContext, CombatInputParams};
+use bevy::prelude::*;
+
+pub trait SpellDefinition: Sync {
+    fn kind(&self) -> SpellType;
+    /// `Locale` key of the full display name (palette, descriptions)
+    fn name(&self) -> &'static str;
+    /// `Locale` key of the compact name for slot cards
+    fn short_name(&self) -> &'static str;
+    /// `Locale` key
+    fn description(&self) -> &'static str;
+    fn icon(&self) -> &'static str;
+    /// Base cooldown in seconds, before cooldown reduction
+    fn cooldown(&self) -> f32;
+    /// Mana drained from the caster's pool per cast
+    fn mana_cost(&self) -> f32;
+    /// Damage one cast deals to a single target, `None` for utility spells
+    fn hit_profile(&self) -> Option<HitProfile>;
+    fn cast(&self, params: &mut CombatInputParams, ctx: &mut CombatContext, faction: Faction);
+}
+
+/// Ordered like `SpellType` so lookups can index directly.
+pub static SPELL_REGISTRY: [&dyn SpellDefinition; 11] = [
+    &energy_bolt::EnergyBoltSpell,
+    &laser::LaserSpell,
+    &nova::NovaSpell,
+    &blink::BlinkSpell,
+    &global_spell::GlobalSpell,
+    &force_logic::ForcePushSpell,
+    &force_logic::ForcePullSpell,
+    &meteor::MeteorSpell,
+    &frost_ring::FrostRingSpell,
+    &chain_lightning::ChainLightningSpell,
+    &arcane_turret::ArcaneTurretSpell,
+];
+
+impl SpellType {
+    pub fn definition(self) -> &'static dyn SpellDefinition {
+        SPELL_REGISTRY[self as usize]
+    }
+}
+
+/// Aim direction and rotation angle from the caster's spawn point towards the cursor.
+pub fn aim(ctx: &CombatContext) -> (Vec2, f32) {
+    let direction = (ctx.cursor_pos - ctx.spawn_pos).normalize_or_zero();
+    (direction, direction.y.atan2(direction.x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_order_matches_spell_type() {
+        for (index, def) in SPELL_REGISTRY.iter().enumerate() {
+            assert_eq!(def.kind() as usize, index, "{} is out of order", def.name());
+            assert_eq!(def.kind().definition().name(), def.name());
+        }
+    }
+
+    #[test]
+    fn test_heavy_spells_cost_more_mana() {
+        let bolt = SpellType::EnergyBolt.definition().mana_cost();
+        assert!(SpellType::Laser.definition().mana_cost() > bolt);
+        assert!(SpellType::Global.definition().mana_cost() > bolt);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::components::enemy::Enemy;
+use crate::components::enemy::{Enemy, EnemyKind};
 use crate::components::physics::UniformGrid;
-use crate::components::player::{CombatStats, Player, Progression};
-use crate::components::weapon::{Lifetime, Projectile};
+use crate::components::player::{CombatStats, HandType, Player, Progression};
+use crate::components::weapon::{Lifetime, Projectile, WeaponType};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
@@ -11,7 +11,9 @@ pub mod events;
 pub mod gun;
 pub mod magic;
 pub mod player_collision;
+pub mod relics;
 pub mod shuriken;
+pub mod stat_calc;
 pub mod sword;
 pub mod sword_mechanics;
 pub mod yellow_ai;
@@ -21,6 +23,7 @@ pub use events::*;
 pub use gun::*;
 pub use magic::*;
 pub use player_collision::*;
+pub use relics::*;
 pub use shuriken::*;
 pub use sword::*;
 pub use sword_mechanics::*;
@@ -30,6 +33,21 @@ pub struct DamageEvent {
     pub entity: Entity,
     pub damage: f32,
     pub crit: bool,
+    pub source: DamageSource,
+    /// Health the player regained through lifesteal from this hit
+    pub healed: f32,
+}
+
+/// What dealt a hit, used for run statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    /// Projectile or sword swing; `hand` is set for player weapons only
+    Weapon {
+        kind: WeaponType,
+        hand: Option<HandType>,
+    },
+    /// Enemy body touching the player
+    Contact(EnemyKind),
 }
 
 /// Marker component for projectiles that have hit a target and should be despawned.
@@ -58,6 +76,8 @@ pub struct CombatInputParams<'w, 's> {
 
 pub struct CombatContext<'a> {
     pub owner_entity: Entity,
+    /// Hand casting or firing; `None` for enemies
+    pub hand: Option<HandType>,
     pub transform: &'a mut Transform,
     pub cursor_pos: Vec2,
     pub spawn_pos: Vec2,
@@ -1,18 +1,31 @@
-use crate::components::enemy::Enemy;
+use crate::components::enemy::{EliteEnemy, Enemy, EnemyKind, YellowEnemy};
 use crate::components::physics::{Collider, check_collision};
 use crate::components::player::{Health, Player};
-use crate::resources::game_state::GameState;
+use crate::systems::combat::{DamageSource, PlayerHitEvent};
 use bevy::prelude::*;
 
 const COLLISION_PUSH_STRENGTH: f32 = 200.0;
 
-#[allow(clippy::unnecessary_wraps, clippy::needless_pass_by_value)]
+#[allow(
+    clippy::unnecessary_wraps,
+    clippy::needless_pass_by_value,
+    clippy::type_complexity
+)]
 pub fn handle_player_collision(
+    mut commands: Commands,
     player: Single<(&mut Health, &mut Transform, &Collider), With<Player>>,
-    mut enemy_query: Query<(Entity, &mut Transform, &Enemy, &Collider), Without<Player>>,
+    mut enemy_query: Query<
+        (
+            &mut Transform,
+            &Enemy,
+            &Collider,
+            Has<EliteEnemy>,
+            Has<YellowEnemy>,
+        ),
+        Without<Player>,
+    >,
     grid: Res<crate::components::physics::UniformGrid>,
     time: Res<Time>,
-    mut next_state: ResMut<NextState<GameState>>,
 ) {
     let (mut health, mut player_transform, player_collider) = player.into_inner();
     health.invulnerability_timer.tick(time.delta());
@@ -23,7 +36,7 @@ pub fn handle_player_collision(
     let nearby_entities = grid.query_nearby(player_pos);
 
     for enemy_entity in nearby_entities {
-        if let Ok((_, mut enemy_transform, enemy, enemy_collider)) =
+        if let Ok((mut enemy_transform, enemy, enemy_collider, elite, yellow)) =
             enemy_query.get_mut(enemy_entity)
         {
             let enemy_pos = enemy_transform.translation.truncate();
@@ -44,16 +57,14 @@ pub fn handle_player_collision(
                     enemy_transform.translation.y -= push_dir.y * push_amount;
                 }
 
-                // Apply damage only if not invulnerable
+                // Skip the event while invulnerable; `player_hit_observer` re-checks anyway
                 if health.invulnerability_timer.is_finished() {
-                    health.current -= enemy.damage;
-                    health.invulnerability_timer.reset();
-                    // Screen shake disabled
-
-                    if health.current <= 0.0 {
-                        health.current = 0.0;
-                        next_state.set(GameState::GameOver);
-                    }
+                    commands.trigger(PlayerHitEvent {
+                        damage: enemy.damage,
+                        crit: false,
+                        source: enemy_pos,
+                        attacker: DamageSource::Contact(EnemyKind::from_markers(elite, yellow)),
+                    });
                 }
             }
         }
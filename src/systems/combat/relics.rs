@@ -0,0 +1,177 @@
+//! Relic trigger hooks
+//! Relics piggyback on the regular combat events; every effect spawns ordinary projectiles
+//! so damage, crits and lifesteal go through the usual collision pipeline.
+
+use super::{DamageEvent, EnemyDeathEvent, ShurikenTeleportEvent};
+use crate::components::physics::{Collider, IgnoreGrid};
+use crate::components::player::{CombatStats, Player, PlayerStats, Progression};
+use crate::components::relic::{Decoy, Relic, RelicTriggers};
+use crate::components::weapon::{
+    AoEProjectile, ChainLightning, Faction, Lifetime, Projectile, WeaponType,
+};
+use crate::configs::relic::{
+    DECOY_DURATION, DECOY_SIZE, STORM_DAMAGE, STORM_HOPS, THORN_DAMAGE, VOLATILE_DAMAGE,
+    VOLATILE_LIFETIME, VOLATILE_RADIUS,
+};
+use crate::configs::spells::{chain_lightning, nova};
+use crate::resources::cached_assets::CachedAssets;
+use crate::visuals::world::{spawn_bolt_explosion_visuals, spawn_nova_visuals};
+use bevy::prelude::*;
+
+type RelicOwner<'w, 's> = Single<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static PlayerStats,
+        &'static CombatStats,
+        &'static Progression,
+        &'static mut RelicTriggers,
+    ),
+    With<Player>,
+>;
+
+const fn relic_projectile(owner: Entity, damage: f32, combat_stats: &CombatStats) -> Projectile {
+    Projectile {
+        kind: WeaponType::Magic,
+        damage,
+        speed: 0.0,
+        direction: Vec2::ZERO,
+        owner_entity: owner,
+        hand: None,
+        is_aoe: true,
+        faction: Faction::Player,
+        crit_chance: combat_stats.crit_chance,
+        crit_damage: combat_stats.crit_damage,
+        lifesteal_efficiency: 0.5,
+    }
+}
+
+/// Volatile Core: dead enemies burst and damage their neighbours
+#[allow(clippy::needless_pass_by_value)]
+pub fn relic_death_observer(
+    trigger: On<EnemyDeathEvent>,
+    mut commands: Commands,
+    res: Res<CachedAssets>,
+    player: RelicOwner,
+) {
+    let (owner, _, stats, combat_stats, progression, _) = player.into_inner();
+    if !progression.has_relic(Relic::VolatileCore) {
+        return;
+    }
+
+    commands
+        .spawn((
+            Transform::from_translation(trigger.event().position.extend(0.0)),
+            Visibility::Visible,
+            relic_projectile(
+                owner,
+                VOLATILE_DAMAGE * stats.damage_multiplier,
+                combat_stats,
+            ),
+            AoEProjectile::default(),
+            Collider::ball(VOLATILE_RADIUS),
+            IgnoreGrid,
+            Lifetime {
+                timer: Timer::from_seconds(VOLATILE_LIFETIME, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            spawn_bolt_explosion_visuals(parent, &res, VOLATILE_RADIUS);
+        });
+}
+
+/// Thorn Heart: a nova around the player whenever they take damage.
+/// Storm Sigil: critical hits on enemies arc chain lightning to their neighbours.
+#[allow(clippy::needless_pass_by_value)]
+pub fn relic_damage_observer(
+    trigger: On<DamageEvent>,
+    mut commands: Commands,
+    res: Res<CachedAssets>,
+    player: RelicOwner,
+    target_query: Query<&Transform, Without<Player>>,
+) {
+    let event = trigger.event();
+    let (owner, transform, stats, combat_stats, progression, mut triggers) = player.into_inner();
+
+    if event.entity == owner {
+        if event.damage > 0.0 && progression.has_relic(Relic::ThornHeart) {
+            commands
+                .spawn((
+                    Transform::from_translation(transform.translation),
+                    Visibility::Visible,
+                    relic_projectile(owner, THORN_DAMAGE * stats.damage_multiplier, combat_stats),
+                    AoEProjectile::default(),
+                    Collider::ball(nova::RADIUS),
+                    Lifetime {
+                        timer: Timer::from_seconds(nova::LIFETIME, TimerMode::Once),
+                    },
+                ))
+                .with_children(|parent| {
+                    spawn_nova_visuals(parent, &res);
+                });
+        }
+        return;
+    }
+
+    if !event.crit
+        || !progression.has_relic(Relic::StormSigil)
+        || !triggers.storm_cooldown.is_finished()
+    {
+        return;
+    }
+    let Ok(target) = target_query.get(event.entity) else {
+        return;
+    };
+
+    triggers.storm_cooldown.reset();
+    let position = target.translation.truncate();
+    commands.spawn(ChainLightning {
+        hop_timer: Timer::from_seconds(chain_lightning::HOP_INTERVAL, TimerMode::Repeating),
+        hops_left: STORM_HOPS,
+        damage: STORM_DAMAGE * stats.damage_multiplier,
+        position,
+        search_center: position,
+        search_range: chain_lightning::HOP_RANGE,
+        // Arc away from the enemy that was crit
+        hit_entities: vec![event.entity],
+        owner_entity: owner,
+        hand: None,
+        faction: Faction::Player,
+        crit_chance: combat_stats.crit_chance,
+        crit_damage: combat_stats.crit_damage,
+    });
+}
+
+/// Mirror Veil: shuriken teleport leaves a decoy where the player stood
+#[allow(clippy::needless_pass_by_value)]
+pub fn relic_teleport_observer(
+    trigger: On<ShurikenTeleportEvent>,
+    mut commands: Commands,
+    res: Res<CachedAssets>,
+    progression: Single<&Progression, With<Player>>,
+) {
+    if !progression.has_relic(Relic::MirrorVeil) {
+        return;
+    }
+
+    commands.spawn((
+        Decoy,
+        Mesh2d(res.unit_square.clone()),
+        MeshMaterial2d(res.mat_cyan_50.clone()),
+        Transform::from_translation(trigger.event().from.extend(-0.1))
+            .with_scale(Vec3::splat(DECOY_SIZE)),
+        Lifetime {
+            timer: Timer::from_seconds(DECOY_DURATION, TimerMode::Once),
+        },
+    ));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn tick_relic_triggers(
+    time: Res<Time>,
+    mut triggers: Single<&mut RelicTriggers, With<Player>>,
+) {
+    triggers.storm_cooldown.tick(time.delta());
+}
@@ -1,4 +1,4 @@
-use super::{CombatContext, CombatInputParams};
+use super::{CombatContext, CombatInputParams, ShurikenTeleportEvent};
 use crate::components::physics::{Collider, Velocity};
 use crate::components::player::{CombatStats, Hand, HandType, Player, PlayerStats, Progression};
 use crate::components::weapon::{Faction, Lifetime, Projectile, Weapon, WeaponType};
@@ -52,6 +52,7 @@ pub fn shuriken_weapon_system(
                 &mut params,
                 &CombatContext {
                     owner_entity: player_entity,
+                    hand: Some(hand.side),
                     transform: &mut *player_transform,
                     cursor_pos,
                     spawn_pos: hand_pos,
@@ -72,6 +73,7 @@ pub fn shuriken_weapon_system(
                 &mut params,
                 &mut CombatContext {
                     owner_entity: player_entity,
+                    hand: Some(hand.side),
                     transform: &mut *player_transform,
                     cursor_pos,
                     spawn_pos: hand_pos,
@@ -126,6 +128,7 @@ pub fn fire_shuriken(
                 speed: shuriken::SPEED,
                 direction,
                 owner_entity: ctx.owner_entity,
+                hand: ctx.hand,
                 is_aoe: false,
                 faction,
                 crit_chance: ctx.combat_stats.crit_chance,
@@ -167,6 +170,7 @@ fn perform_shuriken_skill(params: &mut CombatInputParams, ctx: &mut CombatContex
                 timer: Timer::from_seconds(shuriken::TELEPORT_VISUAL_LIFETIME, TimerMode::Once),
             },
         ));
+        let from = ctx.transform.translation.truncate();
         ctx.transform.translation = shuriken_location.extend(0.0);
         params.commands.spawn((
             Mesh2d(params.cached_assets.unit_circle.clone()),
@@ -178,6 +182,7 @@ fn perform_shuriken_skill(params: &mut CombatInputParams, ctx: &mut CombatContex
             },
         ));
         params.commands.entity(entity).despawn();
+        params.commands.trigger(ShurikenTeleportEvent { from });
         true
     } else {
         false
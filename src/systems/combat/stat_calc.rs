@@ -0,0 +1,224 @@
+//! Effective weapon and spell numbers for the current `PlayerStats`/`CombatStats`
+//! Mirrors the damage pipeline in `collision/damage.rs` for a single target, without
+//! the situational bonuses (Force range bonus, Ambidextrous, relics).
+
+use crate::components::player::{CombatStats, PlayerStats};
+use crate::components::weapon::{MagicLoadout, SpellType, WeaponType};
+use crate::configs::weapons::{gun, shuriken, sword};
+
+/// Lifesteal share of area hits: half efficiency, halved again by the area penalty
+pub const AOE_LIFESTEAL_RATE: f32 = 0.25;
+
+/// What one use of an attack does to a single target, before the player's stats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitProfile {
+    pub damage: f32,
+    /// Hits one target takes from a single use, e.g. a turret's whole volley
+    pub hits: f32,
+    /// Fraction of `CombatStats::lifesteal` this source heals for
+    pub lifesteal_rate: f32,
+}
+
+/// An attack's numbers with the player's stats applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackStats {
+    pub hit: f32,
+    pub crit_hit: f32,
+    /// Average hit over `crit_chance`
+    pub expected_hit: f32,
+    pub cooldown: f32,
+    pub dps: f32,
+    /// Average health restored per hit
+    pub heal_per_hit: f32,
+}
+
+impl AttackStats {
+    /// `reducible` marks cooldowns shortened by `cooldown_reduction`, which only spells are
+    pub fn compute(
+        profile: HitProfile,
+        base_cooldown: f32,
+        reducible: bool,
+        stats: &PlayerStats,
+        combat: &CombatStats,
+    ) -> Self {
+        let hit = profile.damage * stats.damage_multiplier;
+        let crit_hit = hit * combat.crit_damage;
+        let crit_chance = combat.crit_chance.clamp(0.0, 1.0);
+        let expected_hit = crit_chance.mul_add(crit_hit - hit, hit);
+        let cooldown = if reducible {
+            base_cooldown * (1.0 - combat.cooldown_reduction)
+        } else {
+            base_cooldown
+        };
+        let dps = if cooldown > 0.0 {
+            expected_hit * profile.hits / cooldown
+        } else {
+            0.0
+        };
+        Self {
+            hit,
+            crit_hit,
+            expected_hit,
+            cooldown,
+            dps,
+            heal_per_hit: expected_hit * combat.lifesteal * profile.lifesteal_rate,
+        }
+    }
+}
+
+/// Hit profile and cooldown of a weapon's default mode; `None` for Magic, which attacks
+/// with its loadout's spells
+pub const fn weapon_profile(kind: WeaponType) -> Option<(HitProfile, f32)> {
+    match kind {
+        WeaponType::Sword => Some((
+            HitProfile {
+                damage: sword::NORMAL_DAMAGE,
+                hits: 1.0,
+                lifesteal_rate: sword::LIFESTEAL_RATE,
+            },
+            sword::COOLDOWN,
+        )),
+        WeaponType::Gun => Some((
+            HitProfile {
+                damage: gun::SINGLE_DAMAGE,
+                hits: 1.0,
+                lifesteal_rate: 1.0,
+            },
+            gun::STANDARD_COOLDOWN,
+        )),
+        WeaponType::Shuriken => Some((
+            HitProfile {
+                damage: shuriken::DAMAGE,
+                hits: 1.0,
+                lifesteal_rate: 1.0,
+            },
+            shuriken::COOLDOWN,
+        )),
+        WeaponType::Magic => None,
+    }
+}
+
+pub fn weapon_attack(
+    kind: WeaponType,
+    stats: &PlayerStats,
+    combat: &CombatStats,
+) -> Option<AttackStats> {
+    weapon_profile(kind)
+        .map(|(profile, cooldown)| AttackStats::compute(profile, cooldown, false, stats, combat))
+}
+
+/// `None` for utility spells such as Blink
+pub fn spell_attack(
+    spell: SpellType,
+    stats: &PlayerStats,
+    combat: &CombatStats,
+) -> Option<AttackStats> {
+    let definition = spell.definition();
+    definition
+        .hit_profile()
+        .map(|profile| AttackStats::compute(profile, definition.cooldown(), true, stats, combat))
+}
+
+/// Every damaging attack a hand holds, with the `Locale` key of its name
+pub fn hand_attacks(
+    kind: WeaponType,
+    loadout: Option<&MagicLoadout>,
+    stats: &PlayerStats,
+    combat: &CombatStats,
+) -> Vec<(&'static str, AttackStats)> {
+    if kind == WeaponType::Magic {
+        let Some(loadout) = loadout else {
+            return Vec::new();
+        };
+        return [loadout.primary, loadout.secondary]
+            .into_iter()
+            .filter_map(|spell| {
+                spell_attack(spell, stats, combat).map(|attack| (spell.definition().name(), attack))
+            })
+            .collect();
+    }
+    weapon_attack(kind, stats, combat)
+        .map(|attack| vec![(kind.name(), attack)])
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_expected_hit_averages_crits() {
+        let stats = PlayerStats {
+            damage_multiplier: 1.5,
+            ..Default::default()
+        };
+        let combat = CombatStats {
+            crit_chance: 0.25,
+            crit_damage: 3.0,
+            ..Default::default()
+        };
+        let attack = weapon_attack(WeaponType::Shuriken, &stats, &combat).unwrap();
+
+        assert!(close(attack.hit, shuriken::DAMAGE * 1.5));
+        assert!(close(attack.crit_hit, attack.hit * 3.0));
+        // 75% normal hits, 25% triple hits
+        assert!(close(attack.expected_hit, attack.hit * 1.5));
+        assert!(close(attack.dps, attack.expected_hit / shuriken::COOLDOWN));
+    }
+
+    #[test]
+    fn test_cooldown_reduction_only_shortens_spells() {
+        let stats = PlayerStats::default();
+        let combat = CombatStats {
+            cooldown_reduction: 0.5,
+            ..Default::default()
+        };
+        let sword = weapon_attack(WeaponType::Sword, &stats, &combat).unwrap();
+        let nova = spell_attack(SpellType::Nova, &stats, &combat).unwrap();
+
+        assert!(close(sword.cooldown, sword::COOLDOWN));
+        assert!(close(
+            nova.cooldown,
+            SpellType::Nova.definition().cooldown() * 0.5
+        ));
+    }
+
+    #[test]
+    fn test_lifesteal_follows_source_rate() {
+        let stats = PlayerStats::default();
+        let combat = CombatStats {
+            lifesteal: 0.2,
+            ..Default::default()
+        };
+        let gun = weapon_attack(WeaponType::Gun, &stats, &combat).unwrap();
+        let nova = spell_attack(SpellType::Nova, &stats, &combat).unwrap();
+
+        assert!(close(gun.heal_per_hit, gun.expected_hit * 0.2));
+        assert!(close(
+            nova.heal_per_hit,
+            nova.expected_hit * 0.2 * AOE_LIFESTEAL_RATE
+        ));
+    }
+
+    #[test]
+    fn test_magic_hand_lists_damaging_spells() {
+        let loadout = MagicLoadout {
+            primary: SpellType::EnergyBolt,
+            secondary: SpellType::Blink,
+            ..Default::default()
+        };
+        let attacks = hand_attacks(
+            WeaponType::Magic,
+            Some(&loadout),
+            &PlayerStats::default(),
+            &CombatStats::default(),
+        );
+
+        assert_eq!(attacks.len(), 1);
+        assert_eq!(attacks[0].0, SpellType::EnergyBolt.definition().name());
+    }
+}
@@ -1,5 +1,6 @@
 use super::{CombatContext, CombatInputParams};
 use crate::components::player::{CombatStats, Hand, HandType, Player, PlayerStats, Progression};
+use crate::components::relic::{Relic, RelicTriggers};
 use crate::components::weapon::{
     Faction, SwingState, SwordMode, SwordState, SwordSwing, Weapon, WeaponType,
 };
@@ -8,16 +9,17 @@ use crate::visuals::world::{spawn_sword_normal_visuals, spawn_sword_shattered_vi
 use bevy::prelude::*;
 use rand::Rng;
 
-#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
 pub fn sword_weapon_system(
     mut params: CombatInputParams,
-    mut player: Single<
+    player: Single<
         (
             Entity,
             &mut Transform,
             &PlayerStats,
             &CombatStats,
             &Progression,
+            &mut RelicTriggers,
         ),
         With<Player>,
     >,
@@ -31,11 +33,8 @@ pub fn sword_weapon_system(
 ) {
     let cursor_pos = params.virtual_input.cursor_world;
 
-    let player_entity = player.0;
-    let stats = player.2;
-    let combat_stats = player.3;
-    let progression = player.4;
-    let player_transform = &mut *player.1;
+    let (player_entity, mut player_transform, stats, combat_stats, progression, mut triggers) =
+        player.into_inner();
 
     let q_just_pressed = params.virtual_input.left_skill;
     let e_just_pressed = params.virtual_input.right_skill;
@@ -56,19 +55,26 @@ pub fn sword_weapon_system(
 
         // Fire logic (Swing)
         if now - weapon_data.last_shot >= weapon_data.cooldown {
+            // Whetstone turns every Nth swing into a free shattered one
+            let mode = if progression.has_relic(Relic::Whetstone) && triggers.count_sword_swing() {
+                SwordMode::Shattered
+            } else {
+                sword_state.mode
+            };
             fire_sword(
                 &mut params,
                 hand_entity,
                 &CombatContext {
                     owner_entity: player_entity,
-                    transform: &mut *player_transform,
+                    hand: Some(hand.side),
+                    transform: &mut player_transform,
                     cursor_pos,
                     spawn_pos: hand_pos,
                     damage_multiplier: stats.damage_multiplier,
                     combat_stats,
                     progression,
                 },
-                sword_state.mode,
+                mode,
             );
             weapon_data.last_shot = now;
         }
@@ -1,7 +1,8 @@
-use super::DamageEvent;
+use super::{DamageEvent, DamageSource};
 use crate::components::enemy::Enemy;
 use crate::components::player::{CombatStats, Hand, HandType, Health, Player};
-use crate::components::weapon::{Faction, SwingState, SwordSwing};
+use crate::components::weapon::{Faction, SwingState, SwordSwing, WeaponType};
+use crate::configs::weapons::sword;
 
 use bevy::prelude::*;
 use rand::Rng;
@@ -21,13 +22,14 @@ pub fn update_sword_mechanics(
 ) {
     let mut player = player_query;
     for (entity, mut swing, mut transform) in &mut sword_query {
+        let mut hand_side = None;
         if let Ok((hand_transform, hand)) = hand_query.get(swing.hand_entity) {
             let hand_pos = hand_transform.translation().truncate();
-            let hand_side = hand.side;
+            hand_side = Some(hand.side);
 
             // Calculate offset direction (perpendicular to base_angle)
             // If base_angle is forward, side offset is to the right (+90 deg) or left (-90 deg)
-            let side_multiplier = match hand_side {
+            let side_multiplier = match hand.side {
                 HandType::Left => -1.0,
                 HandType::Right => 1.0,
             };
@@ -71,6 +73,7 @@ pub fn update_sword_mechanics(
                                 if dot > 0.0 {
                                     let mut final_damage = swing.damage;
                                     let mut is_crit = false;
+                                    let mut healed = 0.0;
 
                                     {
                                         let (ref mut health, stats) = *player;
@@ -83,9 +86,13 @@ pub fn update_sword_mechanics(
 
                                         // Lifesteal (Sword is AOE, 50% penalty)
                                         if stats.lifesteal > 0.0 {
-                                            let heal = final_damage * stats.lifesteal * 0.5;
+                                            let heal = final_damage
+                                                * stats.lifesteal
+                                                * sword::LIFESTEAL_RATE;
+                                            let before = health.current;
                                             health.current =
                                                 (health.current + heal).min(health.max);
+                                            healed = health.current - before;
                                         }
                                     }
 
@@ -95,6 +102,11 @@ pub fn update_sword_mechanics(
                                         entity: enemy_entity,
                                         damage: final_damage,
                                         crit: is_crit,
+                                        source: DamageSource::Weapon {
+                                            kind: WeaponType::Sword,
+                                            hand: hand_side,
+                                        },
+                                        healed,
                                     });
 
                                     if enemy.health <= 0.0 {
@@ -1,12 +1,19 @@
 use crate::components::enemy::{YellowAi, YellowEnemy};
-use crate::components::player::{CombatStats, Player, PlayerStats, Progression};
-use crate::components::weapon::Faction;
+use crate::components::player::{CombatStats, Mana, Player, PlayerStats, Progression};
+use crate::components::weapon::{Faction, SpellType};
 use crate::configs::enemy;
-use crate::systems::combat::magic::{blink, force_logic};
 use crate::systems::combat::{CombatContext, CombatInputParams};
 use bevy::prelude::*;
 use rand::Rng;
 
+/// Registry spells the Mirror Mage picks from on `spell_timer`
+const YELLOW_SPELL_POOL: [SpellType; 4] = [
+    SpellType::Meteor,
+    SpellType::FrostRing,
+    SpellType::ChainLightning,
+    SpellType::ArcaneTurret,
+];
+
 type YellowEnemyQuery<'w, 's> = Query<
     'w,
     's,
@@ -16,6 +23,7 @@ type YellowEnemyQuery<'w, 's> = Query<
         &'static mut YellowAi,
         &'static CombatStats,
         &'static PlayerStats,
+        &'static mut Mana,
     ),
     (With<YellowEnemy>, Without<Player>),
 >;
@@ -29,15 +37,19 @@ pub fn yellow_ai_system(
     let (player_transform, _player_stats, progression) = *player;
     let player_pos = player_transform.translation.truncate();
 
-    for (enemy_entity, mut enemy_transform, mut ai, combat_stats, enemy_stats) in &mut yellow_query
+    for (enemy_entity, mut enemy_transform, mut ai, combat_stats, enemy_stats, mut mana) in
+        &mut yellow_query
     {
         ai.blink_timer.tick(params.time.delta());
         ai.force_timer.tick(params.time.delta());
+        ai.spell_timer.tick(params.time.delta());
 
         let enemy_pos = enemy_transform.translation.truncate();
 
         // Blink logic
-        if ai.blink_timer.just_finished() {
+        if ai.blink_timer.just_finished()
+            && mana.try_spend(SpellType::Blink.definition().mana_cost())
+        {
             let mut rng = rand::thread_rng();
             let angle = rng.gen_range(0.0..std::f32::consts::TAU);
             let radius = rng.gen_range(200.0..enemy::YELLOW_BLINK_RANGE);
@@ -46,6 +58,7 @@ pub fn yellow_ai_system(
 
             let mut ctx = CombatContext {
                 owner_entity: enemy_entity,
+                hand: None,
                 transform: &mut enemy_transform,
                 cursor_pos: target_pos,
                 spawn_pos: enemy_pos,
@@ -53,13 +66,16 @@ pub fn yellow_ai_system(
                 combat_stats,
                 progression,
             };
-            blink::perform_blink(&mut ctx);
+            SpellType::Blink
+                .definition()
+                .cast(&mut params, &mut ctx, Faction::Enemy);
         }
 
         // Force Push / Pull logic (Smarter choice based on damage/distance)
         if ai.force_timer.just_finished() {
-            let ctx = CombatContext {
+            let mut ctx = CombatContext {
                 owner_entity: enemy_entity,
+                hand: None,
                 transform: &mut enemy_transform,
                 cursor_pos: player_pos,
                 spawn_pos: enemy_pos,
@@ -75,10 +91,36 @@ pub fn yellow_ai_system(
             // Push damage: base + (1 - d/800) * bonus
             // Pull damage: base + (d/800) * bonus
             // Tipping point is d = 400.
-            if dist < 400.0 {
-                force_logic::spawn_force_push(&mut params, &ctx, Faction::Enemy);
+            let spell = if dist < 400.0 {
+                SpellType::ForcePush
             } else {
-                force_logic::spawn_force_pull(&mut params, &ctx, Faction::Enemy);
+                SpellType::ForcePull
+            };
+            let spell = spell.definition();
+            if mana.try_spend(spell.mana_cost()) {
+                spell.cast(&mut params, &mut ctx, Faction::Enemy);
+            }
+        }
+
+        // Registry spell aimed at the player
+        if ai.spell_timer.just_finished() {
+            let mut rng = rand::thread_rng();
+            let spell = YELLOW_SPELL_POOL[rng.gen_range(0..YELLOW_SPELL_POOL.len())].definition();
+            // Position may have changed from a blink this frame
+            let cast_pos = enemy_transform.translation.truncate();
+
+            let mut ctx = CombatContext {
+                owner_entity: enemy_entity,
+                hand: None,
+                transform: &mut enemy_transform,
+                cursor_pos: player_pos,
+                spawn_pos: cast_pos,
+                damage_multiplier: enemy_stats.damage_multiplier * enemy::YELLOW_SPELL_DAMAGE_SCALE,
+                combat_stats,
+                progression,
+            };
+            if mana.try_spend(spell.mana_cost()) {
+                spell.cast(&mut params, &mut ctx, Faction::Enemy);
             }
         }
     }
@@ -1,103 +1,297 @@
+//! Pooled floating damage numbers
+//! Hits on the same target within `DAMAGE_TEXT_MERGE_WINDOW` add up on one rising number,
+//! and at most `DAMAGE_TEXT_POOL_SIZE` number entities ever exist; hidden ones are reused.
+
+use crate::configs::accessibility::crit_text_colors;
+use crate::configs::visuals::{
+    DAMAGE_TEXT_COLOR_NORMAL, DAMAGE_TEXT_LIFETIME, DAMAGE_TEXT_MERGE_WINDOW,
+    DAMAGE_TEXT_OUTLINE_OFFSET, DAMAGE_TEXT_POOL_SIZE, DAMAGE_TEXT_SIZE_CRIT,
+    DAMAGE_TEXT_SIZE_NORMAL, DAMAGE_TEXT_VELOCITY, DAMAGE_TEXT_Z_INDEX,
+};
+use crate::resources::game_settings::{ColorPalette, GameSettings};
 use crate::systems::combat::DamageEvent;
 use bevy::prelude::*;
+use bevy::sprite::Text2dShadow;
+use std::collections::VecDeque;
 
 #[derive(Component)]
 pub struct DamageText {
+    pub target: Entity,
+    /// Total of the merged hits
+    pub amount: f32,
+    pub crit: bool,
+    /// Hits on `target` land on this number until the window closes
+    pub merge_window: Timer,
     pub lifetime: Timer,
     pub velocity: Vec2,
 }
 
+#[derive(Resource, Default)]
+pub struct DamageTextPool {
+    /// Numbers on screen, oldest first
+    pub active: VecDeque<Entity>,
+    /// Hidden numbers ready for reuse
+    pub free: Vec<Entity>,
+}
+
+impl DamageTextPool {
+    /// Active number accepting more hits on `target`, if any
+    fn mergeable(&self, target: Entity, numbers: &Query<&mut DamageText>) -> Option<Entity> {
+        self.active.iter().copied().find(|entity| {
+            numbers
+                .get(*entity)
+                .is_ok_and(|number| number.target == target && !number.merge_window.is_finished())
+        })
+    }
+}
+
+fn number_style(amount: f32, crit: bool, palette: ColorPalette) -> (Text2d, TextFont, TextColor) {
+    (
+        Text2d::new(format!("{amount:.0}")),
+        TextFont {
+            font_size: if crit {
+                DAMAGE_TEXT_SIZE_CRIT
+            } else {
+                DAMAGE_TEXT_SIZE_NORMAL
+            },
+            ..default()
+        },
+        TextColor(if crit {
+            crit_text_colors(palette).0
+        } else {
+            DAMAGE_TEXT_COLOR_NORMAL
+        }),
+    )
+}
+
+const fn crit_outline(palette: ColorPalette) -> Text2dShadow {
+    Text2dShadow {
+        offset: DAMAGE_TEXT_OUTLINE_OFFSET,
+        color: crit_text_colors(palette).1,
+    }
+}
+
+/// Writes a number's text, size, colour and outline from its current total
+fn restyle(commands: &mut Commands, entity: Entity, number: &DamageText, palette: ColorPalette) {
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.insert(number_style(number.amount, number.crit, palette));
+    if number.crit {
+        entity_commands.insert(crit_outline(palette));
+    } else {
+        entity_commands.remove::<Text2dShadow>();
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 pub fn spawn_damage_text(
     trigger: On<DamageEvent>,
     mut commands: Commands,
-    transform_query: Query<&Transform>,
+    settings: Res<GameSettings>,
+    mut pool: ResMut<DamageTextPool>,
+    transform_query: Query<&Transform, Without<DamageText>>,
+    mut numbers: Query<&mut DamageText>,
 ) {
     let event = trigger.event();
-    let Ok(target_transform) = transform_query.get(event.entity) else {
+    if !settings.damage_numbers {
         return;
-    };
-    let position = target_transform.translation.truncate();
+    }
 
-    let size = if event.crit {
-        crate::configs::visuals::DAMAGE_TEXT_SIZE_CRIT
-    } else {
-        crate::configs::visuals::DAMAGE_TEXT_SIZE_NORMAL
+    if let Some(entity) = pool.mergeable(event.entity, &numbers)
+        && let Ok(mut number) = numbers.get_mut(entity)
+    {
+        number.amount += event.damage;
+        number.crit |= event.crit;
+        number.lifetime.reset();
+        restyle(&mut commands, entity, &number, settings.palette);
+        return;
+    }
+
+    let Ok(target_transform) = transform_query.get(event.entity) else {
+        return;
     };
-    let color = if event.crit {
-        Color::srgb(1.0, 0.0, 0.0) // Red
-    } else {
-        Color::srgb(1.0, 1.0, 1.0) // White
+    let position = target_transform
+        .translation
+        .truncate()
+        .extend(DAMAGE_TEXT_Z_INDEX);
+    let number = DamageText {
+        target: event.entity,
+        amount: event.damage,
+        crit: event.crit,
+        merge_window: Timer::from_seconds(DAMAGE_TEXT_MERGE_WINDOW, TimerMode::Once),
+        lifetime: Timer::from_seconds(DAMAGE_TEXT_LIFETIME, TimerMode::Once),
+        velocity: DAMAGE_TEXT_VELOCITY,
     };
 
-    if event.crit {
-        // Spawn white border/shadow layers
-        let offsets = [
-            Vec2::new(-1.0, -1.0),
-            Vec2::new(1.0, -1.0),
-            Vec2::new(-1.0, 1.0),
-            Vec2::new(1.0, 1.0),
-        ];
-
-        for offset in offsets {
-            commands.spawn((
-                Text2d::new(format!("{:.0}", event.damage)),
-                TextFont {
-                    font_size: size,
-                    ..default()
-                },
-                TextColor(Color::srgb(1.0, 1.0, 1.0)), // White border
-                Transform::from_translation(
-                    (position + offset).extend(crate::configs::visuals::DAMAGE_TEXT_Z_INDEX - 1.0), // Slightly behind
-                ),
-                DamageText {
-                    lifetime: Timer::from_seconds(
-                        crate::configs::visuals::DAMAGE_TEXT_LIFETIME,
-                        TimerMode::Once,
-                    ),
-                    velocity: crate::configs::visuals::DAMAGE_TEXT_VELOCITY,
-                },
-            ));
+    // Reuse a hidden number, grow the pool, or recycle the oldest one on screen
+    let reused = pool.free.pop().or_else(|| {
+        (pool.active.len() >= DAMAGE_TEXT_POOL_SIZE)
+            .then(|| pool.active.pop_front())
+            .flatten()
+    });
+    let entity = if let Some(entity) = reused {
+        restyle(&mut commands, entity, &number, settings.palette);
+        commands.entity(entity).insert((
+            number,
+            Transform::from_translation(position),
+            Visibility::Visible,
+        ));
+        entity
+    } else {
+        let entity = commands
+            .spawn((
+                number_style(number.amount, number.crit, settings.palette),
+                Transform::from_translation(position),
+                Visibility::Visible,
+            ))
+            .id();
+        if number.crit {
+            commands
+                .entity(entity)
+                .insert(crit_outline(settings.palette));
         }
-    }
-
-    // Main text
-    commands.spawn((
-        Text2d::new(format!("{:.0}", event.damage)),
-        TextFont {
-            font_size: size,
-            ..default()
-        },
-        TextColor(color),
-        Transform::from_translation(position.extend(crate::configs::visuals::DAMAGE_TEXT_Z_INDEX)),
-        DamageText {
-            lifetime: Timer::from_seconds(
-                crate::configs::visuals::DAMAGE_TEXT_LIFETIME,
-                TimerMode::Once,
-            ),
-            velocity: crate::configs::visuals::DAMAGE_TEXT_VELOCITY,
-        },
-    ));
+        commands.entity(entity).insert(number);
+        entity
+    };
+    pool.active.push_back(entity);
 }
 
 #[allow(clippy::needless_pass_by_value)]
 pub fn update_damage_text(
-    mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &mut TextColor, &mut DamageText)>,
+    settings: Res<GameSettings>,
+    mut pool: ResMut<DamageTextPool>,
+    mut query: Query<(
+        &mut Transform,
+        &mut TextColor,
+        &mut DamageText,
+        &mut Visibility,
+        Option<&mut Text2dShadow>,
+    )>,
 ) {
-    for (entity, mut transform, mut text_color, mut damage_text) in &mut query {
-        damage_text.lifetime.tick(time.delta());
+    let mut released = Vec::new();
+    for &entity in &pool.active {
+        let Ok((mut transform, mut text_color, mut number, mut visibility, shadow)) =
+            query.get_mut(entity)
+        else {
+            continue;
+        };
+        number.merge_window.tick(time.delta());
+        number.lifetime.tick(time.delta());
 
-        if damage_text.lifetime.is_finished() {
-            commands.entity(entity).despawn();
-        } else {
-            transform.translation.x += damage_text.velocity.x * time.delta_secs();
-            transform.translation.y += damage_text.velocity.y * time.delta_secs();
+        if number.lifetime.is_finished() || !settings.damage_numbers {
+            *visibility = Visibility::Hidden;
+            released.push(entity);
+            continue;
+        }
 
-            // Fade out
-            let alpha = damage_text.lifetime.fraction_remaining();
-            text_color.0.set_alpha(alpha);
+        transform.translation += (number.velocity * time.delta_secs()).extend(0.0);
+
+        // Fade out
+        let alpha = number.lifetime.fraction_remaining();
+        text_color.0.set_alpha(alpha);
+        if let Some(mut shadow) = shadow {
+            shadow.color.set_alpha(alpha);
         }
     }
+
+    if released.is_empty() {
+        return;
+    }
+    pool.active.retain(|entity| !released.contains(entity));
+    pool.free.extend(released);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>();
+        app.init_resource::<GameSettings>();
+        app.init_resource::<DamageTextPool>();
+        app.add_observer(spawn_damage_text);
+        app
+    }
+
+    fn hit(app: &mut App, entity: Entity, damage: f32, crit: bool) {
+        app.world_mut().trigger(DamageEvent {
+            entity,
+            damage,
+            crit,
+            source: crate::systems::combat::DamageSource::Contact(
+                crate::components::enemy::EnemyKind::Grunt,
+            ),
+            healed: 0.0,
+        });
+    }
+
+    fn numbers(app: &mut App) -> Vec<(f32, bool)> {
+        let world = app.world_mut();
+        world
+            .query::<&DamageText>()
+            .iter(world)
+            .map(|number| (number.amount, number.crit))
+            .collect()
+    }
+
+    #[test]
+    fn test_rapid_hits_merge_into_one_number() {
+        let mut app = setup();
+        let target = app.world_mut().spawn(Transform::default()).id();
+        hit(&mut app, target, 10.0, false);
+        hit(&mut app, target, 15.0, true);
+
+        assert_eq!(numbers(&mut app), vec![(25.0, true)]);
+    }
+
+    #[test]
+    fn test_crit_outline_adds_no_entities() {
+        let mut app = setup();
+        let target = app.world_mut().spawn(Transform::default()).id();
+        let entity_count = |app: &mut App| {
+            let world = app.world_mut();
+            world.query::<()>().iter(world).count()
+        };
+        let before = entity_count(&mut app);
+        hit(&mut app, target, 10.0, true);
+        hit(&mut app, target, 15.0, true);
+
+        assert_eq!(entity_count(&mut app), before + 1);
+        let world = app.world_mut();
+        assert_eq!(
+            world
+                .query_filtered::<(), (With<DamageText>, With<Text2dShadow>)>()
+                .iter(world)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_pool_caps_numbers_on_screen() {
+        let mut app = setup();
+        for _ in 0..DAMAGE_TEXT_POOL_SIZE + 10 {
+            let target = app.world_mut().spawn(Transform::default()).id();
+            hit(&mut app, target, 5.0, false);
+        }
+
+        assert_eq!(numbers(&mut app).len(), DAMAGE_TEXT_POOL_SIZE);
+        assert_eq!(
+            app.world().resource::<DamageTextPool>().active.len(),
+            DAMAGE_TEXT_POOL_SIZE
+        );
+    }
+
+    #[test]
+    fn test_disabled_setting_spawns_nothing() {
+        let mut app = setup();
+        app.world_mut()
+            .resource_mut::<GameSettings>()
+            .damage_numbers = false;
+        let target = app.world_mut().spawn(Transform::default()).id();
+        hit(&mut app, target, 10.0, false);
+
+        assert!(numbers(&mut app).is_empty());
+    }
 }
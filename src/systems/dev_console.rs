@@ -0,0 +1,467 @@
+//! Developer console, FPS/entity overlay and debug gizmos for balance testing
+//! Only compiled with the `dev_console` cargo feature. Commands are typed after pressing
+//! the backquote key; `help` lists them. Parsing lives in `resources::dev_commands`.
+
+use crate::components::enemy::Enemy;
+use crate::components::physics::{Collider, UniformGrid};
+use crate::components::player::{CombatStats, Currency, Health, Player, PlayerStats};
+use crate::configs::dev_console::{
+    COLLIDER_COLOR, CONSOLE_BG, CONSOLE_FONT_SIZE, CONSOLE_INPUT_COLOR, CONSOLE_TEXT_COLOR,
+    GRID_CELL_COLOR, GRID_FULL_CELL, INPUT_MAX_CHARS, LOG_LINES, OVERLAY_TEXT_COLOR,
+    PLAYER_COLLIDER_COLOR, TOGGLE_KEY,
+};
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::dev_commands::{DevCommand, parse_command};
+use crate::resources::input_settings::VirtualInput;
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::combat::EnemyDeathEvent;
+use crate::systems::enemy::spawn_enemy_at;
+use bevy::diagnostic::{
+    DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+const HELP: &str = "round <n> | gold <n> | spawn <grunt|elite|mage> [count] | killall | god \
+                    | set <stat> <value> | colliders | grid | clear";
+
+#[derive(Resource, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DevConsole {
+    pub open: bool,
+    pub input: String,
+    pub log: Vec<String>,
+    /// Recalled with the up arrow
+    pub last_line: Option<String>,
+    /// Parsed commands waiting for `run_dev_commands`
+    pub pending: Vec<DevCommand>,
+    pub god_mode: bool,
+    pub show_colliders: bool,
+    pub show_grid: bool,
+}
+
+impl DevConsole {
+    pub fn push_log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > LOG_LINES {
+            let excess = self.log.len() - LOG_LINES;
+            self.log.drain(..excess);
+        }
+    }
+
+    /// Echoes and parses the typed line, queueing the command if it is valid
+    fn submit(&mut self) {
+        let line = std::mem::take(&mut self.input);
+        if line.trim().is_empty() {
+            return;
+        }
+        self.push_log(format!("> {line}"));
+        match parse_command(&line) {
+            Ok(command) => self.pending.push(command),
+            Err(message) => self.push_log(message),
+        }
+        self.last_line = Some(line);
+    }
+}
+
+#[derive(Component)]
+pub struct DevConsolePanel;
+
+#[derive(Component)]
+pub struct DevConsoleLogText;
+
+#[derive(Component)]
+pub struct DevConsoleInputText;
+
+/// FPS, entity count and grid occupancy in the top-right corner
+#[derive(Component)]
+pub struct DevOverlayText;
+
+pub fn spawn_dev_console(mut commands: Commands) {
+    let font = TextFont {
+        font_size: CONSOLE_FONT_SIZE,
+        ..default()
+    };
+    commands.spawn((
+        Text::default(),
+        font.clone(),
+        TextColor(OVERLAY_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Right),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(4.0),
+            right: Val::Px(8.0),
+            ..default()
+        },
+        GlobalZIndex(200),
+        DevOverlayText,
+    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                width: Val::Percent(50.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(4.0),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(CONSOLE_BG),
+            GlobalZIndex(200),
+            DevConsolePanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(HELP),
+                font.clone(),
+                TextColor(CONSOLE_TEXT_COLOR),
+                DevConsoleLogText,
+            ));
+            panel.spawn((
+                Text::new("> "),
+                font,
+                TextColor(CONSOLE_INPUT_COLOR),
+                DevConsoleInputText,
+            ));
+        });
+}
+
+/// Reads typed keys: the toggle key, the input line and Enter to run it
+pub fn dev_console_input(mut keys: MessageReader<KeyboardInput>, mut console: ResMut<DevConsole>) {
+    for event in keys.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        if event.key_code == TOGGLE_KEY {
+            console.open = !console.open;
+            continue;
+        }
+        if !console.open {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Escape => console.open = false,
+            Key::Enter => console.submit(),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::ArrowUp => {
+                if let Some(line) = console.last_line.clone() {
+                    console.input = line;
+                }
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => {
+                for c in text.chars().filter(|c| !c.is_control()) {
+                    if console.input.chars().count() < INPUT_MAX_CHARS {
+                        console.input.push(c);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Hides key presses from gameplay and menus while the console has focus. On the
+/// frame it closes, the closing key is still swallowed so Escape doesn't also pause
+#[allow(clippy::needless_pass_by_value)]
+pub fn block_game_keys(
+    console: Res<DevConsole>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut was_open: Local<bool>,
+) {
+    if console.open {
+        keys.reset_all();
+    } else if *was_open {
+        keys.clear();
+    }
+    *was_open = console.open;
+}
+
+/// Executes queued commands against the running game
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
+pub fn run_dev_commands(
+    mut commands: Commands,
+    mut console: ResMut<DevConsole>,
+    mut round_manager: ResMut<RoundManager>,
+    run_settings: Res<RunSettings>,
+    virtual_input: Res<VirtualInput>,
+    cached: Res<CachedAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    player: Single<
+        (
+            &mut Currency,
+            &mut Health,
+            &mut PlayerStats,
+            &mut CombatStats,
+        ),
+        With<Player>,
+    >,
+    mut enemies: Query<(Entity, &mut Enemy, &Transform)>,
+) {
+    if console.pending.is_empty() {
+        return;
+    }
+    let (mut currency, mut health, mut stats, mut combat) = player.into_inner();
+    for command in std::mem::take(&mut console.pending) {
+        let message = match command {
+            DevCommand::Help => HELP.to_string(),
+            DevCommand::SetRound(round) => {
+                round_manager.current_round = round;
+                format!("round set to {round}")
+            }
+            DevCommand::GiveGold(gold) => {
+                currency.gold = currency.gold.saturating_add(gold);
+                format!("gold is now {}", currency.gold)
+            }
+            DevCommand::Spawn { kind, count } => {
+                for _ in 0..count {
+                    spawn_enemy_at(
+                        &mut commands,
+                        &mut meshes,
+                        cached.enemy_material(kind).clone(),
+                        kind,
+                        virtual_input.cursor_world,
+                        round_manager.current_round,
+                        &run_settings,
+                    );
+                }
+                format!("spawned {count} {kind:?}")
+            }
+            DevCommand::KillAll => {
+                let mut killed = 0;
+                for (entity, mut enemy, transform) in &mut enemies {
+                    if enemy.health <= 0.0 {
+                        continue;
+                    }
+                    enemy.health = 0.0;
+                    commands.trigger(EnemyDeathEvent {
+                        entity,
+                        position: transform.translation.truncate(),
+                    });
+                    killed += 1;
+                }
+                format!("killed {killed} enemies")
+            }
+            DevCommand::GodMode => {
+                console.god_mode = !console.god_mode;
+                format!("god mode {}", on_off(console.god_mode))
+            }
+            DevCommand::SetStat { stat, value } => {
+                stat.apply(value, &mut stats, &mut combat, &mut health);
+                format!("{} set to {value}", stat.name())
+            }
+            DevCommand::Colliders => {
+                console.show_colliders = !console.show_colliders;
+                format!("collider gizmos {}", on_off(console.show_colliders))
+            }
+            DevCommand::Grid => {
+                console.show_grid = !console.show_grid;
+                format!("grid occupancy {}", on_off(console.show_grid))
+            }
+            DevCommand::Clear => {
+                console.log.clear();
+                continue;
+            }
+        };
+        console.push_log(message);
+    }
+}
+
+const fn on_off(enabled: bool) -> &'static str {
+    if enabled { "on" } else { "off" }
+}
+
+/// Keeps the player topped up and inside i-frames while god mode is on
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_god_mode(console: Res<DevConsole>, mut player: Single<&mut Health, With<Player>>) {
+    if !console.god_mode {
+        return;
+    }
+    player.current = player.max;
+    player.invulnerability_timer.reset();
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn update_dev_console_ui(
+    console: Res<DevConsole>,
+    mut panel: Single<&mut Node, With<DevConsolePanel>>,
+    mut log: Single<&mut Text, (With<DevConsoleLogText>, Without<DevConsoleInputText>)>,
+    mut input: Single<&mut Text, (With<DevConsoleInputText>, Without<DevConsoleLogText>)>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    panel.display = if console.open {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    log.0 = if console.log.is_empty() {
+        HELP.to_string()
+    } else {
+        console.log.join("\n")
+    };
+    input.0 = format!("> {}_", console.input);
+}
+
+/// Number of cells holding entities and the fullest cell's count
+fn grid_occupancy(grid: &UniformGrid) -> (usize, usize) {
+    grid.cells
+        .values()
+        .filter(|entities| !entities.is_empty())
+        .fold((0, 0), |(cells, fullest), entities| {
+            (cells + 1, fullest.max(entities.len()))
+        })
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_dev_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    console: Res<DevConsole>,
+    grid: Res<UniformGrid>,
+    mut text: Single<&mut Text, With<DevOverlayText>>,
+) {
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(bevy::diagnostic::Diagnostic::smoothed)
+        .unwrap_or(0.0);
+    let entities = diagnostics
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(bevy::diagnostic::Diagnostic::value)
+        .unwrap_or(0.0);
+    let mut lines = vec![format!("FPS {fps:.0}"), format!("Entities {entities:.0}")];
+    if console.show_grid {
+        let (cells, fullest) = grid_occupancy(&grid);
+        lines.push(format!("Grid {cells} cells, max {fullest}"));
+    }
+    if console.god_mode {
+        lines.push("GOD".to_string());
+    }
+    let overlay = lines.join("\n");
+    if text.0 != overlay {
+        text.0 = overlay;
+    }
+}
+
+/// Outlines every `Collider` at the shape collision detection tests against
+#[allow(clippy::needless_pass_by_value)]
+pub fn draw_collider_gizmos(
+    console: Res<DevConsole>,
+    mut gizmos: Gizmos,
+    colliders: Query<(&GlobalTransform, &Collider, Has<Player>)>,
+) {
+    if !console.show_colliders {
+        return;
+    }
+    for (transform, collider, is_player) in &colliders {
+        let position = transform.translation().truncate();
+        let color = if is_player {
+            PLAYER_COLLIDER_COLOR
+        } else {
+            COLLIDER_COLOR
+        };
+        match *collider {
+            Collider::Circle { radius } => {
+                gizmos.circle_2d(position, radius, color);
+            }
+            // Rectangles are tested axis-aligned, so they are drawn unrotated
+            Collider::Rectangle {
+                half_width,
+                half_height,
+            } => {
+                gizmos.rect_2d(position, Vec2::new(half_width, half_height) * 2.0, color);
+            }
+            // `width` pads the segment on each side, like a capsule
+            Collider::Line {
+                direction,
+                length,
+                width,
+            } => {
+                let end = position + direction * length;
+                let offset = direction.perp() * width;
+                gizmos.line_2d(position + offset, end + offset, color);
+                gizmos.line_2d(position - offset, end - offset, color);
+                gizmos.circle_2d(position, width, color);
+                gizmos.circle_2d(end, width, color);
+            }
+        }
+    }
+}
+
+/// Tints every occupied `UniformGrid` cell by how many entities it holds
+#[allow(clippy::needless_pass_by_value, clippy::cast_precision_loss)]
+pub fn draw_grid_gizmos(console: Res<DevConsole>, grid: Res<UniformGrid>, mut gizmos: Gizmos) {
+    if !console.show_grid {
+        return;
+    }
+    for (&(x, y), entities) in &grid.cells {
+        if entities.is_empty() {
+            continue;
+        }
+        let center = (Vec2::new(x as f32, y as f32) + 0.5) * grid.cell_size;
+        let fill = (entities.len() as f32 / GRID_FULL_CELL as f32).clamp(0.2, 1.0);
+        gizmos.rect_2d(
+            center,
+            Vec2::splat(grid.cell_size),
+            GRID_CELL_COLOR.with_alpha(fill),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_keeps_recent_lines() {
+        let mut console = DevConsole::default();
+        for index in 0..LOG_LINES + 5 {
+            console.push_log(index.to_string());
+        }
+        assert_eq!(console.log.len(), LOG_LINES);
+        assert_eq!(console.log[0], "5");
+    }
+
+    #[test]
+    fn test_closing_key_does_not_reach_gameplay() {
+        let mut app = App::new();
+        app.insert_resource(DevConsole {
+            open: true,
+            ..default()
+        })
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_systems(Update, block_game_keys);
+        app.update();
+
+        app.world_mut().resource_mut::<DevConsole>().open = false;
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<ButtonInput<KeyCode>>()
+                .just_pressed(KeyCode::Escape)
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyW);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<ButtonInput<KeyCode>>()
+                .just_pressed(KeyCode::KeyW)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Endless mode extras: director reset at run start and the roaming shop drones that
+//! replace the between-round shop
+
+use crate::components::pickup::ShopDrone;
+use crate::components::player::{
+    CombatStats, Currency, Dash, Health, Mana, Player, PlayerStats, Progression,
+};
+use crate::configs::endless::{
+    DRONE_COLLECT_RADIUS, DRONE_LABEL_OFFSET, DRONE_LEASH, DRONE_LIFETIME, DRONE_RADIUS,
+    DRONE_SPAWN_DISTANCE, DRONE_SPEED, DRONE_TURN_INTERVAL,
+};
+use crate::configs::shop::{card_price, get_card_config};
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::endless::EndlessDirector;
+use crate::resources::locale::Locale;
+use crate::resources::profile::MetaProfile;
+use crate::resources::round::{RoundManager, RunStartedEvent};
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::ui::menu::CardPurchasedEvent;
+use crate::systems::ui::menu::interaction::apply_upgrade_effect;
+use crate::systems::ui::menu::offers::{card_available, roll_cards};
+use bevy::prelude::*;
+use rand::Rng;
+
+pub fn reset_endless_director(
+    _trigger: On<RunStartedEvent>,
+    mut director: ResMut<EndlessDirector>,
+) {
+    *director = EndlessDirector::default();
+}
+
+/// Sends out a drone carrying a card the player could buy, priced for the current level
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn spawn_shop_drones(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<EndlessDirector>,
+    mut run_seed: ResMut<RunSeed>,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    round_manager: Res<RoundManager>,
+    cached: Res<CachedAssets>,
+    locale: Res<Locale>,
+    player: Single<(&Transform, &Progression), With<Player>>,
+    drones: Query<&ShopDrone>,
+) {
+    if !director.drone_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let (transform, progression) = player.into_inner();
+    // One drone per card at a time
+    let exclude: Vec<_> = drones.iter().map(|drone| drone.card).collect();
+    let Some(&card) = roll_cards(&mut run_seed.shop, 1, &exclude, |card| {
+        card_available(card, progression, &profile, &run_settings)
+    })
+    .first() else {
+        return;
+    };
+    let price = card_price(
+        card,
+        round_manager.current_round,
+        run_settings.price_multiplier(),
+    );
+
+    let mut rng = rand::thread_rng();
+    let heading = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU));
+    let position = transform.translation.truncate() + heading * DRONE_SPAWN_DISTANCE;
+
+    commands
+        .spawn((
+            Transform::from_translation(position.extend(-0.4)),
+            ShopDrone {
+                card,
+                price,
+                heading: -heading,
+                turn_timer: Timer::from_seconds(DRONE_TURN_INTERVAL, TimerMode::Repeating),
+                lifetime: Timer::from_seconds(DRONE_LIFETIME, TimerMode::Once),
+            },
+        ))
+        .with_children(|drone| {
+            drone.spawn((
+                Mesh2d(cached.unit_circle.clone()),
+                MeshMaterial2d(cached.mat_shop_drone.clone()),
+                Transform::from_scale(Vec3::splat(DRONE_RADIUS)),
+            ));
+            drone.spawn((
+                Text2d::new(format!(
+                    "{}\n{price}g",
+                    locale.get(get_card_config(card).name)
+                )),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextLayout::new_with_justify(Justify::Center),
+                Transform::from_xyz(0.0, DRONE_LABEL_OFFSET, 0.1),
+            ));
+        });
+}
+
+/// Drones wander, stay within `DRONE_LEASH` of the player and leave when their time is up
+#[allow(clippy::needless_pass_by_value)]
+pub fn move_shop_drones(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Single<&Transform, With<Player>>,
+    mut drones: Query<(Entity, &mut ShopDrone, &mut Transform), Without<Player>>,
+) {
+    let player_pos = player.translation.truncate();
+    let mut rng = rand::thread_rng();
+    for (entity, mut drone, mut transform) in &mut drones {
+        if drone.lifetime.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let to_player = player_pos - transform.translation.truncate();
+        if to_player.length() > DRONE_LEASH {
+            drone.heading = to_player.normalize_or_zero();
+        } else if drone.turn_timer.tick(time.delta()).just_finished() {
+            drone.heading = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU));
+        }
+        transform.translation += (drone.heading * DRONE_SPEED * time.delta_secs()).extend(0.0);
+    }
+}
+
+/// Touching a drone buys its card if the player can afford it
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn collect_shop_drones(
+    mut commands: Commands,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    player: Single<
+        (
+            &Transform,
+            &mut Health,
+            &mut Mana,
+            &mut Dash,
+            &mut Currency,
+            &mut PlayerStats,
+            &mut CombatStats,
+            &mut Progression,
+        ),
+        With<Player>,
+    >,
+    drones: Query<(Entity, &ShopDrone, &Transform), Without<Player>>,
+) {
+    let (
+        player_transform,
+        mut health,
+        mut mana,
+        mut dash,
+        mut currency,
+        mut stats,
+        mut combat,
+        mut progression,
+    ) = player.into_inner();
+    let player_pos = player_transform.translation.truncate();
+
+    for (entity, drone, transform) in &drones {
+        if transform.translation.truncate().distance(player_pos) > DRONE_COLLECT_RADIUS
+            || currency.gold < drone.price
+            || !card_available(drone.card, &progression, &profile, &run_settings)
+        {
+            continue;
+        }
+        if apply_upgrade_effect(
+            drone.card,
+            get_card_config(drone.card).value,
+            &mut health,
+            &mut mana,
+            &mut dash,
+            &mut stats,
+            &mut combat,
+            &mut progression,
+        ) {
+            currency.gold -= drone.price;
+            commands.trigger(CardPurchasedEvent {
+                card: drone.card,
+                price: drone.price,
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -2,60 +2,109 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::components::enemy::Enemy;
+use crate::components::enemy::{Enemy, EnemyKind};
 use crate::components::physics::{Collider, Velocity};
+use crate::components::pickup::Pickup;
 use crate::components::player::Player;
+use crate::components::relic::Decoy;
 use crate::components::status::UnitStatus;
+use crate::configs::difficulty::{MAGE_COUNCIL_MULTIPLIER, VANGUARD_EXTRA_ELITES};
+use crate::configs::endless::MAX_ALIVE_ENEMIES;
+use crate::configs::relic::DECOY_LURE_RADIUS;
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::endless::EndlessDirector;
 use crate::resources::game_state::GameState;
-use crate::resources::round::{RoundManager, RoundState};
+use crate::resources::round::{RoundClearedEvent, RoundManager, RoundState};
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::{Ascension, RunSettings};
 
 #[derive(SystemParam)]
 pub struct SpawnWavesParams<'w, 's> {
     pub commands: Commands<'w, 's>,
     pub time: Res<'w, Time>,
     pub round_manager: ResMut<'w, RoundManager>,
+    pub run_settings: Res<'w, RunSettings>,
+    pub run_seed: ResMut<'w, RunSeed>,
+    pub director: ResMut<'w, EndlessDirector>,
     pub meshes: ResMut<'w, Assets<Mesh>>,
-    pub materials: ResMut<'w, Assets<ColorMaterial>>,
+    pub cached: Res<'w, CachedAssets>,
     pub enemy_query: Query<'w, 's, &'static Enemy>,
+    pub pickup_query: Query<'w, 's, (), With<Pickup>>,
     pub player: Single<'w, 's, &'static Transform, With<Player>>,
     pub next_state: ResMut<'w, NextState<GameState>>,
 }
 
+/// Spawns one enemy of `kind` around the player, scaled to the current round
+fn spawn_enemy(params: &mut SpawnWavesParams, kind: EnemyKind, player_pos: Vec2) {
+    let position = ring_position(player_pos, &mut params.run_seed.waves);
+    spawn_enemy_at(
+        &mut params.commands,
+        &mut params.meshes,
+        params.cached.enemy_material(kind).clone(),
+        kind,
+        position,
+        params.round_manager.current_round,
+        &params.run_settings,
+    );
+}
+
+/// Random point on the spawn ring around the player
+fn ring_position(player_pos: Vec2, rng: &mut impl Rng) -> Vec2 {
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let radius = rng.gen_range(
+        crate::configs::enemy::SPAWN_RADIUS_MIN..crate::configs::enemy::SPAWN_RADIUS_MAX,
+    );
+    player_pos + Vec2::new(angle.cos() * radius, angle.sin() * radius)
+}
+
+/// Spawns one enemy of `kind` at `spawn_pos`, scaled to `current_round`
+pub fn spawn_enemy_at(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    kind: EnemyKind,
+    spawn_pos: Vec2,
+    current_round: u32,
+    settings: &RunSettings,
+) {
+    let spawn = match kind {
+        EnemyKind::Grunt => spawn_random_enemy,
+        EnemyKind::Elite => spawn_elite_enemy,
+        EnemyKind::Yellow => spawn_yellow_enemy,
+    };
+    spawn(
+        commands,
+        meshes,
+        material,
+        spawn_pos,
+        current_round,
+        settings,
+    );
+}
+
+/// Drives both game modes: round-based waves with shop breaks, or the endless director
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::cast_possible_wrap)]
 pub fn spawn_waves(mut params: SpawnWavesParams) {
     let player_pos = params.player.translation.truncate();
 
+    if params.run_settings.is_endless() {
+        direct_endless(&mut params, player_pos);
+        return;
+    }
+
     match params.round_manager.round_state {
         RoundState::Spawning => {
             params.round_manager.spawn_timer.tick(params.time.delta());
             if params.round_manager.spawn_timer.is_finished() {
                 if params.round_manager.enemies_to_spawn > 0 {
-                    spawn_random_enemy(
-                        &mut params.commands,
-                        &mut params.meshes,
-                        &mut params.materials,
-                        player_pos,
-                        params.round_manager.current_round,
-                    );
+                    spawn_enemy(&mut params, EnemyKind::Grunt, player_pos);
                     params.round_manager.enemies_to_spawn -= 1;
                 } else if params.round_manager.elites_to_spawn > 0 {
-                    spawn_elite_enemy(
-                        &mut params.commands,
-                        &mut params.meshes,
-                        &mut params.materials,
-                        player_pos,
-                        params.round_manager.current_round,
-                    );
+                    spawn_enemy(&mut params, EnemyKind::Elite, player_pos);
                     params.round_manager.elites_to_spawn -= 1;
                 } else if params.round_manager.yellow_enemies_to_spawn > 0 {
-                    spawn_yellow_enemy(
-                        &mut params.commands,
-                        &mut params.meshes,
-                        &mut params.materials,
-                        player_pos,
-                        params.round_manager.current_round,
-                    );
+                    spawn_enemy(&mut params, EnemyKind::Yellow, player_pos);
                     params.round_manager.yellow_enemies_to_spawn -= 1;
                 } else {
                     params.round_manager.round_state = RoundState::Fighting;
@@ -67,7 +116,17 @@ pub fn spawn_waves(mut params: SpawnWavesParams) {
             // Check if all enemies are dead
             let alive_count = params.enemy_query.iter().count();
             if alive_count == 0 {
-                println!("Round Cleared! Opening Menu...");
+                println!("Round Cleared! Collecting loot...");
+                params.round_manager.round_state = RoundState::Collecting;
+                params.commands.trigger(RoundClearedEvent {
+                    round: params.round_manager.current_round,
+                });
+            }
+        }
+        RoundState::Collecting => {
+            // `pickup_system` vacuums everything in, open the shop once the ground is clear
+            if params.pickup_query.is_empty() {
+                info!("Loot collected! Opening Menu...");
                 params.round_manager.round_state = RoundState::Shop;
                 // Tự động hiện Menu khi hết round
                 params.next_state.set(GameState::WeaponMenu);
@@ -80,45 +139,90 @@ pub fn spawn_waves(mut params: SpawnWavesParams) {
     }
 }
 
+/// Endless mode: no rounds or shop, spawns keep coming faster as time goes on.
+/// The level reached is written to `current_round` so enemy and price scaling carry over.
+fn direct_endless(params: &mut SpawnWavesParams, player_pos: Vec2) {
+    let delta = params.time.delta();
+    params.director.elapsed += delta.as_secs_f32();
+    let level = params.director.level();
+    if params.round_manager.current_round != level {
+        params.round_manager.current_round = level;
+        info!("Endless: level {level}");
+    }
+
+    let interval = params.director.spawn_interval(&params.run_settings);
+    params
+        .director
+        .spawn_timer
+        .set_duration(std::time::Duration::from_secs_f32(interval));
+    let grunts = params
+        .director
+        .spawn_timer
+        .tick(delta)
+        .times_finished_this_tick();
+    let elites = params
+        .director
+        .elite_timer
+        .tick(delta)
+        .times_finished_this_tick();
+    let yellows = params
+        .director
+        .yellow_timer
+        .tick(delta)
+        .times_finished_this_tick();
+
+    if params.enemy_query.iter().len() >= MAX_ALIVE_ENEMIES {
+        return;
+    }
+
+    let mut elite_batch = 1;
+    if params.run_settings.has(Ascension::EliteVanguard) {
+        elite_batch += VANGUARD_EXTRA_ELITES;
+    }
+    let mut yellow_batch = 1;
+    if params.run_settings.has(Ascension::MageCouncil) {
+        yellow_batch *= MAGE_COUNCIL_MULTIPLIER;
+    }
+
+    for _ in 0..grunts {
+        spawn_enemy(params, EnemyKind::Grunt, player_pos);
+    }
+    for _ in 0..elites * elite_batch {
+        spawn_enemy(params, EnemyKind::Elite, player_pos);
+    }
+    for _ in 0..yellows * yellow_batch {
+        spawn_enemy(params, EnemyKind::Yellow, player_pos);
+    }
+}
+
 #[allow(clippy::cast_precision_loss)]
 fn spawn_random_enemy(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    player_pos: Vec2,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    spawn_pos: Vec2,
     current_round: u32,
+    settings: &RunSettings,
 ) {
-    let mut rng = rand::thread_rng();
-
-    // Random position outside screen? Or just circle around player?
-    // Circle around player (radius 500-800)
-    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-    let radius = rng.gen_range(
-        crate::configs::enemy::SPAWN_RADIUS_MIN..crate::configs::enemy::SPAWN_RADIUS_MAX,
-    );
-    let x = angle.cos() * radius;
-    let y = angle.sin() * radius;
-    let spawn_pos = player_pos + Vec2::new(x, y);
-
     // Scaling Formulas
     // Base HP: 30, +20 per round. Round 1: 50, Round 5: 130
     let health = (current_round as f32).mul_add(
         crate::configs::enemy::HEALTH_SCALING_PER_ROUND,
         crate::configs::enemy::BASE_HEALTH,
-    );
+    ) * settings.enemy_health_multiplier();
     // Base Speed: 150 (Constant)
-    let speed = crate::configs::enemy::BASE_SPEED;
+    let speed = crate::configs::enemy::BASE_SPEED * settings.enemy_speed_multiplier();
     // Base Damage: 10, +5 per round. Round 5: 35
     let damage = (current_round as f32).mul_add(
         crate::configs::enemy::DAMAGE_SCALING_PER_ROUND,
         crate::configs::enemy::BASE_DAMAGE,
-    );
+    ) * settings.enemy_damage_multiplier();
 
     println!("Spawning Enemy (R{current_round}): HP={health}, Spd={speed}, Dmg={damage}");
 
     commands.spawn((
         Mesh2d(meshes.add(Circle::new(crate::configs::enemy::VISUAL_RADIUS))),
-        MeshMaterial2d(materials.add(Color::from(bevy::color::palettes::css::RED))),
+        MeshMaterial2d(material),
         Transform::from_translation(spawn_pos.extend(crate::configs::enemy::VISUAL_Z_INDEX)),
         Collider::ball(crate::configs::enemy::COLLIDER_RADIUS),
         Enemy {
@@ -133,36 +237,27 @@ fn spawn_random_enemy(
 #[allow(clippy::cast_precision_loss)]
 fn spawn_elite_enemy(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    player_pos: Vec2,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    spawn_pos: Vec2,
     current_round: u32,
+    settings: &RunSettings,
 ) {
-    let mut rng = rand::thread_rng();
-
-    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-    let radius = rng.gen_range(
-        crate::configs::enemy::SPAWN_RADIUS_MIN..crate::configs::enemy::SPAWN_RADIUS_MAX,
-    );
-    let x = angle.cos() * radius;
-    let y = angle.sin() * radius;
-    let spawn_pos = player_pos + Vec2::new(x, y);
-
     let health = (current_round as f32).mul_add(
         crate::configs::enemy::ELITE_HEALTH_SCALING_PER_ROUND,
         crate::configs::enemy::ELITE_BASE_HEALTH,
-    );
-    let speed = crate::configs::enemy::ELITE_BASE_SPEED;
+    ) * settings.enemy_health_multiplier();
+    let speed = crate::configs::enemy::ELITE_BASE_SPEED * settings.enemy_speed_multiplier();
     let damage = (current_round as f32).mul_add(
         crate::configs::enemy::DAMAGE_SCALING_PER_ROUND,
         crate::configs::enemy::BASE_DAMAGE, // Elites also do normal contact damage
-    );
+    ) * settings.enemy_damage_multiplier();
 
     println!("Spawning ELITE Enemy (R{current_round}): HP={health}, Spd={speed}");
 
     commands.spawn((
         Mesh2d(meshes.add(Circle::new(crate::configs::enemy::ELITE_VISUAL_RADIUS))),
-        MeshMaterial2d(materials.add(Color::from(bevy::color::palettes::css::PURPLE))),
+        MeshMaterial2d(material),
         Transform::from_translation(spawn_pos.extend(crate::configs::enemy::VISUAL_Z_INDEX)),
         Collider::ball(crate::configs::enemy::ELITE_COLLIDER_RADIUS),
         Enemy {
@@ -188,29 +283,21 @@ fn spawn_elite_enemy(
 #[allow(clippy::cast_precision_loss)]
 fn spawn_yellow_enemy(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    player_pos: Vec2,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    spawn_pos: Vec2,
     current_round: u32,
+    settings: &RunSettings,
 ) {
-    let mut rng = rand::thread_rng();
-
-    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-    let radius = rng.gen_range(
-        crate::configs::enemy::SPAWN_RADIUS_MIN..crate::configs::enemy::SPAWN_RADIUS_MAX,
-    );
-    let x = angle.cos() * radius;
-    let y = angle.sin() * radius;
-    let spawn_pos = player_pos + Vec2::new(x, y);
-
     let health = (current_round as f32).mul_add(
         crate::configs::enemy::YELLOW_HEALTH_SCALING_PER_ROUND,
         crate::configs::enemy::YELLOW_BASE_HEALTH,
-    );
-    let speed = crate::configs::enemy::YELLOW_BASE_SPEED;
+    ) * settings.enemy_health_multiplier();
+    let speed = crate::configs::enemy::YELLOW_BASE_SPEED * settings.enemy_speed_multiplier();
 
     // Damage mul: +0.1 * round
-    let damage_multiplier = 0.1f32.mul_add(current_round as f32, 1.0);
+    let damage_multiplier =
+        0.1f32.mul_add(current_round as f32, 1.0) * settings.enemy_damage_multiplier();
     // Crit chance: 10% mỗi round = 0.1 * round
     let crit_chance = 0.1 * current_round as f32;
     // Crit damage: 2 + 0.5 * round
@@ -222,7 +309,7 @@ fn spawn_yellow_enemy(
 
     commands.spawn((
         Mesh2d(meshes.add(Circle::new(crate::configs::enemy::YELLOW_VISUAL_RADIUS))),
-        MeshMaterial2d(materials.add(Color::from(bevy::color::palettes::css::YELLOW))),
+        MeshMaterial2d(material),
         Transform::from_translation(spawn_pos.extend(crate::configs::enemy::VISUAL_Z_INDEX)),
         Collider::ball(crate::configs::enemy::YELLOW_COLLIDER_RADIUS),
         Enemy {
@@ -240,6 +327,10 @@ fn spawn_yellow_enemy(
                 crate::configs::enemy::YELLOW_FORCE_COOLDOWN,
                 TimerMode::Repeating,
             ),
+            spell_timer: Timer::from_seconds(
+                crate::configs::enemy::YELLOW_SPELL_COOLDOWN,
+                TimerMode::Repeating,
+            ),
         },
         crate::components::player::PlayerStats {
             damage_multiplier,
@@ -250,6 +341,10 @@ fn spawn_yellow_enemy(
             crit_damage,
             ..default()
         },
+        crate::components::player::Mana::new(
+            crate::configs::enemy::YELLOW_MAX_MANA,
+            crate::configs::enemy::YELLOW_MANA_REGEN,
+        ),
     ));
 }
 
@@ -257,6 +352,7 @@ fn spawn_yellow_enemy(
 pub fn enemy_chase_player(
     mut enemy_query: Query<(&mut Velocity, &Transform, &Enemy, &UnitStatus)>,
     player: Single<&Transform, With<Player>>,
+    decoy_query: Query<&Transform, (With<Decoy>, Without<Enemy>)>,
 ) {
     let player_pos = player.translation.truncate();
 
@@ -266,7 +362,14 @@ pub fn enemy_chase_player(
             continue;
         }
         let pos = transform.translation.truncate();
-        let dir = (player_pos - pos).normalize_or_zero();
-        velocity.linvel = dir * enemy.speed;
+        // Decoys lure enemies within range away from the player
+        let target = decoy_query
+            .iter()
+            .map(|decoy| decoy.translation.truncate())
+            .filter(|decoy| decoy.distance(pos) <= DECOY_LURE_RADIUS)
+            .min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos)))
+            .unwrap_or(player_pos);
+        let dir = (target - pos).normalize_or_zero();
+        velocity.linvel = dir * enemy.speed * status.speed_multiplier();
     }
 }
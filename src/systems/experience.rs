@@ -0,0 +1,192 @@
+//! Kill XP, level-ups and the perk draft they open, plus the behaviour perk hooks
+
+use crate::components::enemy::{EliteEnemy, EnemyKind, YellowEnemy};
+use crate::components::perk::Perk;
+use crate::components::player::{
+    CombatStats, Dash, Experience, Hand, Health, Mana, Player, PlayerStats, Progression,
+};
+use crate::configs::experience::{BLOODTHIRST_HEAL, OVERCHARGE_MANA, enemy_xp};
+use crate::configs::shop::get_card_config;
+use crate::resources::game_state::GameState;
+use crate::resources::perks::{PerkOffers, PerkSelection};
+use crate::resources::profile::MetaProfile;
+use crate::resources::round::RunStartedEvent;
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::combat::{DamageEvent, EnemyDeathEvent};
+use crate::systems::ui::menu::interaction::apply_upgrade_effect;
+use crate::systems::ui::menu::offers::card_available;
+use bevy::prelude::*;
+
+/// The player picked the perk at `index` in `PerkOffers`
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct PerkChosenEvent {
+    pub index: usize,
+}
+
+/// True when both hands hold a weapon and they differ, which Ambidextrous rewards
+pub fn hands_differ<'a>(hands: impl IntoIterator<Item = &'a Hand>) -> bool {
+    let mut weapons = hands.into_iter().map(|hand| hand.equipped_weapon);
+    match (weapons.next(), weapons.next()) {
+        (Some(Some(first)), Some(Some(second))) => first != second,
+        _ => false,
+    }
+}
+
+fn perk_available(
+    perk: Perk,
+    progression: &Progression,
+    profile: &MetaProfile,
+    settings: &RunSettings,
+) -> bool {
+    match perk {
+        Perk::Upgrade(card) => card_available(card, progression, profile, settings),
+        _ => !progression.has_perk(perk),
+    }
+}
+
+pub fn reset_experience(
+    _trigger: On<RunStartedEvent>,
+    mut experience: Single<&mut Experience, With<Player>>,
+) {
+    **experience = Experience::default();
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn gain_experience(
+    trigger: On<EnemyDeathEvent>,
+    enemy_query: Query<(Has<EliteEnemy>, Has<YellowEnemy>)>,
+    mut experience: Single<&mut Experience, With<Player>>,
+) {
+    let Ok((elite, yellow)) = enemy_query.get(trigger.event().entity) else {
+        return;
+    };
+    experience.gain(enemy_xp(EnemyKind::from_markers(elite, yellow)));
+}
+
+/// Pauses into the perk draft while level-ups are waiting. Runs as a system rather than
+/// from the kill observer so a game over or round end queued the same frame wins.
+#[allow(clippy::needless_pass_by_value)]
+pub fn open_level_up(
+    experience: Single<&Experience, With<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if experience.pending > 0 && matches!(*next_state, NextState::Unchanged) {
+        next_state.set(GameState::LevelUp);
+    }
+}
+
+/// Rolls the draft on entering `LevelUp`; with nothing left to offer the level-ups are dropped
+#[allow(clippy::needless_pass_by_value)]
+pub fn roll_perk_offers(
+    mut offers: ResMut<PerkOffers>,
+    mut selection: ResMut<PerkSelection>,
+    mut run_seed: ResMut<RunSeed>,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    player: Single<(&Progression, &mut Experience), With<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let (progression, mut experience) = player.into_inner();
+    offers.roll(&mut run_seed.perks, |perk| {
+        perk_available(perk, progression, &profile, &run_settings)
+    });
+    selection.0 = 0;
+
+    if offers.perks.is_empty() {
+        experience.pending = 0;
+        next_state.set(GameState::Playing);
+    }
+}
+
+/// Applies the chosen perk, then drafts again for the next queued level or resumes play
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
+pub fn apply_perk_choice(
+    trigger: On<PerkChosenEvent>,
+    mut offers: ResMut<PerkOffers>,
+    mut selection: ResMut<PerkSelection>,
+    mut run_seed: ResMut<RunSeed>,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    player: Single<
+        (
+            &mut Health,
+            &mut Mana,
+            &mut Dash,
+            &mut PlayerStats,
+            &mut CombatStats,
+            &mut Progression,
+            &mut Experience,
+        ),
+        With<Player>,
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(&perk) = offers.perks.get(trigger.event().index) else {
+        return;
+    };
+    let (mut health, mut mana, mut dash, mut stats, mut combat, mut progression, mut experience) =
+        player.into_inner();
+    if experience.pending == 0 {
+        return;
+    }
+
+    if let Perk::Upgrade(card) = perk {
+        apply_upgrade_effect(
+            card,
+            get_card_config(card).value,
+            &mut health,
+            &mut mana,
+            &mut dash,
+            &mut stats,
+            &mut combat,
+            &mut progression,
+        );
+    }
+    progression.perks.push(perk);
+    experience.pending -= 1;
+
+    if experience.pending == 0 {
+        offers.perks.clear();
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    offers.roll(&mut run_seed.perks, |perk| {
+        perk_available(perk, &progression, &profile, &run_settings)
+    });
+    selection.0 = 0;
+    if offers.perks.is_empty() {
+        experience.pending = 0;
+        next_state.set(GameState::Playing);
+    }
+}
+
+/// Bloodthirst: kills heal the player
+#[allow(clippy::needless_pass_by_value)]
+pub fn perk_kill_observer(
+    _trigger: On<EnemyDeathEvent>,
+    player: Single<(&mut Health, &Progression), With<Player>>,
+) {
+    let (mut health, progression) = player.into_inner();
+    if progression.has_perk(Perk::Bloodthirst) && health.current > 0.0 {
+        health.current = (health.current + BLOODTHIRST_HEAL).min(health.max);
+    }
+}
+
+/// Overcharge: critical hits on enemies restore mana
+#[allow(clippy::needless_pass_by_value)]
+pub fn perk_damage_observer(
+    trigger: On<DamageEvent>,
+    player: Single<(Entity, &mut Mana, &Progression), With<Player>>,
+) {
+    let event = trigger.event();
+    let (player_entity, mut mana, progression) = player.into_inner();
+    if event.crit && event.entity != player_entity && progression.has_perk(Perk::Overcharge) {
+        mana.current = (mana.current + OVERCHARGE_MANA).min(mana.max);
+    }
+}
@@ -0,0 +1,252 @@
+//! World-space health bars over damaged enemies, with nameplates on elites and Yellow mages
+//! Every bar is two quads sharing the cached unit square and bar materials, so they batch
+//! into a couple of draw calls. Parts are children of their enemy and despawn with it.
+
+use crate::components::enemy::{EliteEnemy, Enemy, EnemyKind, YellowEnemy};
+use crate::configs::difficulty::get_ascension_config;
+use crate::configs::enemy::visual_radius;
+use crate::configs::health_bars::{
+    BAR_GAP, BAR_HEIGHT, BAR_WIDTH_SCALE, BAR_Z_OFFSET, NAMEPLATE_AFFIX_COLOR,
+    NAMEPLATE_AFFIX_FONT_SIZE, NAMEPLATE_COLOR, NAMEPLATE_FONT_SIZE, NAMEPLATE_GAP, is_affix,
+    nameplate_name,
+};
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::locale::Locale;
+use crate::resources::run_settings::RunSettings;
+use bevy::prelude::*;
+
+/// Health bar of an enemy, filled from `Enemy.health` against the health it spawned with
+#[derive(Component)]
+pub struct HealthBar {
+    pub max: f32,
+    pub width: f32,
+    /// Shown even at full health, for enemies with a nameplate
+    pub persistent: bool,
+    /// Holds the backing quad, the fill and any nameplate text
+    pub root: Entity,
+    pub fill: Entity,
+}
+
+/// Fraction of the bar left filled
+pub fn fill_ratio(health: f32, max: f32) -> f32 {
+    if max <= 0.0 {
+        return 0.0;
+    }
+    (health / max).clamp(0.0, 1.0)
+}
+
+/// Fill quad transform for `ratio`, anchored to the bar's left edge
+fn fill_transform(ratio: f32, width: f32) -> Transform {
+    let filled = width * ratio;
+    Transform::from_xyz((filled - width) / 2.0, 0.0, 0.01)
+        .with_scale(Vec3::new(filled, BAR_HEIGHT, 1.0))
+}
+
+/// Names and affixes shown over `kind`, empty for grunts
+fn nameplate_lines(kind: EnemyKind, run_settings: &RunSettings, locale: &Locale) -> Vec<String> {
+    let Some(name) = nameplate_name(kind) else {
+        return Vec::new();
+    };
+    let affixes: Vec<&str> = run_settings
+        .ascensions
+        .iter()
+        .filter(|ascension| is_affix(**ascension, kind))
+        .map(|ascension| locale.get(get_ascension_config(*ascension).name))
+        .collect();
+    let mut lines = vec![locale.get(name).to_string()];
+    if !affixes.is_empty() {
+        lines.push(affixes.join(" · "));
+    }
+    lines
+}
+
+/// Builds the bar, and nameplate where the enemy has one, as soon as an enemy spawns
+#[allow(clippy::needless_pass_by_value)]
+pub fn attach_health_bar(
+    add: On<Add, Enemy>,
+    mut commands: Commands,
+    cached: Res<CachedAssets>,
+    locale: Res<Locale>,
+    run_settings: Res<RunSettings>,
+    enemies: Query<(&Enemy, Has<EliteEnemy>, Has<YellowEnemy>)>,
+) {
+    let Ok((enemy, elite, yellow)) = enemies.get(add.entity) else {
+        return;
+    };
+    let kind = EnemyKind::from_markers(elite, yellow);
+    let radius = visual_radius(kind);
+    let width = radius * BAR_WIDTH_SCALE;
+    let lines = nameplate_lines(kind, &run_settings, &locale);
+    let persistent = !lines.is_empty();
+
+    let root = commands
+        .spawn((
+            Transform::from_xyz(0.0, radius + BAR_GAP, BAR_Z_OFFSET),
+            if persistent {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            },
+            ChildOf(add.entity),
+        ))
+        .id();
+    commands.spawn((
+        Mesh2d(cached.unit_square.clone()),
+        MeshMaterial2d(cached.mat_health_bar_back.clone()),
+        Transform::from_scale(Vec3::new(width, BAR_HEIGHT, 1.0)),
+        ChildOf(root),
+    ));
+    let fill = commands
+        .spawn((
+            Mesh2d(cached.unit_square.clone()),
+            MeshMaterial2d(cached.mat_health_bar_fill.clone()),
+            fill_transform(1.0, width),
+            ChildOf(root),
+        ))
+        .id();
+
+    // Name first, affixes stacked beneath it and above the bar
+    let mut y = NAMEPLATE_GAP;
+    for (index, line) in lines.into_iter().enumerate().rev() {
+        let (size, color) = if index == 0 {
+            (NAMEPLATE_FONT_SIZE, NAMEPLATE_COLOR)
+        } else {
+            (NAMEPLATE_AFFIX_FONT_SIZE, NAMEPLATE_AFFIX_COLOR)
+        };
+        commands.spawn((
+            Text2d::new(line),
+            TextFont {
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+            Transform::from_xyz(0.0, y, 0.0),
+            ChildOf(root),
+        ));
+        y += size;
+    }
+
+    commands.entity(add.entity).insert(HealthBar {
+        max: enemy.health,
+        width,
+        persistent,
+        root,
+        fill,
+    });
+}
+
+/// Resizes fills after damage and reveals grunt bars once they are hurt
+#[allow(clippy::type_complexity)]
+pub fn update_health_bars(
+    enemies: Query<(&Enemy, &HealthBar), Changed<Enemy>>,
+    mut parts: Query<(&mut Transform, &mut Visibility)>,
+) {
+    for (enemy, bar) in &enemies {
+        let ratio = fill_ratio(enemy.health, bar.max);
+        if let Ok((mut transform, _)) = parts.get_mut(bar.fill) {
+            *transform = fill_transform(ratio, bar.width);
+        }
+        if let Ok((_, mut visibility)) = parts.get_mut(bar.root) {
+            visibility.set_if_neq(if bar.persistent || ratio < 1.0 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::game_settings::ColorPalette;
+    use crate::resources::locale::Language;
+    use crate::resources::run_settings::Ascension;
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<ColorMaterial>>();
+        app.init_resource::<RunSettings>();
+        app.insert_resource(Locale::new(Language::English));
+        let world = app.world_mut();
+        let cached = world.resource_scope(|world, mut meshes: Mut<Assets<Mesh>>| {
+            let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+            CachedAssets::new(&mut meshes, &mut materials, ColorPalette::Standard)
+        });
+        world.insert_resource(cached);
+        app.add_observer(attach_health_bar);
+        app.add_systems(Update, update_health_bars);
+        app
+    }
+
+    fn enemy(health: f32) -> Enemy {
+        Enemy {
+            health,
+            speed: 0.0,
+            damage: 0.0,
+        }
+    }
+
+    fn root_visibility(app: &App, entity: Entity) -> Visibility {
+        let root = app.world().get::<HealthBar>(entity).unwrap().root;
+        *app.world().get::<Visibility>(root).unwrap()
+    }
+
+    #[test]
+    fn test_fill_ratio_clamps() {
+        assert!((fill_ratio(50.0, 200.0) - 0.25).abs() < f32::EPSILON);
+        assert!(fill_ratio(-10.0, 200.0).abs() < f32::EPSILON);
+        assert!((fill_ratio(300.0, 200.0) - 1.0).abs() < f32::EPSILON);
+        assert!(fill_ratio(10.0, 0.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_grunt_bar_appears_once_damaged() {
+        let mut app = setup();
+        let grunt = app.world_mut().spawn(enemy(100.0)).id();
+        app.update();
+        assert_eq!(root_visibility(&app, grunt), Visibility::Hidden);
+
+        app.world_mut().get_mut::<Enemy>(grunt).unwrap().health = 40.0;
+        app.update();
+        assert_eq!(root_visibility(&app, grunt), Visibility::Inherited);
+
+        let fill = app.world().get::<HealthBar>(grunt).unwrap().fill;
+        let width = app.world().get::<HealthBar>(grunt).unwrap().width;
+        let scale = app.world().get::<Transform>(fill).unwrap().scale.x;
+        assert!((scale - width * 0.4).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_elite_nameplate_lists_affixes() {
+        let mut app = setup();
+        app.world_mut()
+            .resource_mut::<RunSettings>()
+            .ascensions
+            .extend([Ascension::Frenzy, Ascension::MageCouncil]);
+        let elite = app.world_mut().spawn((enemy(250.0), EliteEnemy)).id();
+        app.update();
+        assert_eq!(root_visibility(&app, elite), Visibility::Inherited);
+
+        let world = app.world_mut();
+        let mut lines: Vec<String> = world
+            .query::<&Text2d>()
+            .iter(world)
+            .map(|text| text.0.clone())
+            .collect();
+        lines.sort();
+        assert_eq!(lines, vec!["Elite".to_string(), "Frenzy".to_string()]);
+    }
+
+    #[test]
+    fn test_bar_despawns_with_enemy() {
+        let mut app = setup();
+        let grunt = app.world_mut().spawn(enemy(100.0)).id();
+        app.update();
+        app.world_mut().entity_mut(grunt).despawn();
+
+        let world = app.world_mut();
+        assert_eq!(world.query::<&Mesh2d>().iter(world).count(), 0);
+    }
+}
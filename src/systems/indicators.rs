@@ -0,0 +1,143 @@
+//! Screen-edge arrows pointing at off-screen elites and Yellow mages
+
+use crate::components::enemy::{EliteEnemy, YellowEnemy};
+use crate::components::player::GameCamera;
+use crate::configs::indicators::{
+    ARROW_FALLOFF, ARROW_MARGIN, ARROW_MAX_SIZE, ARROW_MIN_SIZE, ARROW_Z_INDEX,
+};
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::game_settings::GameSettings;
+use bevy::prelude::*;
+
+/// Arrow tracking `enemy`, despawned with it
+#[derive(Component)]
+pub struct OffscreenIndicator {
+    pub enemy: Entity,
+}
+
+/// Where the arrow for an enemy at `offset` from the screen centre sits, inset by
+/// `ARROW_MARGIN` from the edge. `None` while the enemy is on screen.
+pub fn edge_position(offset: Vec2, half_extents: Vec2) -> Option<Vec2> {
+    if offset.x.abs() <= half_extents.x && offset.y.abs() <= half_extents.y {
+        return None;
+    }
+    let inner = (half_extents - Vec2::splat(ARROW_MARGIN)).max(Vec2::ZERO);
+    let scale_x = if offset.x == 0.0 {
+        f32::INFINITY
+    } else {
+        inner.x / offset.x.abs()
+    };
+    let scale_y = if offset.y == 0.0 {
+        f32::INFINITY
+    } else {
+        inner.y / offset.y.abs()
+    };
+    Some(offset * scale_x.min(scale_y))
+}
+
+/// Keeps one arrow per elite and mage alive
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn sync_offscreen_indicators(
+    mut commands: Commands,
+    cached: Res<CachedAssets>,
+    threats: Query<(Entity, Has<EliteEnemy>), Or<(With<EliteEnemy>, With<YellowEnemy>)>>,
+    indicators: Query<(Entity, &OffscreenIndicator)>,
+) {
+    let mut tracked = Vec::new();
+    for (entity, indicator) in &indicators {
+        if threats.contains(indicator.enemy) {
+            tracked.push(indicator.enemy);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (enemy, elite) in &threats {
+        if tracked.contains(&enemy) {
+            continue;
+        }
+        let material = if elite {
+            cached.mat_indicator_elite.clone()
+        } else {
+            cached.mat_indicator_yellow.clone()
+        };
+        commands.spawn((
+            Mesh2d(cached.unit_triangle.clone()),
+            MeshMaterial2d(material),
+            Transform::default(),
+            Visibility::Hidden,
+            OffscreenIndicator { enemy },
+        ));
+    }
+}
+
+/// Pins arrows to the screen edge, pointing at their enemy and shrinking with distance
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_offscreen_indicators(
+    settings: Res<GameSettings>,
+    camera: Single<(&Camera, &GlobalTransform), With<GameCamera>>,
+    enemies: Query<&GlobalTransform, Without<OffscreenIndicator>>,
+    mut indicators: Query<(&OffscreenIndicator, &mut Transform, &mut Visibility)>,
+) {
+    let (camera, camera_transform) = camera.into_inner();
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    let center = camera_transform.translation().truncate();
+
+    for (indicator, mut transform, mut visibility) in &mut indicators {
+        let edge = enemies.get(indicator.enemy).ok().and_then(|enemy| {
+            let offset = enemy.translation().truncate() - center;
+            edge_position(offset, viewport / 2.0).map(|edge| (offset, edge))
+        });
+        let Some((offset, edge)) = edge.filter(|_| settings.offscreen_indicators) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let closeness = 1.0 - ((offset.length() - edge.length()) / ARROW_FALLOFF).clamp(0.0, 1.0);
+        *visibility = Visibility::Visible;
+        *transform = Transform::from_translation((center + edge).extend(ARROW_Z_INDEX))
+            // The triangle mesh points up
+            .with_rotation(Quat::from_rotation_z(
+                offset.to_angle() - std::f32::consts::FRAC_PI_2,
+            ))
+            .with_scale(Vec3::splat(ARROW_MIN_SIZE.lerp(ARROW_MAX_SIZE, closeness)));
+    }
+}
+
+pub fn despawn_offscreen_indicators(
+    mut commands: Commands,
+    indicators: Query<Entity, With<OffscreenIndicator>>,
+) {
+    for entity in &indicators {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_screen_enemies_have_no_arrow() {
+        assert_eq!(
+            edge_position(Vec2::new(100.0, -50.0), Vec2::new(400.0, 300.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_arrow_clamps_to_nearest_edge() {
+        let half = Vec2::new(400.0, 300.0);
+        // Straight right: x pinned to the inset edge, y stays centred
+        assert_eq!(
+            edge_position(Vec2::new(1000.0, 0.0), half),
+            Some(Vec2::new(400.0 - ARROW_MARGIN, 0.0))
+        );
+        // Diagonal hits the top edge first
+        let edge = edge_position(Vec2::new(600.0, 600.0), half).unwrap();
+        assert!((edge.y - (300.0 - ARROW_MARGIN)).abs() < 0.001);
+        assert!((edge.x - edge.y).abs() < 0.001);
+    }
+}
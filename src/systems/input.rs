@@ -58,10 +58,13 @@ pub fn update_virtual_input(
         || virtual_input.left_skill_clicked;
     virtual_input.right_skill = input_settings.right_skill.is_just_pressed(&input, &mouse_input)
         || virtual_input.right_skill_clicked;
+    virtual_input.dash = input_settings.dash.is_just_pressed(&input, &mouse_input)
+        || virtual_input.dash_clicked;
 
     // Reset clicked flags
     virtual_input.left_skill_clicked = false;
     virtual_input.right_skill_clicked = false;
+    virtual_input.dash_clicked = false;
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -0,0 +1,32 @@
+//! High score hook: submits every finished run to the local leaderboard
+
+use crate::resources::leaderboard::{Leaderboard, ScoreEntry};
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
+use crate::resources::run_stats::RunStats;
+use bevy::prelude::*;
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_high_score(
+    mut leaderboard: ResMut<Leaderboard>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    stats: Res<RunStats>,
+) {
+    let rank = leaderboard.submit(ScoreEntry {
+        mode: run_settings.mode,
+        round: round_manager.current_round,
+        kills: round_manager.kills,
+        time: stats.time,
+        loadout: stats.loadout,
+        seed: stats.seed,
+        difficulty: run_settings.difficulty,
+        ascensions: run_settings.ascensions.clone(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    });
+    leaderboard.last_rank = rank;
+    if let Some(rank) = rank {
+        info!("New high score: #{}", rank + 1);
+        leaderboard.save();
+    }
+}
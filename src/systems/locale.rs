@@ -0,0 +1,47 @@
+//! Moves string tables from the asset server into `Locale`
+
+use crate::resources::game_state::GameState;
+use crate::resources::locale::{Locale, StringTable};
+use crate::systems::ui::{
+    despawn_settings_menu, despawn_weapon_menu, spawn_settings_menu, spawn_weapon_menu,
+};
+use bevy::prelude::*;
+
+/// Copies loaded and hot-reloaded tables into `Locale`. A menu that is open when the
+/// current language's strings change was built from the old ones, so it is rebuilt.
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_string_tables(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<StringTable>>,
+    tables: Res<Assets<StringTable>>,
+    mut locale: ResMut<Locale>,
+    state: Res<State<GameState>>,
+) {
+    let mut refresh = false;
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        if let Some(language) = locale.table_language(id)
+            && let Some(table) = tables.get(id)
+        {
+            let changed = locale.insert_table(language, table);
+            refresh |= changed && language == locale.language();
+        }
+    }
+    if !refresh {
+        return;
+    }
+    match state.get() {
+        GameState::WeaponMenu => {
+            commands.run_system_cached(despawn_weapon_menu);
+            commands.run_system_cached(spawn_weapon_menu);
+        }
+        GameState::Settings => {
+            commands.run_system_cached(despawn_settings_menu);
+            commands.run_system_cached(spawn_settings_menu);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,62 @@
+//! Meta-progression hooks: shard rewards at game over and permanent bonuses at run start
+
+use crate::components::player::{Currency, Health, Mana, Player, PlayerStats};
+use crate::configs::meta::{
+    FOCUS_MAX_MANA, FORTUNE_STARTING_GOLD, POWER_DAMAGE, VITALITY_MAX_HEALTH,
+};
+use crate::resources::locale::Locale;
+use crate::resources::profile::{MetaProfile, Unlock};
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
+use bevy::prelude::*;
+
+/// Applies owned permanent bonuses on top of freshly reset run stats.
+pub fn apply_meta_bonuses(
+    profile: &MetaProfile,
+    health: &mut Health,
+    mana: &mut Mana,
+    currency: &mut Currency,
+    stats: &mut PlayerStats,
+) {
+    if profile.is_unlocked(Unlock::Vitality) {
+        health.max += VITALITY_MAX_HEALTH;
+        health.current = health.max;
+    }
+    if profile.is_unlocked(Unlock::Fortune) {
+        currency.gold += FORTUNE_STARTING_GOLD;
+    }
+    if profile.is_unlocked(Unlock::Focus) {
+        mana.max += FOCUS_MAX_MANA;
+        mana.current = mana.max;
+    }
+    if profile.is_unlocked(Unlock::Power) {
+        stats.damage_multiplier += POWER_DAMAGE;
+    }
+}
+
+/// The very first run starts at app launch, after the player has been spawned
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_meta_bonuses_on_startup(
+    profile: Res<MetaProfile>,
+    player: Single<(&mut Health, &mut Mana, &mut Currency, &mut PlayerStats), With<Player>>,
+) {
+    let (mut health, mut mana, mut currency, mut stats) = player.into_inner();
+    apply_meta_bonuses(&profile, &mut health, &mut mana, &mut currency, &mut stats);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn award_meta_shards(
+    mut profile: ResMut<MetaProfile>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    locale: Res<Locale>,
+) {
+    let reward = profile.record_run(round_manager.current_round, round_manager.kills);
+    profile.best_ascension = profile.best_ascension.max(run_settings.level());
+    info!(
+        "Run ended on round {} ({}): +{reward} shards",
+        round_manager.current_round,
+        run_settings.label(&locale)
+    );
+    profile.save();
+}
@@ -1,8 +1,23 @@
+pub mod accessibility;
+pub mod achievements;
+pub mod audio;
 pub mod combat;
 pub mod damage_text;
+#[cfg(feature = "dev_console")]
+pub mod dev_console;
+pub mod endless;
+pub mod experience;
+pub mod health_bars;
+pub mod indicators;
 pub mod enemy;
+pub mod leaderboard;
+pub mod locale;
+pub mod pause;
 pub mod physics;
 pub mod player;
+pub mod run_stats;
 pub mod status;
 pub mod ui;
 pub mod input;
+pub mod meta;
+pub mod pickup;
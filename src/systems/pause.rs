@@ -0,0 +1,70 @@
+//! Game clock freezing and the browser auto-pause
+//! Gameplay reads `Time<Virtual>` (the default `Time`), so pausing it mid-run also stops the
+//! `elapsed_secs` clock the weapon `last_shot`/`last_skill_use` cooldowns compare against.
+//! Menus, the shop and the tutorial keep a running clock for their own timers and animations.
+
+use crate::resources::game_state::{GameState, PreviousMenuState};
+use bevy::prelude::*;
+
+/// Whether `state` freezes the game clock; settings opened from the pause menu keep it frozen
+pub const fn freezes_game_time(state: GameState, previous: GameState) -> bool {
+    matches!(state, GameState::Paused | GameState::LevelUp)
+        || (matches!(state, GameState::Settings) && matches!(previous, GameState::Paused))
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn sync_game_time(
+    state: Res<State<GameState>>,
+    previous: Res<PreviousMenuState>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if freezes_game_time(*state.get(), previous.0) {
+        time.pause();
+    } else {
+        time.unpause();
+    }
+}
+
+/// Pauses when the browser tab is hidden or loses focus
+#[cfg(target_arch = "wasm32")]
+pub fn auto_pause_on_blur(
+    mut focused: MessageReader<bevy::window::WindowFocused>,
+    mut occluded: MessageReader<bevy::window::WindowOccluded>,
+    mut next_state: ResMut<NextState<crate::resources::game_state::GameState>>,
+) {
+    // Only the latest change matters: a blur followed by a refocus is not a pause
+    let unfocused = focused.read().last().is_some_and(|event| !event.focused);
+    let occluded = occluded.read().last().is_some_and(|event| event.occluded);
+    let tab_hidden = web_sys::window()
+        .and_then(|window| window.document())
+        .is_some_and(|document| document.hidden());
+    if unfocused || occluded || tab_hidden {
+        // A round end or level-up queued this frame takes priority
+        if matches!(*next_state, NextState::Unchanged) {
+            next_state.set(crate::resources::game_state::GameState::Paused);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_mid_run_overlays_freeze_time() {
+        assert!(freezes_game_time(GameState::Paused, GameState::WeaponMenu));
+        assert!(freezes_game_time(GameState::LevelUp, GameState::WeaponMenu));
+        assert!(freezes_game_time(GameState::Settings, GameState::Paused));
+        assert!(!freezes_game_time(
+            GameState::Settings,
+            GameState::WeaponMenu
+        ));
+        assert!(!freezes_game_time(GameState::WeaponMenu, GameState::Paused));
+        assert!(!freezes_game_time(
+            GameState::Tutorial,
+            GameState::WeaponMenu
+        ));
+        assert!(!freezes_game_time(GameState::GameOver, GameState::Paused));
+        assert!(!freezes_game_time(GameState::Playing, GameState::Paused));
+    }
+}
@@ -0,0 +1,310 @@
+//! Loot pickups: drops on enemy death, magnet pull, collection and the end-of-round vacuum
+
+use crate::components::pickup::{Pickup, PickupKind, PowerUpKind, PowerUps};
+use crate::components::player::{Currency, Health, Player, PlayerStats, Progression};
+use crate::configs::pickup::{
+    BLINK_INTERVAL, BLINK_TIME, COLLECT_RADIUS, ELITE_POWER_UP_CHANCE, GOLD_RADIUS, HASTE_DURATION,
+    HEALTH_ORB_CHANCE, HEALTH_ORB_HEAL, HEALTH_RADIUS, LIFETIME, MAGNET_PULL_SPEED,
+    MAGNET_SURGE_DURATION, MAGNET_SURGE_RADIUS, POWER_UP_CHANCE, POWER_UP_RADIUS, SCATTER_RADIUS,
+    VACUUM_ACCELERATION, VACUUM_SPEED,
+};
+use crate::configs::relic::{ELITE_RELIC_CHANCE, RELIC_FALLBACK_GOLD, RELIC_LIST};
+use crate::resources::cached_assets::CachedAssets;
+use crate::resources::round::{RoundManager, RoundState};
+use crate::resources::run_stats::RunStats;
+use bevy::prelude::*;
+use rand::Rng;
+
+pub fn spawn_pickup(commands: &mut Commands, res: &CachedAssets, position: Vec2, kind: PickupKind) {
+    let (material, radius) = match kind {
+        PickupKind::Gold(_) => (res.mat_pickup_gold.clone(), GOLD_RADIUS),
+        PickupKind::Health(_) => (res.mat_pickup_health.clone(), HEALTH_RADIUS),
+        PickupKind::PowerUp(PowerUpKind::Haste) => (res.mat_pickup_haste.clone(), POWER_UP_RADIUS),
+        PickupKind::PowerUp(PowerUpKind::Magnet) => {
+            (res.mat_pickup_magnet.clone(), POWER_UP_RADIUS)
+        }
+        PickupKind::Relic => (
+            res.mat_pickup_relic.clone(),
+            crate::configs::relic::PICKUP_RADIUS,
+        ),
+    };
+
+    let mut rng = rand::thread_rng();
+    let offset = Vec2::new(
+        rng.gen_range(-SCATTER_RADIUS..SCATTER_RADIUS),
+        rng.gen_range(-SCATTER_RADIUS..SCATTER_RADIUS),
+    );
+
+    commands.spawn((
+        Mesh2d(res.unit_circle.clone()),
+        MeshMaterial2d(material),
+        // Below enemies and projectiles
+        Transform::from_translation((position + offset).extend(-0.5))
+            .with_scale(Vec3::splat(radius)),
+        Pickup {
+            kind,
+            lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+        },
+    ));
+}
+
+/// Rolls and spawns the loot for a dead enemy
+pub fn drop_loot(
+    commands: &mut Commands,
+    res: &CachedAssets,
+    position: Vec2,
+    gold: u32,
+    elite: bool,
+) {
+    let mut rng = rand::thread_rng();
+
+    spawn_pickup(commands, res, position, PickupKind::Gold(gold));
+
+    if rng.gen_bool(HEALTH_ORB_CHANCE) {
+        spawn_pickup(commands, res, position, PickupKind::Health(HEALTH_ORB_HEAL));
+    }
+
+    let power_up_chance = if elite {
+        ELITE_POWER_UP_CHANCE
+    } else {
+        POWER_UP_CHANCE
+    };
+    if rng.gen_bool(power_up_chance) {
+        let kind = if rng.gen_bool(0.5) {
+            PowerUpKind::Haste
+        } else {
+            PowerUpKind::Magnet
+        };
+        spawn_pickup(commands, res, position, PickupKind::PowerUp(kind));
+    }
+
+    if elite && rng.gen_bool(ELITE_RELIC_CHANCE) {
+        spawn_pickup(commands, res, position, PickupKind::Relic);
+    }
+}
+
+fn collect(
+    kind: PickupKind,
+    currency: &mut Currency,
+    health: &mut Health,
+    power_ups: &mut PowerUps,
+    progression: &mut Progression,
+) {
+    match kind {
+        PickupKind::Gold(amount) => currency.gold += amount,
+        PickupKind::Health(amount) => health.current = (health.current + amount).min(health.max),
+        PickupKind::PowerUp(PowerUpKind::Haste) => {
+            power_ups.grant(PowerUpKind::Haste, HASTE_DURATION);
+        }
+        PickupKind::PowerUp(PowerUpKind::Magnet) => {
+            power_ups.grant(PowerUpKind::Magnet, MAGNET_SURGE_DURATION);
+        }
+        PickupKind::Relic => {
+            let unowned: Vec<_> = RELIC_LIST
+                .into_iter()
+                .filter(|relic| !progression.has_relic(*relic))
+                .collect();
+            if unowned.is_empty() {
+                currency.gold += RELIC_FALLBACK_GOLD;
+            } else {
+                let relic = unowned[rand::thread_rng().gen_range(0..unowned.len())];
+                progression.relics.push(relic);
+            }
+        }
+    }
+}
+
+/// Moves pickups towards the player inside the magnet radius and collects them on contact.
+/// Once the round is cleared every pickup is vacuumed in regardless of distance.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn pickup_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    round_manager: Res<RoundManager>,
+    mut run_stats: ResMut<RunStats>,
+    mut pickups: Query<(Entity, &mut Transform, &mut Pickup), Without<Player>>,
+    player: Single<
+        (
+            &Transform,
+            &PlayerStats,
+            &mut Currency,
+            &mut Health,
+            &mut PowerUps,
+            &mut Progression,
+        ),
+        With<Player>,
+    >,
+) {
+    let (player_transform, stats, mut currency, mut health, mut power_ups, mut progression) =
+        player.into_inner();
+    let player_pos = player_transform.translation.truncate();
+    let vacuum = round_manager.round_state == RoundState::Collecting;
+    let magnet_radius = if power_ups.is_active(PowerUpKind::Magnet) {
+        MAGNET_SURGE_RADIUS
+    } else {
+        stats.magnet_radius
+    };
+
+    for (entity, mut transform, mut pickup) in &mut pickups {
+        let pos = transform.translation.truncate();
+        let to_player = player_pos - pos;
+        let distance = to_player.length();
+
+        if distance <= COLLECT_RADIUS {
+            let gold_before = currency.gold;
+            collect(
+                pickup.kind,
+                &mut currency,
+                &mut health,
+                &mut power_ups,
+                &mut progression,
+            );
+            if currency.gold > gold_before {
+                run_stats
+                    .record_gold_earned(round_manager.current_round, currency.gold - gold_before);
+            }
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if vacuum {
+            // Accelerate with time on the ground so far-away loot still arrives quickly
+            let speed = VACUUM_ACCELERATION.mul_add(pickup.lifetime.elapsed_secs(), VACUUM_SPEED);
+            pickup.lifetime.tick(time.delta());
+            let step = (speed * time.delta_secs()).min(distance);
+            transform.translation += (to_player / distance * step).extend(0.0);
+            continue;
+        }
+
+        pickup.lifetime.tick(time.delta());
+        if pickup.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if distance <= magnet_radius {
+            // Pull harder the closer the pickup gets
+            let pull = MAGNET_PULL_SPEED * (2.0 - distance / magnet_radius);
+            let step = (pull * time.delta_secs()).min(distance);
+            transform.translation += (to_player / distance * step).extend(0.0);
+        }
+    }
+}
+
+/// Blinks pickups that are about to time out
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn pickup_blink_system(
+    round_manager: Res<RoundManager>,
+    mut pickups: Query<(&Pickup, &mut Visibility)>,
+) {
+    let vacuum = round_manager.round_state == RoundState::Collecting;
+    for (pickup, mut visibility) in &mut pickups {
+        let remaining = pickup.lifetime.remaining_secs();
+        let hidden = !vacuum
+            && remaining < BLINK_TIME
+            && ((remaining / BLINK_INTERVAL) as u32).is_multiple_of(2);
+        visibility.set_if_neq(if hidden {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn tick_power_ups(time: Res<Time>, mut power_ups: Single<&mut PowerUps, With<Player>>) {
+    power_ups.tick(time.delta());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn spawn_gold(app: &mut App, x: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Pickup {
+                    kind: PickupKind::Gold(5),
+                    lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_pickups_collect_on_contact_and_vacuum_on_clear() {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.init_resource::<RoundManager>();
+        app.init_resource::<RunStats>();
+
+        let player = app.world_mut().spawn(Player).id();
+        let starting_gold = app.world().get::<Currency>(player).unwrap().gold;
+        let near = spawn_gold(&mut app, COLLECT_RADIUS * 0.5);
+        let far = spawn_gold(&mut app, 500.0);
+
+        app.world_mut().run_system_once(pickup_system).unwrap();
+        app.world_mut().flush();
+        assert!(app.world().get_entity(near).is_err());
+        assert!(app.world().get_entity(far).is_ok());
+        assert_eq!(
+            app.world().get::<Currency>(player).unwrap().gold,
+            starting_gold + 5
+        );
+
+        // Round cleared: the far coin flies in regardless of the magnet radius
+        app.world_mut().resource_mut::<RoundManager>().round_state = RoundState::Collecting;
+        for _ in 0..4 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            app.world_mut().run_system_once(pickup_system).unwrap();
+            app.world_mut().flush();
+        }
+        assert!(app.world().get_entity(far).is_err());
+        assert_eq!(
+            app.world().get::<Currency>(player).unwrap().gold,
+            starting_gold + 10
+        );
+        assert_eq!(app.world().resource::<RunStats>().gold_earned, 10);
+    }
+
+    #[test]
+    fn test_relic_pickup_grants_each_relic_once() {
+        let mut currency = Currency { gold: 0 };
+        let mut health = Health::default();
+        let mut power_ups = PowerUps::default();
+        let mut progression = Progression::default();
+
+        for _ in RELIC_LIST {
+            collect(
+                PickupKind::Relic,
+                &mut currency,
+                &mut health,
+                &mut power_ups,
+                &mut progression,
+            );
+        }
+        assert_eq!(progression.relics.len(), RELIC_LIST.len());
+        assert!(RELIC_LIST.iter().all(|relic| progression.has_relic(*relic)));
+        assert_eq!(currency.gold, 0);
+
+        // Nothing left to roll: the drop turns into gold
+        collect(
+            PickupKind::Relic,
+            &mut currency,
+            &mut health,
+            &mut power_ups,
+            &mut progression,
+        );
+        assert_eq!(progression.relics.len(), RELIC_LIST.len());
+        assert_eq!(currency.gold, RELIC_FALLBACK_GOLD);
+    }
+}
@@ -2,7 +2,8 @@
 use bevy::prelude::*;
 
 use crate::components::physics::Velocity;
-use crate::components::player::{Hand, HandType, Player, PlayerStats};
+use crate::components::pickup::PowerUps;
+use crate::components::player::{Dash, Hand, HandType, Health, Player, PlayerStats};
 use crate::components::status::UnitStatus;
 use crate::components::weapon::{Weapon, WeaponType};
 
@@ -57,9 +58,9 @@ pub fn spawn_player(
 #[allow(clippy::unnecessary_wraps)]
 pub fn move_player(
     virtual_input: Res<crate::resources::input_settings::VirtualInput>,
-    mut player: Single<(&mut Velocity, &PlayerStats, &UnitStatus), With<Player>>,
+    mut player: Single<(&mut Velocity, &PlayerStats, &UnitStatus, &PowerUps), With<Player>>,
 ) {
-    let (ref mut velocity, stats, unit_status) = *player;
+    let (ref mut velocity, stats, unit_status, power_ups) = *player;
 
     if unit_status.is_rooted() {
         velocity.linvel = Vec2::ZERO;
@@ -68,7 +69,61 @@ pub fn move_player(
 
     let direction = virtual_input.axis;
 
-    velocity.linvel = direction * stats.speed;
+    velocity.linvel =
+        direction * stats.speed * unit_status.speed_multiplier() * power_ups.speed_multiplier();
+}
+
+/// Starts a dash on input and drives its velocity burst.
+/// Runs after status ticking so the burst overrides both walking and forced movement.
+#[allow(clippy::needless_pass_by_value)]
+pub fn dash_system(
+    time: Res<Time>,
+    virtual_input: Res<crate::resources::input_settings::VirtualInput>,
+    player: Single<
+        (
+            &mut Dash,
+            &mut Velocity,
+            &mut Health,
+            &mut UnitStatus,
+            &Transform,
+        ),
+        With<Player>,
+    >,
+) {
+    let (mut dash, mut velocity, mut health, mut unit_status, transform) = player.into_inner();
+    dash.cooldown.tick(time.delta());
+    dash.active.tick(time.delta());
+
+    if virtual_input.dash && dash.cooldown.is_finished() && !unit_status.has_root() {
+        // Dash along the movement axis, or towards the cursor when standing still
+        let mut direction = virtual_input.axis;
+        if direction == Vec2::ZERO {
+            direction =
+                (virtual_input.cursor_world - transform.translation.truncate()).normalize_or_zero();
+        }
+
+        if direction != Vec2::ZERO {
+            dash.direction = direction;
+            dash.cooldown.reset();
+            dash.active.reset();
+            unit_status.clear_forced_movement();
+
+            // Extend i-frames to at least DASH_IFRAMES without shortening an active window
+            let iframes = &mut health.invulnerability_timer;
+            let remaining = iframes.remaining_secs();
+            if remaining < crate::configs::player::DASH_IFRAMES {
+                let duration = iframes.duration();
+                iframes.reset();
+                iframes.set_elapsed(duration.saturating_sub(std::time::Duration::from_secs_f32(
+                    crate::configs::player::DASH_IFRAMES,
+                )));
+            }
+        }
+    }
+
+    if dash.is_dashing() {
+        velocity.linvel = dash.direction * crate::configs::player::DASH_SPEED;
+    }
 }
 
 #[allow(clippy::needless_pass_by_value)]
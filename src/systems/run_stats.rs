@@ -0,0 +1,103 @@
+//! Run statistics hooks: combat and purchase observers, fight timer and the JSON export
+
+use crate::components::enemy::{EliteEnemy, EnemyKind, YellowEnemy};
+use crate::components::player::{Hand, HandType, Player};
+use crate::configs::stats::RUN_STATS_SAVE_NAME;
+use crate::resources::round::{RoundManager, RoundState, RunStartedEvent};
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::RunSettings;
+use crate::resources::run_stats::RunStats;
+use crate::systems::combat::{DamageEvent, EnemyDeathEvent};
+use crate::systems::ui::menu::CardPurchasedEvent;
+use crate::utils::storage;
+use bevy::prelude::*;
+
+/// Remembers the seed and starting loadout, which identify the run on the leaderboard
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_run_start(
+    _trigger: On<RunStartedEvent>,
+    mut stats: ResMut<RunStats>,
+    run_seed: Res<RunSeed>,
+    hands: Query<&Hand>,
+) {
+    stats.seed = run_seed.seed;
+    for hand in &hands {
+        match hand.side {
+            HandType::Left => stats.loadout.left = hand.equipped_weapon,
+            HandType::Right => stats.loadout.right = hand.equipped_weapon,
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_damage_stats(
+    trigger: On<DamageEvent>,
+    mut stats: ResMut<RunStats>,
+    round_manager: Res<RoundManager>,
+    player: Single<Entity, With<Player>>,
+) {
+    let event = trigger.event();
+    let round = round_manager.current_round;
+    if event.entity == *player {
+        stats.record_damage_taken(round, event.source, event.damage);
+    } else {
+        stats.record_damage_dealt(round, event.source, event.damage, event.crit, event.healed);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_kill_stats(
+    trigger: On<EnemyDeathEvent>,
+    mut stats: ResMut<RunStats>,
+    round_manager: Res<RoundManager>,
+    enemy_query: Query<(Has<EliteEnemy>, Has<YellowEnemy>)>,
+) {
+    let Ok((elite, yellow)) = enemy_query.get(trigger.event().entity) else {
+        return;
+    };
+    stats.record_kill(
+        round_manager.current_round,
+        EnemyKind::from_markers(elite, yellow),
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_purchase_stats(
+    trigger: On<CardPurchasedEvent>,
+    mut stats: ResMut<RunStats>,
+    round_manager: Res<RoundManager>,
+) {
+    stats.record_gold_spent(round_manager.current_round, trigger.event().price);
+}
+
+/// Counts fight time; the shop between rounds does not count
+#[allow(clippy::needless_pass_by_value)]
+pub fn tick_run_stats(
+    time: Res<Time>,
+    round_manager: Res<RoundManager>,
+    mut stats: ResMut<RunStats>,
+) {
+    if round_manager.has_started && round_manager.round_state != RoundState::Shop {
+        stats.add_time(round_manager.current_round, time.delta_secs());
+    }
+}
+
+/// The run died on `current_round`, so every earlier round was survived
+#[allow(clippy::needless_pass_by_value)]
+pub fn finish_run_stats(round_manager: Res<RoundManager>, mut stats: ResMut<RunStats>) {
+    stats.rounds_survived = round_manager.current_round.saturating_sub(1);
+    // Make sure the fatal round shows up in the breakdown even if nothing happened in it
+    stats.round_mut(round_manager.current_round);
+}
+
+/// Saves the finished run as JSON and returns where it went.
+pub fn export_run_stats(stats: &RunStats, settings: &RunSettings) -> Option<String> {
+    let json = match stats.to_json(settings) {
+        Ok(json) => json,
+        Err(err) => {
+            warn!("Failed to serialize run stats: {err}");
+            return None;
+        }
+    };
+    storage::write(RUN_STATS_SAVE_NAME, &json).then(|| storage::location(RUN_STATS_SAVE_NAME))
+}
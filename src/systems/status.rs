@@ -12,7 +12,7 @@ pub fn tick_status_system(
         let mut has_forced_movement = false;
 
         status.effects.retain_mut(|effect| match effect {
-            StatusEffect::Rooted { timer } => {
+            StatusEffect::Rooted { timer } | StatusEffect::Slowed { timer, .. } => {
                 timer.tick(time.delta());
                 !timer.is_finished()
             }
@@ -137,4 +137,54 @@ mod tests {
             assert!(status.effects.is_empty());
         }
     }
+
+    #[test]
+    fn test_slowed_status() {
+        let mut app = App::new();
+        app.add_systems(Update, tick_status_system);
+        app.init_resource::<Time>();
+
+        let entity = app.world_mut().spawn(UnitStatus::default()).id();
+
+        // Slow to 50% for 1 second; a weaker slow refreshes it without weakening or stacking
+        {
+            let mut status = app.world_mut().get_mut::<UnitStatus>(entity).unwrap();
+            status.slow(0.5, 1.0);
+            status.slow(0.8, 1.0);
+            assert!((status.speed_multiplier() - 0.5).abs() < f32::EPSILON);
+            assert_eq!(status.effects.len(), 1);
+            assert!(!status.is_rooted());
+        }
+
+        // Tick past 1.0s - slow expired
+        {
+            let mut time = app.world_mut().resource_mut::<Time>();
+            time.advance_by(Duration::from_millis(1100));
+        }
+        app.update();
+
+        {
+            let status = app.world().get::<UnitStatus>(entity).unwrap();
+            assert!((status.speed_multiplier() - 1.0).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_clear_forced_movement_keeps_root() {
+        let mut status = UnitStatus::default();
+        status.add(StatusEffect::ForcedMovement {
+            timer: Timer::from_seconds(1.0, TimerMode::Once),
+            direction: Vec2::X,
+            speed: 100.0,
+            move_type: crate::components::status::ForceType::Knockback,
+        });
+        // Forced movement alone blocks walking but is not a hard root
+        assert!(status.is_rooted());
+        assert!(!status.has_root());
+
+        status.root(1.0);
+        status.clear_forced_movement();
+        assert!(status.has_root());
+        assert_eq!(status.effects.len(), 1);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::components::player::HandType;
+use crate::resources::game_settings::GameSettings;
 use bevy::prelude::*;
 
 #[derive(Component)]
@@ -20,6 +21,9 @@ pub struct CooldownOverlay {
     pub side: HandType,
 }
 
+#[derive(Component)]
+pub struct DashCooldownOverlay;
+
 #[derive(Component)]
 pub struct ShurikenCountText {
     pub side: HandType,
@@ -31,12 +35,68 @@ pub struct HealthBar;
 #[derive(Component)]
 pub struct HealthText;
 
+#[derive(Component)]
+pub struct ManaBar;
+
+#[derive(Component)]
+pub struct ManaText;
+
+#[derive(Component)]
+pub struct XpBar;
+
+/// Current level next to the XP bar
+#[derive(Component)]
+pub struct XpText;
+
+/// Active power-ups and their remaining time
+#[derive(Component)]
+pub struct PowerUpText;
+
+/// Row of owned relics under the gold counter
+#[derive(Component)]
+pub struct RelicStrip;
+
+/// Corner minimap frame under the round counter
+#[derive(Component)]
+pub struct Minimap;
+
+/// Pooled enemy marker inside the minimap
+#[derive(Component)]
+pub struct MinimapDot;
+
+#[derive(Component)]
+pub struct LevelUpUI;
+
+/// Row the perk cards are rebuilt into by `refresh_perk_cards`
+#[derive(Component)]
+pub struct PerkGrid;
+
+/// Perk card, indexing into `PerkOffers`
+#[derive(Component)]
+pub struct PerkCard(pub usize);
+
+#[derive(Component)]
+pub struct PauseMenuUI;
+
 #[derive(Component)]
 pub struct GameOverUI;
 
 #[derive(Component)]
 pub struct NewGameButton;
 
+/// Status line under the game over buttons, updated after exporting the run stats
+#[derive(Component)]
+pub struct RunStatsExportText;
+
+/// Top-center column unlock toasts are stacked in; lives for the whole app
+#[derive(Component)]
+pub struct ToastStack;
+
+#[derive(Component)]
+pub struct AchievementToast {
+    pub timer: Timer,
+}
+
 #[derive(Component)]
 pub struct GoldText;
 
@@ -66,6 +126,90 @@ pub struct SettingsBackButton;
 #[derive(Component)]
 pub struct SensitivityValueText;
 
+#[derive(Component)]
+pub struct TextScaleValueText;
+
+/// On/off preferences stored in `GameSettings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingToggle {
+    DamageNumbers,
+    OffscreenIndicators,
+    Minimap,
+    EnemyGlyphs,
+    ReducedMotion,
+}
+
+impl SettingToggle {
+    pub const fn value(self, settings: &GameSettings) -> bool {
+        match self {
+            Self::DamageNumbers => settings.damage_numbers,
+            Self::OffscreenIndicators => settings.offscreen_indicators,
+            Self::Minimap => settings.minimap,
+            Self::EnemyGlyphs => settings.enemy_glyphs,
+            Self::ReducedMotion => settings.reduced_motion,
+        }
+    }
+
+    pub const fn toggle(self, settings: &mut GameSettings) {
+        match self {
+            Self::DamageNumbers => settings.damage_numbers = !settings.damage_numbers,
+            Self::OffscreenIndicators => {
+                settings.offscreen_indicators = !settings.offscreen_indicators;
+            }
+            Self::Minimap => settings.minimap = !settings.minimap,
+            Self::EnemyGlyphs => settings.enemy_glyphs = !settings.enemy_glyphs,
+            Self::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct SettingToggleButton(pub SettingToggle);
+
+/// Volume sliders stored in `GameSettings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeChannel {
+    Master,
+    Sfx,
+    Music,
+}
+
+impl VolumeChannel {
+    pub const fn value(self, settings: &GameSettings) -> f32 {
+        match self {
+            Self::Master => settings.master_volume,
+            Self::Sfx => settings.sfx_volume,
+            Self::Music => settings.music_volume,
+        }
+    }
+
+    pub const fn set(self, settings: &mut GameSettings, volume: f32) {
+        match self {
+            Self::Master => settings.master_volume = volume,
+            Self::Sfx => settings.sfx_volume = volume,
+            Self::Music => settings.music_volume = volume,
+        }
+    }
+}
+
+/// Clickable slider track; its child `VolumeFill` shows the current level
+#[derive(Component)]
+pub struct VolumeSlider(pub VolumeChannel);
+
+#[derive(Component)]
+pub struct VolumeFill(pub VolumeChannel);
+
+#[derive(Component)]
+pub struct VolumeValueText(pub VolumeChannel);
+
+/// Cycles `GameSettings::language` and rebuilds the settings screen in the new language
+#[derive(Component)]
+pub struct LanguageButton;
+
+/// Cycles `GameSettings::palette`
+#[derive(Component)]
+pub struct PaletteButton;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
     MoveUp,
@@ -74,6 +218,7 @@ pub enum Action {
     MoveRight,
     LeftSkill,
     RightSkill,
+    Dash,
 }
 
 #[derive(Component)]
@@ -0,0 +1,21 @@
+//! Swaps Bevy's ASCII-only default font for one covering every shipped language
+
+use bevy::prelude::*;
+
+/// Noto Sans (SIL OFL, see `assets/fonts/NotoSans-LICENSE.txt`), with full Vietnamese coverage
+const UI_FONT: &[u8] = include_bytes!("../../../assets/fonts/NotoSans-Regular.ttf");
+
+/// Replaces the default font asset in place, so every `TextFont { ..default() }` picks it up
+/// without naming a handle. Runs at startup, before any text is laid out.
+pub fn install_ui_font(mut fonts: ResMut<Assets<Font>>) {
+    let installed = Font::try_from_bytes(UI_FONT.to_vec())
+        .map_err(|err| err.to_string())
+        .and_then(|font| {
+            fonts
+                .insert(&Handle::<Font>::default(), font)
+                .map_err(|err| err.to_string())
+        });
+    if let Err(err) = installed {
+        error!("Keeping the default font, UI font failed to load: {err}");
+    }
+}
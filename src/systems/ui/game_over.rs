@@ -1,9 +1,290 @@
-use super::components::{GameOverUI, NewGameButton};
+use super::components::{GameOverUI, NewGameButton, RunStatsExportText};
+use crate::configs::stats::GAME_OVER_ROUND_ROWS;
+use crate::resources::leaderboard::Leaderboard;
+use crate::resources::locale::Locale;
+use crate::resources::profile::MetaProfile;
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
+use crate::resources::run_stats::RunStats;
+use crate::systems::run_stats::export_run_stats;
 
 use bevy::prelude::*;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+const STAT_COLOR: Color = Color::srgb(0.85, 0.85, 0.9);
+const STAT_DIM_COLOR: Color = Color::srgb(0.6, 0.6, 0.7);
+const HEADER_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_time(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+/// "Gun 1200, Magic 300", or "-" when nothing was recorded
+fn breakdown<K: Debug>(map: &BTreeMap<K, f32>) -> String {
+    if map.is_empty() {
+        return "-".to_string();
+    }
+    map.iter()
+        .map(|(key, value)| format!("{key:?} {value:.0}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn spawn_stat_line(parent: &mut ChildSpawnerCommands, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        },
+    ));
+}
+
+fn spawn_column_title(parent: &mut ChildSpawnerCommands, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(HEADER_COLOR),
+        Node {
+            margin: UiRect::bottom(Val::Px(8.0)),
+            ..default()
+        },
+    ));
+}
 
 #[allow(clippy::too_many_lines)]
-pub fn spawn_game_over_menu(mut commands: Commands) {
+fn spawn_run_totals(parent: &mut ChildSpawnerCommands, stats: &RunStats, locale: &Locale) {
+    spawn_column_title(parent, locale.get("game_over.run"));
+    let kills = stats
+        .kills_by_enemy
+        .iter()
+        .map(|(kind, count)| format!("{kind:?} {count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let hands = if stats.other_damage > 0.0 {
+        locale.format(
+            "game_over.hands_with_relics",
+            &[
+                ("hands", &breakdown(&stats.damage_by_hand)),
+                ("relics", &format!("{:.0}", stats.other_damage)),
+            ],
+        )
+    } else {
+        breakdown(&stats.damage_by_hand)
+    };
+
+    let lines = [
+        (
+            locale.format(
+                "game_over.survived",
+                &[
+                    ("rounds", &stats.rounds_survived),
+                    ("time", &format_time(stats.time)),
+                ],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.kills",
+                &[("kills", &stats.total_kills()), ("breakdown", &kills)],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.gold",
+                &[("earned", &stats.gold_earned), ("spent", &stats.gold_spent)],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.crits",
+                &[
+                    ("crits", &stats.crits),
+                    ("healed", &format!("{:.0}", stats.lifesteal_healed)),
+                ],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.dealt",
+                &[("damage", &format!("{:.0}", stats.total_damage_dealt()))],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.by_weapon",
+                &[("breakdown", &breakdown(&stats.damage_by_weapon))],
+            ),
+            STAT_DIM_COLOR,
+        ),
+        (
+            locale.format("game_over.by_hand", &[("breakdown", &hands)]),
+            STAT_DIM_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.taken",
+                &[("damage", &format!("{:.0}", stats.total_damage_taken()))],
+            ),
+            STAT_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.contact",
+                &[("breakdown", &breakdown(&stats.contact_damage_taken))],
+            ),
+            STAT_DIM_COLOR,
+        ),
+        (
+            locale.format(
+                "game_over.projectiles",
+                &[("breakdown", &breakdown(&stats.projectile_damage_taken))],
+            ),
+            STAT_DIM_COLOR,
+        ),
+    ];
+    for (text, color) in lines {
+        spawn_stat_line(parent, text, color);
+    }
+}
+
+fn spawn_grid_cell(parent: &mut ChildSpawnerCommands, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 15.0,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// Per-round table; only the last `GAME_OVER_ROUND_ROWS` rounds fit on screen
+fn spawn_round_breakdown(parent: &mut ChildSpawnerCommands, stats: &RunStats, locale: &Locale) {
+    spawn_column_title(parent, locale.get("game_over.rounds"));
+    parent
+        .spawn(Node {
+            display: Display::Grid,
+            grid_template_columns: RepeatedGridTrack::auto(6),
+            column_gap: Val::Px(16.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|grid| {
+            for header in [
+                "records.round",
+                "records.time",
+                "game_over.column.dealt",
+                "game_over.column.taken",
+                "records.kills",
+                "game_over.column.gold",
+            ] {
+                spawn_grid_cell(grid, locale.get(header).to_string(), STAT_DIM_COLOR);
+            }
+            let skipped = stats.rounds.len().saturating_sub(GAME_OVER_ROUND_ROWS);
+            for entry in stats.rounds.iter().skip(skipped) {
+                let cells = [
+                    entry.round.to_string(),
+                    format_time(entry.time),
+                    format!("{:.0}", entry.damage_dealt),
+                    format!("{:.0}", entry.damage_taken),
+                    entry.kills.to_string(),
+                    format!("+{}/-{}", entry.gold_earned, entry.gold_spent),
+                ];
+                for cell in cells {
+                    spawn_grid_cell(grid, cell, STAT_COLOR);
+                }
+            }
+        });
+    if stats.rounds.len() > GAME_OVER_ROUND_ROWS {
+        spawn_stat_line(
+            parent,
+            locale.get("game_over.earlier_rounds").to_string(),
+            STAT_DIM_COLOR,
+        );
+    }
+}
+
+fn spawn_export_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(240.0),
+                height: Val::Px(80.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.2, 0.25, 0.35, 1.0)),
+        ))
+        .observe(
+            |_trigger: On<Pointer<Click>>,
+             stats: Res<RunStats>,
+             settings: Res<RunSettings>,
+             locale: Res<Locale>,
+             mut status: Query<&mut Text, With<RunStatsExportText>>| {
+                let message = export_run_stats(&stats, &settings).map_or_else(
+                    || locale.get("game_over.export_failed").to_string(),
+                    |location| locale.format("game_over.export_saved", &[("location", &location)]),
+                );
+                for mut text in &mut status {
+                    text.0.clone_from(&message);
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.3, 0.35, 0.5, 1.0));
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.2, 0.25, 0.35, 1.0));
+                }
+            },
+        )
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("game_over.export")),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
+pub fn spawn_game_over_menu(
+    mut commands: Commands,
+    profile: Res<MetaProfile>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    run_stats: Res<RunStats>,
+    leaderboard: Res<Leaderboard>,
+    locale: Res<Locale>,
+) {
     commands
         .spawn((
             Node {
@@ -21,58 +302,179 @@ pub fn spawn_game_over_menu(mut commands: Commands) {
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("GAME OVER"),
+                Text::new(locale.get("game_over.title")),
                 TextFont {
-                    font_size: 100.0,
+                    font_size: 72.0,
                     ..default()
                 },
                 TextColor(Color::srgb(1.0, 0.0, 0.0)),
                 Node {
-                    margin: UiRect::bottom(Val::Px(40.0)),
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Text::new(if run_settings.is_endless() {
+                    locale.format(
+                        "game_over.summary.endless",
+                        &[
+                            ("label", &run_settings.label(&locale).to_uppercase()),
+                            ("time", &format_time(run_stats.time)),
+                        ],
+                    )
+                } else {
+                    locale.format(
+                        "game_over.summary.rounds",
+                        &[
+                            ("label", &run_settings.label(&locale).to_uppercase()),
+                            ("round", &round_manager.current_round),
+                        ],
+                    )
+                }),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Text::new(locale.format(
+                    "game_over.shards",
+                    &[("reward", &profile.last_reward), ("total", &profile.shards)],
+                )),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.55, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
                     ..default()
                 },
             ));
 
+            if let Some(rank) = leaderboard.last_rank {
+                let title = if rank == 0 {
+                    locale.get("game_over.best_run").to_string()
+                } else {
+                    locale.format("game_over.high_score", &[("rank", &(rank + 1))])
+                };
+                parent.spawn((
+                    Text::new(title),
+                    TextFont {
+                        font_size: 32.0,
+                        ..default()
+                    },
+                    TextColor(HEADER_COLOR),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(20.0)),
+                        ..default()
+                    },
+                ));
+            }
+
             parent
                 .spawn((
-                    Button,
                     Node {
-                        width: Val::Px(240.0),
-                        height: Val::Px(80.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(40.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        margin: UiRect::bottom(Val::Px(20.0)),
                         ..default()
                     },
-                    BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0)),
-                    NewGameButton,
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 0.9)),
+                    BorderRadius::all(Val::Px(8.0)),
                 ))
-                .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
-                    crate::systems::ui::menu::spawn_confirmation_dialog(&mut commands);
-                })
-                .observe(
-                    |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
-                        if let Ok(mut color) = color.get_mut(trigger.entity) {
-                            *color = BackgroundColor(Color::srgba(0.4, 0.4, 0.4, 1.0));
-                        }
-                    },
-                )
-                .observe(
-                    |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
-                        if let Ok(mut color) = color.get_mut(trigger.entity) {
-                            *color = BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0));
-                        }
-                    },
-                )
-                .with_children(|btn| {
-                    btn.spawn((
-                        Text::new("NEW GAME"),
-                        TextFont {
-                            font_size: 32.0,
+                .with_children(|panel| {
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        })
+                        .with_children(|column| spawn_run_totals(column, &run_stats, &locale));
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
                             ..default()
-                        },
-                        TextColor(Color::WHITE),
-                    ));
+                        })
+                        .with_children(|column| spawn_round_breakdown(column, &run_stats, &locale));
+                });
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_export_button(row, &locale);
+                    spawn_new_game_button(row, &locale);
                 });
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(STAT_DIM_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+                RunStatsExportText,
+            ));
+        });
+}
+
+fn spawn_new_game_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(240.0),
+                height: Val::Px(80.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+            NewGameButton,
+        ))
+        .observe(
+            |_trigger: On<Pointer<Click>>, mut commands: Commands, locale: Res<Locale>| {
+                crate::systems::ui::menu::spawn_confirmation_dialog(&mut commands, &locale);
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.4, 0.4, 0.4, 1.0));
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0));
+                }
+            },
+        )
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("menu.new_game")),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
         });
 }
 
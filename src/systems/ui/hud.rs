@@ -1,16 +1,29 @@
 use super::components::{
-    CooldownOverlay, GoldText, HUDHandIndicator, HUDIcon, HUDRoot, HealthBar, HealthText,
-    MagicSlotIndicator, RoundText, ShurikenCountText,
+    CooldownOverlay, DashCooldownOverlay, GoldText, HUDHandIndicator, HUDIcon, HUDRoot, HealthBar,
+    HealthText, MagicSlotIndicator, ManaBar, ManaText, Minimap, MinimapDot, PowerUpText,
+    RelicStrip, RoundText, ShurikenCountText, XpBar, XpText,
 };
-use crate::components::player::{CombatStats, Currency, Hand, HandType, Health, Player};
-use crate::components::weapon::{MagicLoadout, SpellType, WeaponType};
+use crate::components::enemy::{EliteEnemy, Enemy, EnemyKind, YellowEnemy};
+use crate::components::physics::UniformGrid;
+use crate::components::pickup::{PowerUpKind, PowerUps};
+use crate::components::player::{
+    CombatStats, Currency, Dash, Experience, Hand, HandType, Health, Mana, Player, Progression,
+};
+use crate::components::weapon::{MagicLoadout, WeaponType};
+use crate::configs::accessibility::indicator_color;
+use crate::configs::indicators::{
+    MINIMAP_DOT_SIZE, MINIMAP_MAX_DOTS, MINIMAP_PLAYER_COLOR, MINIMAP_RANGE, MINIMAP_SIZE,
+    MINIMAP_THREAT_DOT_SIZE,
+};
+use crate::resources::game_settings::GameSettings;
 use crate::resources::input_settings::VirtualInput;
+use crate::resources::locale::Locale;
 use crate::systems::ui::MenuButton;
 
 use bevy::prelude::*;
 
 #[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
-pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>, locale: Res<Locale>) {
     // Root UI Node (HUD)
     commands
         .spawn((
@@ -28,7 +41,7 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
         .with_children(|parent| {
             // Gold Display (Top Left)
             parent.spawn((
-                Text::new("Gold: 0"),
+                Text::new(locale.format("hud.gold", &[("gold", &0)])),
                 TextFont {
                     font_size: 24.0,
                     ..default()
@@ -43,9 +56,21 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 GoldText,
             ));
 
+            // Relic Strip (below Gold)
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(54.0),
+                    left: Val::Px(20.0),
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+                RelicStrip,
+            ));
+
             // Round Display (Top Right)
             parent.spawn((
-                Text::new("Round: 1"),
+                Text::new(locale.format("hud.round", &[("round", &1)])),
                 TextFont {
                     font_size: 30.0,
                     ..default()
@@ -60,6 +85,51 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 RoundText,
             ));
 
+            // Minimap (below Round Display), dots are positioned by `update_minimap`
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(64.0),
+                        right: Val::Px(20.0),
+                        width: Val::Px(MINIMAP_SIZE),
+                        height: Val::Px(MINIMAP_SIZE),
+                        border: UiRect::all(Val::Px(1.0)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.45)),
+                    Pickable::IGNORE,
+                    Minimap,
+                ))
+                .with_children(|map| {
+                    map.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px((MINIMAP_SIZE - MINIMAP_THREAT_DOT_SIZE) / 2.0),
+                            top: Val::Px((MINIMAP_SIZE - MINIMAP_THREAT_DOT_SIZE) / 2.0),
+                            width: Val::Px(MINIMAP_THREAT_DOT_SIZE),
+                            height: Val::Px(MINIMAP_THREAT_DOT_SIZE),
+                            ..default()
+                        },
+                        BorderRadius::MAX,
+                        BackgroundColor(MINIMAP_PLAYER_COLOR),
+                    ));
+                    for _ in 0..MINIMAP_MAX_DOTS {
+                        map.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                display: Display::None,
+                                ..default()
+                            },
+                            BorderRadius::MAX,
+                            BackgroundColor(Color::NONE),
+                            MinimapDot,
+                        ));
+                    }
+                });
+
             // Health Bar (Top Center)
             parent
                 .spawn(Node {
@@ -103,6 +173,112 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
                 HealthText,
             ));
+
+            // Mana Bar (below Health Bar)
+            parent
+                .spawn(Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(46.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-100.0)),
+                    width: Val::Px(200.0),
+                    height: Val::Px(12.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                })
+                .insert(BorderColor::all(Color::WHITE))
+                .insert(BackgroundColor(Color::BLACK))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(MANA_BAR_COLOR),
+                        ManaBar,
+                    ));
+                });
+
+            // Mana Text
+            parent.spawn((
+                Text::new("100 / 100"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(45.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(110.0)),
+                    ..default()
+                },
+                ManaText,
+            ));
+
+            // XP Bar (below Mana Bar)
+            parent
+                .spawn(Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(64.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-100.0)),
+                    width: Val::Px(200.0),
+                    height: Val::Px(8.0),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                })
+                .insert(BorderColor::all(Color::WHITE))
+                .insert(BackgroundColor(Color::BLACK))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(XP_BAR_COLOR),
+                        XpBar,
+                    ));
+                });
+
+            // Level Text
+            parent.spawn((
+                Text::new(locale.format("hud.level", &[("level", &1)])),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.6, 1.0)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(61.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(110.0)),
+                    ..default()
+                },
+                XpText,
+            ));
+
+            // Active Power-ups (below Mana Bar)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(80.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-100.0)),
+                    ..default()
+                },
+                PowerUpText,
+            ));
             // Left Hand Indicator
             parent
                 .spawn((
@@ -174,6 +350,55 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ));
                 });
 
+            // Dash Button (touch), left of the Right Hand Indicator
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(90.0),
+                        height: Val::Px(90.0),
+                        position_type: PositionType::Absolute,
+                        right: Val::Px(160.0),
+                        bottom: Val::Px(20.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(3.0)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(45.0)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
+                    BorderColor::all(Color::srgb(0.0, 1.0, 1.0)),
+                ))
+                .observe(
+                    |_: On<Pointer<Click>>, mut virtual_input: ResMut<VirtualInput>| {
+                        virtual_input.dash_clicked = true;
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(locale.get("hud.dash")),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    // Cooldown Overlay
+                    btn.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(0.0),
+                            position_type: PositionType::Absolute,
+                            bottom: Val::Px(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                        DashCooldownOverlay,
+                    ));
+                });
+
             // Center Menu Button
             parent
                 .spawn((
@@ -197,7 +422,7 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 )
                 .with_children(|btn| {
                     btn.spawn((
-                        Text::new("MENU"),
+                        Text::new(locale.get("hud.menu")),
                         TextFont {
                             font_size: 24.0,
                             ..default()
@@ -307,24 +532,7 @@ pub fn update_hud_indicators(
                     crate::components::weapon::GunMode::Shotgun => "ui/icons/gun_shotgun.png",
                     crate::components::weapon::GunMode::Rapid => "ui/icons/gun_rapid.png",
                 },
-                Some(WeaponType::Magic) => {
-                    let spell = if magic.active_slot
-                        == crate::components::weapon::ActiveSpellSlot::Primary
-                    {
-                        magic.primary
-                    } else {
-                        magic.secondary
-                    };
-                    match spell {
-                        SpellType::EnergyBolt => "ui/icons/magic_bolt.png",
-                        SpellType::Laser => "ui/icons/magic_laser.png",
-                        SpellType::Nova => "ui/icons/magic_nova.png",
-                        SpellType::Blink => "ui/icons/magic_blink.png",
-                        SpellType::Global => "ui/icons/magic_global.png",
-                        SpellType::ForcePush => "ui/icons/magic_push.png",
-                        SpellType::ForcePull => "ui/icons/magic_pull.png",
-                    }
-                }
+                Some(WeaponType::Magic) => magic.active_spell().definition().icon(),
             };
 
             image_node.image = asset_server.load(icon_path);
@@ -350,13 +558,127 @@ pub fn update_health_ui(
     }
 }
 
+const MANA_BAR_COLOR: Color = Color::srgb(0.2, 0.5, 1.0);
+const MANA_STARVED_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+const XP_BAR_COLOR: Color = Color::srgb(0.55, 0.35, 1.0);
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_mana_ui(
+    mut mana_bar_query: Query<(&mut Node, &mut BackgroundColor), With<ManaBar>>,
+    mut mana_text_query: Query<&mut Text, With<ManaText>>,
+    player: Single<&Mana, With<Player>>,
+) {
+    let mana = *player;
+    for (mut node, mut color) in &mut mana_bar_query {
+        let percent = (mana.current / mana.max).clamp(0.0, 1.0) * 100.0;
+        node.width = Val::Percent(percent);
+        // Flash red while a cast is being refused for lack of mana
+        *color = BackgroundColor(if mana.is_starved() {
+            MANA_STARVED_COLOR
+        } else {
+            MANA_BAR_COLOR
+        });
+    }
+
+    for mut text in &mut mana_text_query {
+        text.0 = format!("{:.0} / {:.0}", mana.current, mana.max);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_xp_ui(
+    mut bar_query: Query<&mut Node, With<XpBar>>,
+    mut text_query: Query<&mut Text, With<XpText>>,
+    player: Single<&Experience, With<Player>>,
+    locale: Res<Locale>,
+) {
+    let experience = *player;
+    #[allow(clippy::cast_precision_loss)]
+    let percent = (experience.xp as f32 / experience.xp_needed() as f32).clamp(0.0, 1.0) * 100.0;
+    for mut node in &mut bar_query {
+        node.width = Val::Percent(percent);
+    }
+
+    for mut text in &mut text_query {
+        text.0 = locale.format("hud.level", &[("level", &experience.level)]);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_power_up_ui(
+    mut query: Query<&mut Text, With<PowerUpText>>,
+    player: Single<&PowerUps, With<Player>>,
+    locale: Res<Locale>,
+) {
+    let label = player
+        .active
+        .iter()
+        .map(|(kind, timer)| {
+            let name = locale.get(match kind {
+                PowerUpKind::Haste => "power_up.haste",
+                PowerUpKind::Magnet => "power_up.magnet",
+            });
+            format!("{name} {:.1}s", timer.remaining_secs())
+        })
+        .collect::<Vec<_>>()
+        .join("   ");
+
+    for mut text in &mut query {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+}
+
+/// Rebuilds the relic strip whenever a relic is gained or the run resets
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_relic_strip(
+    mut commands: Commands,
+    strip: Single<(Entity, Option<&Children>), With<RelicStrip>>,
+    progression: Single<&Progression, With<Player>>,
+) {
+    let (strip_entity, children) = *strip;
+    if children.map_or(0, RelationshipTarget::len) == progression.relics.len() {
+        return;
+    }
+
+    let slot_size = crate::configs::relic::STRIP_SLOT_SIZE;
+    commands
+        .entity(strip_entity)
+        .despawn_related::<Children>()
+        .with_children(|strip| {
+            for relic in &progression.relics {
+                strip
+                    .spawn(Node {
+                        width: Val::Px(slot_size),
+                        height: Val::Px(slot_size),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|slot| {
+                        crate::visuals::ui_icons::spawn_relic_icon(slot, *relic, slot_size);
+                        slot.spawn((
+                            Text::new(crate::configs::relic::get_relic_config(*relic).tag),
+                            TextFont {
+                                font_size: 10.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+        });
+}
+
 #[allow(clippy::needless_pass_by_value)]
 pub fn update_gold_ui(
     mut gold_text_query: Query<&mut Text, With<GoldText>>,
     player: Single<&Currency, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut gold_text_query {
-        text.0 = format!("Gold: {}", player.gold);
+        text.0 = locale.format("hud.gold", &[("gold", &player.gold)]);
     }
 }
 
@@ -364,9 +686,22 @@ pub fn update_gold_ui(
 pub fn update_round_text(
     mut query: Query<&mut Text, With<RoundText>>,
     round_manager: Res<crate::resources::round::RoundManager>,
+    run_settings: Res<crate::resources::run_settings::RunSettings>,
+    run_stats: Res<crate::resources::run_stats::RunStats>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
-        text.0 = format!("Round: {}", round_manager.current_round);
+        text.0 = if run_settings.is_endless() {
+            locale.format(
+                "hud.survived",
+                &[
+                    ("time", &super::game_over::format_time(run_stats.time)),
+                    ("level", &round_manager.current_round),
+                ],
+            )
+        } else {
+            locale.format("hud.round", &[("round", &round_manager.current_round)])
+        };
     }
 }
 
@@ -385,29 +720,16 @@ pub fn update_hud_magic_ui(
             bg.0 = Color::srgba(0.0, 0.0, 0.0, 0.4);
         }
 
-        let spell = match slot.slot {
-            crate::components::weapon::ActiveSpellSlot::Primary => magic.primary,
-            crate::components::weapon::ActiveSpellSlot::Secondary => magic.secondary,
-        };
-
         // Set icon
-        let icon_path = match spell {
-            SpellType::EnergyBolt => "ui/icons/magic_bolt.png",
-            SpellType::Laser => "ui/icons/magic_laser.png",
-            SpellType::Nova => "ui/icons/magic_nova.png",
-            SpellType::Blink => "ui/icons/magic_blink.png",
-            SpellType::Global => "ui/icons/magic_global.png",
-            SpellType::ForcePush => "ui/icons/magic_push.png",
-            SpellType::ForcePull => "ui/icons/magic_pull.png",
-        };
-        image.image = asset_server.load(icon_path);
+        let spell = magic.slot_spell(slot.slot).definition();
+        image.image = asset_server.load(spell.icon());
     }
 }
 
 #[allow(clippy::needless_pass_by_value)]
 pub fn update_cooldown_indicators(
     mut overlay_query: Query<(&mut Node, &CooldownOverlay)>,
-    hand_query: Query<(&Hand, &crate::components::weapon::Weapon)>,
+    hand_query: Query<(&Hand, &crate::components::weapon::Weapon, &MagicLoadout)>,
     player: Single<&CombatStats, With<Player>>,
     time: Res<Time>,
 ) {
@@ -415,10 +737,13 @@ pub fn update_cooldown_indicators(
     let combat_stats = *player;
 
     for (mut node, overlay) in &mut overlay_query {
-        if let Some((hand, weapon)) = hand_query.iter().find(|(h, _)| h.side == overlay.side) {
-            // Apply CDR only to Magic weapons
+        if let Some((hand, weapon, magic)) =
+            hand_query.iter().find(|(h, _, _)| h.side == overlay.side)
+        {
+            // Magic uses the active spell's cooldown, and only Magic benefits from CDR
             let effective_cooldown = if hand.equipped_weapon == Some(WeaponType::Magic) {
-                weapon.cooldown * (1.0 - combat_stats.cooldown_reduction)
+                magic.active_spell().definition().cooldown()
+                    * (1.0 - combat_stats.cooldown_reduction)
             } else {
                 weapon.cooldown
             };
@@ -453,6 +778,17 @@ pub fn update_cooldown_indicators(
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_dash_cooldown_ui(
+    mut overlay_query: Query<&mut Node, With<DashCooldownOverlay>>,
+    player: Single<&Dash, With<Player>>,
+) {
+    let progress = 1.0 - player.cooldown.fraction();
+    for mut node in &mut overlay_query {
+        node.height = Val::Percent(progress * 100.0);
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 pub fn update_shuriken_count_ui(
     mut text_query: Query<(&mut Text, &ShurikenCountText)>,
@@ -487,3 +823,69 @@ pub fn despawn_hud(mut commands: Commands, query: Query<Entity, With<HUDRoot>>)
         commands.entity(entity).despawn();
     }
 }
+
+/// Plots the enemies in the `UniformGrid` cells within `MINIMAP_RANGE` of the player,
+/// elites and mages first so they survive the dot cap
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn update_minimap(
+    settings: Res<GameSettings>,
+    grid: Res<UniformGrid>,
+    player: Single<&Transform, With<Player>>,
+    enemies: Query<(&Transform, Has<EliteEnemy>, Has<YellowEnemy>), With<Enemy>>,
+    mut frame: Single<&mut Node, (With<Minimap>, Without<MinimapDot>)>,
+    mut dots: Query<(&mut Node, &mut BackgroundColor), With<MinimapDot>>,
+) {
+    frame.display = if settings.minimap {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if !settings.minimap {
+        return;
+    }
+
+    let center = player.translation.truncate();
+    let range = Vec2::splat(MINIMAP_RANGE);
+    let mut plotted: Vec<(Vec2, EnemyKind)> = grid
+        .query_aabb(center - range, center + range)
+        .into_iter()
+        .filter_map(|entity| enemies.get(entity).ok())
+        .map(|(transform, elite, yellow)| {
+            (
+                (transform.translation.truncate() - center) / MINIMAP_RANGE,
+                EnemyKind::from_markers(elite, yellow),
+            )
+        })
+        .filter(|(offset, _)| offset.x.abs() <= 1.0 && offset.y.abs() <= 1.0)
+        .collect();
+    plotted.sort_by_key(|(_, kind)| std::cmp::Reverse(*kind));
+
+    let mut plotted = plotted.into_iter();
+    for (mut node, mut color) in &mut dots {
+        let Some((offset, kind)) = plotted.next() else {
+            node.display = Display::None;
+            continue;
+        };
+        let size = if kind == EnemyKind::Grunt {
+            MINIMAP_DOT_SIZE
+        } else {
+            MINIMAP_THREAT_DOT_SIZE
+        };
+        node.display = Display::Flex;
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+        node.left = Val::Px(
+            offset
+                .x
+                .mul_add(0.5, 0.5)
+                .mul_add(MINIMAP_SIZE, -size / 2.0),
+        );
+        node.top = Val::Px(
+            offset
+                .y
+                .mul_add(-0.5, 0.5)
+                .mul_add(MINIMAP_SIZE, -size / 2.0),
+        );
+        *color = BackgroundColor(indicator_color(settings.palette, kind));
+    }
+}
@@ -0,0 +1,294 @@
+//! Level-up perk draft: three cards in the shop card style, picked with keyboard, mouse or touch
+
+use super::components::{LevelUpUI, PerkCard, PerkGrid};
+use super::menu::shop::{get_shop_button_colors, get_shop_button_content};
+use crate::components::perk::Perk;
+use crate::components::player::{Experience, Player};
+use crate::configs::experience::get_perk_config;
+use crate::configs::shop::get_card_config;
+use crate::resources::locale::Locale;
+use crate::resources::perks::{PerkOffers, PerkSelection};
+use crate::systems::experience::PerkChosenEvent;
+use bevy::prelude::*;
+
+const TITLE_COLOR: Color = Color::srgb(0.75, 0.6, 1.0);
+const HINT_COLOR: Color = Color::srgb(0.6, 0.6, 0.7);
+const SELECTED_BORDER: Color = Color::WHITE;
+const NUMBER_KEYS: [KeyCode; 3] = [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3];
+
+/// (border, background, highlighted background, accent text), as in `get_shop_button_colors`
+const fn perk_colors(perk: Perk) -> (Color, Color, Color, Color) {
+    if let Perk::Upgrade(card) = perk {
+        return get_shop_button_colors(card);
+    }
+    let color = get_perk_config(perk).color;
+    (
+        color,
+        Color::srgba(0.12, 0.08, 0.18, 0.95),
+        Color::srgba(0.22, 0.15, 0.32, 1.0),
+        color,
+    )
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn spawn_level_up_screen(mut commands: Commands, locale: Res<Locale>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            LevelUpUI,
+        ))
+        .with_children(|parent| {
+            // Title and cards are filled in by `refresh_perk_cards`
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                PerkGrid,
+            ));
+
+            parent.spawn((
+                Text::new(locale.get("level_up.hint")),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(HINT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn despawn_level_up_screen(mut commands: Commands, query: Query<Entity, With<LevelUpUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn spawn_perk_card(parent: &mut ChildSpawnerCommands, index: usize, perk: Perk, locale: &Locale) {
+    let (border_color, bg_color, _, text_accent) = perk_colors(perk);
+    let (rarity_label, rarity_color, title, desc) = if let Perk::Upgrade(card) = perk {
+        let rarity = get_card_config(card).rarity;
+        let (title, desc, _) = get_shop_button_content(card, 1, 1.0, locale);
+        (locale.get(rarity.label()), rarity.color(), title, desc)
+    } else {
+        let config = get_perk_config(perk);
+        (
+            locale.get("level_up.perk"),
+            config.color,
+            locale.get(config.name).to_string(),
+            locale.get(config.description).to_string(),
+        )
+    };
+
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(160.0),
+                min_width: Val::Px(140.0),
+                height: Val::Px(200.0),
+                min_height: Val::Px(180.0),
+                margin: UiRect::all(Val::Px(8.0)),
+                padding: UiRect::all(Val::Px(12.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BorderColor::all(border_color),
+            BorderRadius::all(Val::Px(16.0)),
+            BackgroundColor(bg_color),
+            PerkCard(index),
+        ))
+        .observe(
+            |trigger: On<Pointer<Click>>, cards: Query<&PerkCard>, mut commands: Commands| {
+                if let Ok(card) = cards.get(trigger.entity) {
+                    commands.trigger(PerkChosenEvent { index: card.0 });
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Over>>,
+             cards: Query<&PerkCard>,
+             mut selection: ResMut<PerkSelection>| {
+                if let Ok(card) = cards.get(trigger.entity) {
+                    selection.0 = card.0;
+                }
+            },
+        )
+        .with_children(|card| {
+            // Key hint (top-left corner)
+            card.spawn((
+                Text::new(format!("{}", index + 1)),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(HINT_COLOR),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(6.0),
+                    left: Val::Px(10.0),
+                    ..default()
+                },
+            ));
+
+            card.spawn((
+                Text::new(rarity_label),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(rarity_color),
+            ));
+
+            if let Perk::Upgrade(btn_type) = perk {
+                crate::visuals::ui_icons::spawn_shop_icon(card, btn_type);
+            }
+
+            card.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(text_accent),
+            ));
+
+            card.spawn((
+                Text::new(desc),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+        });
+}
+
+/// Rebuilds the title and cards whenever a new draft is rolled
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_perk_cards(
+    mut commands: Commands,
+    offers: Res<PerkOffers>,
+    locale: Res<Locale>,
+    experience: Single<&Experience, With<Player>>,
+    grid: Single<(Entity, Ref<PerkGrid>)>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !offers.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    // `level` already counts every queued level-up, so the draft on screen is for an earlier one
+    let drafting = experience.level + 1 - experience.pending;
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|grid| {
+        grid.spawn((
+            Text::new(locale.format("level_up.title", &[("level", &drafting)])),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(TITLE_COLOR),
+        ));
+        grid.spawn((
+            Text::new(if experience.pending > 1 {
+                locale.format(
+                    "level_up.choose_more",
+                    &[("count", &(experience.pending - 1))],
+                )
+            } else {
+                locale.get("level_up.choose").to_string()
+            }),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.85, 0.85, 0.9)),
+            Node {
+                margin: UiRect::bottom(Val::Px(16.0)),
+                ..default()
+            },
+        ));
+        grid.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|row| {
+            for (index, perk) in offers.perks.iter().enumerate() {
+                spawn_perk_card(row, index, *perk, &locale);
+            }
+        });
+    });
+}
+
+/// Highlights the selected card the same way the shop highlights a hovered one
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_perk_highlight(
+    offers: Res<PerkOffers>,
+    selection: Res<PerkSelection>,
+    mut cards: Query<(&PerkCard, &mut BorderColor, &mut BackgroundColor)>,
+) {
+    for (card, mut border, mut background) in &mut cards {
+        let Some(&perk) = offers.perks.get(card.0) else {
+            continue;
+        };
+        let (border_color, bg_color, bg_hover, _) = perk_colors(perk);
+        if card.0 == selection.0 {
+            *border = BorderColor::all(SELECTED_BORDER);
+            *background = BackgroundColor(bg_hover);
+        } else {
+            *border = BorderColor::all(border_color);
+            *background = BackgroundColor(bg_color);
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn navigate_perks(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    offers: Res<PerkOffers>,
+    mut selection: ResMut<PerkSelection>,
+) {
+    let count = offers.perks.len();
+    if input.any_just_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]) {
+        selection.step(-1, count);
+    }
+    if input.any_just_pressed([KeyCode::ArrowRight, KeyCode::KeyD]) {
+        selection.step(1, count);
+    }
+
+    let chosen = NUMBER_KEYS
+        .iter()
+        .position(|key| input.just_pressed(*key))
+        .filter(|index| *index < count)
+        .or_else(|| {
+            input
+                .any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+                .then_some(selection.0)
+        });
+    if let Some(index) = chosen {
+        commands.trigger(PerkChosenEvent { index });
+    }
+}
@@ -0,0 +1,189 @@
+//! Achievements screen: every achievement with its progress towards the goal
+
+use super::components::{
+    AchievementContainer, AchievementGrid, AchievementSummaryText, WeaponMenuTab,
+};
+use crate::configs::achievements::{ACHIEVEMENT_LIST, get_achievement_config};
+use crate::resources::achievements::{Achievement, AchievementProgress};
+use crate::resources::locale::Locale;
+use bevy::prelude::*;
+
+const UNLOCKED_BORDER: Color = Color::srgb(1.0, 0.8, 0.2);
+const LOCKED_BORDER: Color = Color::srgb(0.3, 0.3, 0.35);
+const TITLE_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+
+pub fn spawn_achievement_panel(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                display: Display::None, // Default hidden
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            AchievementContainer,
+            WeaponMenuTab::Achievements,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(locale.get("menu.tab.achievements")),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(TITLE_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            panel.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+                AchievementSummaryText,
+            ));
+
+            // Cards are filled in by `refresh_achievements_ui`
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::FlexStart,
+                    width: Val::Percent(100.0),
+                    row_gap: Val::Px(10.0),
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                AchievementGrid,
+            ));
+        });
+}
+
+fn spawn_achievement_card(
+    parent: &mut ChildSpawnerCommands,
+    achievement: Achievement,
+    progress: &AchievementProgress,
+    locale: &Locale,
+) {
+    let config = get_achievement_config(achievement);
+    let unlocked = progress.is_unlocked(achievement);
+    let status = if unlocked {
+        locale.get("achievements.unlocked").to_string()
+    } else {
+        format!(
+            "{} / {}",
+            progress.progress(achievement),
+            config.requirement.goal()
+        )
+    };
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(200.0),
+                min_height: Val::Px(110.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor::all(if unlocked {
+                UNLOCKED_BORDER
+            } else {
+                LOCKED_BORDER
+            }),
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(Color::srgba(0.1, 0.09, 0.06, 0.95)),
+        ))
+        .with_children(|card| {
+            card.spawn((
+                Text::new(locale.get(config.name)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(if unlocked {
+                    Color::WHITE
+                } else {
+                    Color::srgb(0.6, 0.6, 0.65)
+                }),
+            ));
+            card.spawn((
+                Text::new(locale.get(config.description)),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            card.spawn((
+                Text::new(status),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(if unlocked {
+                    UNLOCKED_BORDER
+                } else {
+                    Color::srgb(0.75, 0.75, 0.8)
+                }),
+            ));
+        });
+}
+
+/// Rebuilds the achievement cards whenever progress changes
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_achievements_ui(
+    mut commands: Commands,
+    progress: Res<AchievementProgress>,
+    locale: Res<Locale>,
+    grid: Single<(Entity, Ref<AchievementGrid>)>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !progress.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|grid| {
+        for achievement in ACHIEVEMENT_LIST {
+            spawn_achievement_card(grid, achievement, &progress, &locale);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_achievement_summary_text(
+    progress: Res<AchievementProgress>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<AchievementSummaryText>>,
+) {
+    for mut text in &mut query {
+        text.0 = locale.format(
+            "achievements.summary",
+            &[
+                ("unlocked", &progress.unlocked.len()),
+                ("total", &ACHIEVEMENT_LIST.len()),
+            ],
+        );
+    }
+}
@@ -1,44 +1,147 @@
 use super::systems::WeaponDescriptionText;
-use crate::components::player::HandType;
-use crate::components::weapon::{MagicLoadout, SpellType, WeaponType};
+use crate::components::player::{Hand, HandType};
+use crate::components::weapon::{MagicLoadout, SpellType, Weapon, WeaponType};
 use crate::configs::visuals::{
     MAGIC_DIVIDER_COLOR, MAGIC_INFO_BG, MAGIC_SLOT_BG, MAGIC_SLOT_BG_HOVER,
     MAGIC_SLOT_BORDER_DEFAULT, MAGIC_SLOT_BORDER_HIGHLIGHT,
 };
+use crate::resources::locale::Locale;
+use crate::systems::combat::magic::SPELL_REGISTRY;
+use crate::systems::combat::stat_calc::AttackStats;
 use bevy::prelude::*;
 
-pub fn get_spell_description(spell_type: SpellType) -> String {
-    match spell_type {
-        SpellType::EnergyBolt => "Energy Bolt: Creates a large explosion on impact.".to_string(),
-        SpellType::Laser => "Laser: Instant-hit high-velocity beam.".to_string(),
-        SpellType::Nova => "Nova: Radial burst of high area damage.".to_string(),
-        SpellType::Blink => "Blink: Teleport to cursor & Invulnerable.".to_string(),
-        SpellType::Global => "Global: Massive strike hitting ALL enemies.".to_string(),
-        SpellType::ForcePush => {
-            "Force Push: Push enemies away. More damage when close.".to_string()
+pub fn get_spell_description(spell_type: SpellType, locale: &Locale) -> String {
+    let spell = spell_type.definition();
+    format!(
+        "{}: {}",
+        locale.get(spell.name()),
+        locale.get(spell.description())
+    )
+}
+
+pub fn get_weapon_description(
+    weapon_type: WeaponType,
+    loadout: Option<&MagicLoadout>,
+    locale: &Locale,
+) -> String {
+    match weapon_type {
+        WeaponType::Sword => locale.get("weapon.sword.description").to_string(),
+        WeaponType::Gun => locale.get("weapon.gun.description").to_string(),
+        WeaponType::Shuriken => locale.get("weapon.shuriken.description").to_string(),
+        WeaponType::Magic => loadout.map_or_else(
+            || locale.get("weapon.magic.description").to_string(),
+            |loadout| {
+                locale.format(
+                    "weapon.magic.loadout",
+                    &[
+                        ("primary", &get_spell_description(loadout.primary, locale)),
+                        (
+                            "secondary",
+                            &get_spell_description(loadout.secondary, locale),
+                        ),
+                    ],
+                )
+            },
+        ),
+    }
+}
+
+/// (label key, formatted value) of each number shown for an attack
+fn attack_values(attack: &AttackStats) -> [(&'static str, String); 6] {
+    [
+        ("stats.hit", format!("{:.0}", attack.hit)),
+        ("stats.crit", format!("{:.0}", attack.crit_hit)),
+        ("stats.average", format!("{:.0}", attack.expected_hit)),
+        ("stats.cooldown", format!("{:.2}s", attack.cooldown)),
+        ("stats.dps", format!("{:.0}", attack.dps)),
+        ("stats.heal", format!("{:.1}", attack.heal_per_hit)),
+    ]
+}
+
+/// One line per attack with its computed hit, crit, average, cooldown, DPS and lifesteal
+pub fn format_attack_stats(attacks: &[(&str, AttackStats)], locale: &Locale) -> String {
+    attacks
+        .iter()
+        .map(|(name, attack)| {
+            let values: Vec<String> = attack_values(attack)
+                .into_iter()
+                .map(|(label, value)| {
+                    locale.format(
+                        "stats.value",
+                        &[("label", &locale.get(label)), ("value", &value)],
+                    )
+                })
+                .collect();
+            locale.format(
+                "stats.line",
+                &[("name", &locale.get(name)), ("values", &values.join(" · "))],
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Before/after lines for the numbers that change, empty when nothing does
+pub fn format_attack_comparison(
+    before: &[(&str, AttackStats)],
+    after: &[(&str, AttackStats)],
+    locale: &Locale,
+) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for ((name, old), (_, new)) in before.iter().zip(after) {
+        let changes: Vec<String> = attack_values(old)
+            .into_iter()
+            .zip(attack_values(new))
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((label, old), (_, new))| {
+                locale.format(
+                    "stats.change",
+                    &[
+                        ("label", &locale.get(label)),
+                        ("before", &old),
+                        ("after", &new),
+                    ],
+                )
+            })
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
+        let line = locale.format(
+            "stats.line",
+            &[
+                ("name", &locale.get(name)),
+                ("values", &changes.join(" · ")),
+            ],
+        );
+        // Both hands can hold the same weapon
+        if !lines.contains(&line) {
+            lines.push(line);
         }
-        SpellType::ForcePull => "Force Pull: Pull enemies in. More damage when far.".to_string(),
     }
+    lines.join("\n")
 }
 
-pub fn get_weapon_description(weapon_type: WeaponType, loadout: Option<&MagicLoadout>) -> String {
-    match weapon_type {
-        WeaponType::Sword => {
-            "Sword (Melee)\n\nNormal Mode: Moderate range, high damage.\nShattered Mode (Skill): Blade fragments cover massive area, lower damage.".to_string()
+/// Puts `kind` in the hand with its default cooldowns, shared by the weapon buttons and presets
+pub const fn equip_weapon(hand: &mut Hand, weapon: &mut Weapon, kind: WeaponType) {
+    hand.equipped_weapon = Some(kind);
+    weapon.kind = kind;
+
+    match kind {
+        WeaponType::Magic => {
+            weapon.cooldown = 0.8;
+            weapon.damage = 0.0;
         }
         WeaponType::Gun => {
-            "Gun (Firearm)\n\nModes: Single, Shotgun, Rapid.\nSkill Cycle: Toggle between modes.\nRapid: Hold to spray.".to_string()
+            weapon.cooldown = 0.5;
+            weapon.damage = 0.0;
         }
-        WeaponType::Shuriken => "Shuriken (Utility)\n\nAttack: Throw fast-moving stars (Max 12).\nSkill: Teleport to nearest shuriken.\nGreat for dodging.".to_string(),
-        WeaponType::Magic => {
-            loadout.map_or_else(
-                || "Magic (Spellcasting)\n\nMost customizable weapon.\nTwo spell slots (Primary/Secondary).\nSelect a spell to see details.".to_string(),
-                |loadout| {
-                    let p_desc = get_spell_description(loadout.primary);
-                    let s_desc = get_spell_description(loadout.secondary);
-                    format!("Magic (Spellcasting)\n\nPrimary - {p_desc}\n\nSecondary - {s_desc}\n\nSkill: Toggle Spell Slot.\nBenefits from CDR upgrades.")
-                },
-            )
+        WeaponType::Shuriken => {
+            weapon.cooldown = crate::configs::weapons::shuriken::COOLDOWN;
+            weapon.skill_cooldown = crate::configs::weapons::shuriken::SKILL_COOLDOWN;
+        }
+        WeaponType::Sword => {
+            weapon.cooldown = crate::configs::weapons::sword::COOLDOWN;
         }
     }
 }
@@ -50,8 +153,6 @@ pub fn spawn_weapon_button(
     label: &str,
 ) {
     use super::components::ArsenalButton;
-    use crate::components::player::Hand;
-    use crate::components::weapon::Weapon;
 
     parent
         .spawn((
@@ -74,32 +175,16 @@ pub fn spawn_weapon_button(
             |trigger: On<Pointer<Click>>,
              button_query: Query<&ArsenalButton>,
              mut hand_query: Query<(&mut Hand, &mut Weapon)>,
-             mut active_side: ResMut<super::resources::ActiveDescriptionSide>| {
+             mut active_side: ResMut<super::resources::ActiveDescriptionSide>,
+             profile: Res<crate::resources::profile::MetaProfile>| {
                 if let Ok(button_data) = button_query.get(trigger.entity) {
+                    if !profile.weapon_unlocked(button_data.kind) {
+                        return;
+                    }
                     active_side.0 = button_data.side;
                     for (mut hand, mut weapon) in &mut hand_query {
                         if hand.side == button_data.side {
-                            hand.equipped_weapon = Some(button_data.kind);
-                            weapon.kind = button_data.kind;
-
-                            match button_data.kind {
-                                WeaponType::Magic => {
-                                    weapon.cooldown = 0.8;
-                                    weapon.damage = 0.0;
-                                }
-                                WeaponType::Gun => {
-                                    weapon.cooldown = 0.5;
-                                    weapon.damage = 0.0;
-                                }
-                                WeaponType::Shuriken => {
-                                    weapon.cooldown = crate::configs::weapons::shuriken::COOLDOWN;
-                                    weapon.skill_cooldown =
-                                        crate::configs::weapons::shuriken::SKILL_COOLDOWN;
-                                }
-                                WeaponType::Sword => {
-                                    weapon.cooldown = 0.5;
-                                }
-                            }
+                            equip_weapon(&mut hand, &mut weapon, button_data.kind);
                         }
                     }
                 }
@@ -121,6 +206,7 @@ pub fn spawn_magic_editor(
     parent: &mut ChildSpawnerCommands,
     side: HandType,
     asset_server: &AssetServer,
+    locale: &Locale,
 ) {
     use super::components::{MagicPanel, MagicUnifiedContainer};
 
@@ -140,10 +226,10 @@ pub fn spawn_magic_editor(
         ))
         .with_children(|container| {
             // 1. Top Section: Active Slots
-            spawn_magic_slots_section(container, side, asset_server);
+            spawn_magic_slots_section(container, side, asset_server, locale);
 
             // 2. Middle Section: Spell Palette
-            spawn_spell_palette_section(container, side, asset_server);
+            spawn_spell_palette_section(container, side, asset_server, locale);
 
             // 3. Bottom Section: Info/Description
             spawn_magic_info_section(container);
@@ -154,6 +240,7 @@ fn spawn_magic_slots_section(
     parent: &mut ChildSpawnerCommands,
     side: HandType,
     asset_server: &AssetServer,
+    locale: &Locale,
 ) {
     parent
         .spawn(Node {
@@ -167,7 +254,13 @@ fn spawn_magic_slots_section(
         })
         .with_children(|section| {
             // Primary Slot
-            spawn_magic_slot_card(section, side, true, asset_server, "Primary (LMB/Q)");
+            spawn_magic_slot_card(
+                section,
+                side,
+                true,
+                asset_server,
+                locale.get("arsenal.primary_slot"),
+            );
 
             // Visual Divider or Icon
             // Visual Divider or Icon
@@ -181,7 +274,13 @@ fn spawn_magic_slots_section(
             ));
 
             // Secondary Slot
-            spawn_magic_slot_card(section, side, false, asset_server, "Secondary (RMB/E)");
+            spawn_magic_slot_card(
+                section,
+                side,
+                false,
+                asset_server,
+                locale.get("arsenal.secondary_slot"),
+            );
         });
 }
 
@@ -195,11 +294,14 @@ fn spawn_magic_slot_card(
     use super::components::{MagicSlotButton, MagicSlotIcon};
     use super::systems::magic_button_observer;
 
+    let default_loadout = MagicLoadout::default();
     let default_icon = if is_primary {
-        "ui/icons/magic_bolt.png"
+        default_loadout.primary
     } else {
-        "ui/icons/magic_blink.png"
-    };
+        default_loadout.secondary
+    }
+    .definition()
+    .icon();
 
     parent
         .spawn((
@@ -271,7 +373,7 @@ fn spawn_magic_slot_card(
 
             // Spell Name (Dynamic)
             card.spawn((
-                Text::new("Spell Name"),
+                Text::default(),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -285,6 +387,7 @@ fn spawn_spell_palette_section(
     parent: &mut ChildSpawnerCommands,
     _side: HandType,
     asset_server: &AssetServer,
+    locale: &Locale,
 ) {
     use super::components::{MagicPaletteContainer, SpellListButton};
     use super::systems::spell_list_observer;
@@ -302,7 +405,7 @@ fn spawn_spell_palette_section(
         ))
         .with_children(|container| {
             container.spawn((
-                Text::new("SPELL PALETTE"),
+                Text::new(locale.get("arsenal.palette")),
                 TextFont {
                     font_size: 16.0,
                     ..default()
@@ -325,17 +428,7 @@ fn spawn_spell_palette_section(
                     ..default()
                 })
                 .with_children(|grid| {
-                    let spells = vec![
-                        (SpellType::EnergyBolt, "ui/icons/magic_bolt.png"),
-                        (SpellType::Laser, "ui/icons/magic_laser.png"),
-                        (SpellType::Nova, "ui/icons/magic_nova.png"),
-                        (SpellType::Blink, "ui/icons/magic_blink.png"),
-                        (SpellType::Global, "ui/icons/magic_global.png"),
-                        (SpellType::ForcePush, "ui/icons/magic_push.png"),
-                        (SpellType::ForcePull, "ui/icons/magic_pull.png"),
-                    ];
-
-                    for (spell, icon_path) in spells {
+                    for spell in &SPELL_REGISTRY {
                         grid.spawn((
                             Button,
                             Node {
@@ -348,7 +441,7 @@ fn spawn_spell_palette_section(
                             },
                             BackgroundColor(MAGIC_SLOT_BG),
                             BorderColor::all(Color::NONE),
-                            SpellListButton(spell),
+                            SpellListButton(spell.kind()),
                         ))
                         .observe(spell_list_observer)
                         .observe(
@@ -367,7 +460,7 @@ fn spawn_spell_palette_section(
                         )
                         .with_children(|btn| {
                             btn.spawn((
-                                ImageNode::new(asset_server.load(icon_path)),
+                                ImageNode::new(asset_server.load(spell.icon())),
                                 Node {
                                     width: Val::Px(48.0),
                                     height: Val::Px(48.0),
@@ -401,7 +494,7 @@ fn spawn_magic_info_section(parent: &mut ChildSpawnerCommands) {
         ))
         .with_children(|info| {
             info.spawn((
-                Text::new("Select a spell to see details..."),
+                Text::default(),
                 TextFont {
                     font_size: 16.0,
                     ..default()
@@ -433,7 +526,7 @@ pub fn spawn_weapon_detail_panel(
         ))
         .with_children(|panel| {
             panel.spawn((
-                Text::new("Description..."),
+                Text::default(),
                 TextFont {
                     font_size: 16.0,
                     ..default()
@@ -579,7 +672,7 @@ fn spawn_shuriken_group(
         });
 }
 
-fn spawn_left_column(parent: &mut ChildSpawnerCommands) {
+fn spawn_left_column(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Column,
@@ -591,7 +684,7 @@ fn spawn_left_column(parent: &mut ChildSpawnerCommands) {
         })
         .with_children(|col| {
             col.spawn((
-                Text::new("LEFT HAND"),
+                Text::new(locale.get("arsenal.left_hand")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -603,14 +696,38 @@ fn spawn_left_column(parent: &mut ChildSpawnerCommands) {
                 },
             ));
 
-            spawn_weapon_button(col, HandType::Left, WeaponType::Shuriken, "Shuriken");
-            spawn_weapon_button(col, HandType::Left, WeaponType::Sword, "Sword");
-            spawn_weapon_button(col, HandType::Left, WeaponType::Gun, "Gun");
-            spawn_weapon_button(col, HandType::Left, WeaponType::Magic, "Magic");
+            spawn_weapon_button(
+                col,
+                HandType::Left,
+                WeaponType::Shuriken,
+                locale.get(WeaponType::Shuriken.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Left,
+                WeaponType::Sword,
+                locale.get(WeaponType::Sword.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Left,
+                WeaponType::Gun,
+                locale.get(WeaponType::Gun.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Left,
+                WeaponType::Magic,
+                locale.get(WeaponType::Magic.name()),
+            );
         });
 }
 
-fn spawn_center_column(parent: &mut ChildSpawnerCommands, asset_server: &AssetServer) {
+fn spawn_center_column(
+    parent: &mut ChildSpawnerCommands,
+    asset_server: &AssetServer,
+    locale: &Locale,
+) {
     use super::components::DescriptionWrapper;
 
     parent
@@ -624,7 +741,7 @@ fn spawn_center_column(parent: &mut ChildSpawnerCommands, asset_server: &AssetSe
         })
         .with_children(|center| {
             center.spawn((
-                Text::new("DESCRIPTION"),
+                Text::new(locale.get("arsenal.description")),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -651,7 +768,7 @@ fn spawn_center_column(parent: &mut ChildSpawnerCommands, asset_server: &AssetSe
                     },
                 ))
                 .with_children(|row| {
-                    spawn_magic_editor(row, HandType::Left, asset_server);
+                    spawn_magic_editor(row, HandType::Left, asset_server, locale);
                     spawn_weapon_detail_panel(row, HandType::Left, asset_server);
                 });
 
@@ -670,13 +787,13 @@ fn spawn_center_column(parent: &mut ChildSpawnerCommands, asset_server: &AssetSe
                     },
                 ))
                 .with_children(|row| {
-                    spawn_magic_editor(row, HandType::Right, asset_server);
+                    spawn_magic_editor(row, HandType::Right, asset_server, locale);
                     spawn_weapon_detail_panel(row, HandType::Right, asset_server);
                 });
         });
 }
 
-fn spawn_right_column(parent: &mut ChildSpawnerCommands) {
+fn spawn_right_column(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Column,
@@ -688,7 +805,7 @@ fn spawn_right_column(parent: &mut ChildSpawnerCommands) {
         })
         .with_children(|col| {
             col.spawn((
-                Text::new("RIGHT HAND"),
+                Text::new(locale.get("arsenal.right_hand")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -700,33 +817,69 @@ fn spawn_right_column(parent: &mut ChildSpawnerCommands) {
                 },
             ));
 
-            spawn_weapon_button(col, HandType::Right, WeaponType::Shuriken, "Shuriken");
-            spawn_weapon_button(col, HandType::Right, WeaponType::Sword, "Sword");
-            spawn_weapon_button(col, HandType::Right, WeaponType::Gun, "Gun");
-            spawn_weapon_button(col, HandType::Right, WeaponType::Magic, "Magic");
+            spawn_weapon_button(
+                col,
+                HandType::Right,
+                WeaponType::Shuriken,
+                locale.get(WeaponType::Shuriken.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Right,
+                WeaponType::Sword,
+                locale.get(WeaponType::Sword.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Right,
+                WeaponType::Gun,
+                locale.get(WeaponType::Gun.name()),
+            );
+            spawn_weapon_button(
+                col,
+                HandType::Right,
+                WeaponType::Magic,
+                locale.get(WeaponType::Magic.name()),
+            );
         });
 }
 
-pub fn spawn_equipment_panel(parent: &mut ChildSpawnerCommands, asset_server: &AssetServer) {
+pub fn spawn_equipment_panel(
+    parent: &mut ChildSpawnerCommands,
+    asset_server: &AssetServer,
+    locale: &Locale,
+) {
     use super::components::EquipmentContainer;
 
     // === EQUIPMENT CONTAINER (Equip Tab) ===
     parent
         .spawn((
             Node {
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceEvenly,
-                align_items: AlignItems::FlexStart,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
                 display: Display::None, // Default hidden
                 ..default()
             },
             EquipmentContainer,
+            super::components::WeaponMenuTab::Equip,
         ))
         .with_children(|equip| {
-            spawn_left_column(equip);
-            spawn_center_column(equip, asset_server);
-            spawn_right_column(equip);
+            super::presets::spawn_preset_bar(equip, locale);
+            equip
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceEvenly,
+                    align_items: AlignItems::FlexStart,
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    ..default()
+                })
+                .with_children(|columns| {
+                    spawn_left_column(columns, locale);
+                    spawn_center_column(columns, asset_server, locale);
+                    spawn_right_column(columns, locale);
+                });
         });
 }
@@ -1,4 +1,5 @@
 use crate::components::player::HandType;
+use crate::components::relic::Relic;
 use crate::components::weapon::WeaponType;
 use bevy::prelude::*;
 
@@ -6,6 +7,10 @@ use bevy::prelude::*;
 pub enum WeaponMenuTab {
     Card,
     Equip,
+    Unlocks,
+    Run,
+    Achievements,
+    Records,
 }
 
 #[derive(Component)]
@@ -22,6 +27,59 @@ pub struct ShopContainer;
 #[derive(Component)]
 pub struct EquipmentContainer;
 
+#[derive(Component)]
+pub struct UnlockContainer;
+
+/// Container the unlock cards are spawned into
+#[derive(Component)]
+pub struct UnlockGrid;
+
+#[derive(Component)]
+pub struct UnlockShardsText;
+
+#[derive(Component)]
+pub struct UnlockCardButton(pub crate::resources::profile::Unlock);
+
+#[derive(Component)]
+pub struct AchievementContainer;
+
+/// Container the achievement cards are spawned into
+#[derive(Component)]
+pub struct AchievementGrid;
+
+#[derive(Component)]
+pub struct AchievementSummaryText;
+
+#[derive(Component)]
+pub struct LeaderboardContainer;
+
+/// Container the high score rows are spawned into
+#[derive(Component)]
+pub struct LeaderboardGrid;
+
+/// Replays the leaderboard entry at this index
+#[derive(Component)]
+pub struct ReplayButton(pub usize);
+
+#[derive(Component)]
+pub struct RunSetupContainer;
+
+/// Container the difficulty and ascension cards are spawned into
+#[derive(Component)]
+pub struct RunSetupGrid;
+
+#[derive(Component)]
+pub struct RunSetupSummaryText;
+
+#[derive(Component)]
+pub struct GameModeButton(pub crate::resources::run_settings::GameMode);
+
+#[derive(Component)]
+pub struct DifficultyButton(pub crate::resources::run_settings::Difficulty);
+
+#[derive(Component)]
+pub struct AscensionButton(pub crate::resources::run_settings::Ascension);
+
 #[derive(Component)]
 pub struct ArsenalButton {
     pub side: HandType,
@@ -38,6 +96,13 @@ pub enum ShopButton {
     LifestealUp,
     CooldownReductionUp,
     NovaCore,
+    MaxManaUp,
+    ManaRegenUp,
+    ArmorUp,
+    MoveSpeedUp,
+    DashCooldownDown,
+    MagnetUp,
+    Relic(Relic),
 }
 
 #[derive(Event, Debug)]
@@ -46,6 +111,14 @@ pub struct PurchaseEvent {
     pub entity: Entity,
 }
 
+/// Fired by `handle_purchases` (or a shop drone) once a card has actually been paid for
+#[derive(Event, Debug)]
+pub struct CardPurchasedEvent {
+    #[allow(dead_code)]
+    pub card: ShopButton,
+    pub price: u32,
+}
+
 #[derive(Component)]
 pub struct WeaponMenuUI;
 
@@ -140,6 +213,51 @@ pub struct ShopBuyButtonText;
 /// Marker for buy button price text
 #[derive(Component)]
 pub struct ShopBuyButtonPrice;
+
+/// Container the saved loadout preset chips are spawned into
+#[derive(Component)]
+pub struct PresetList;
+
+/// Field showing the name the next preset is saved under
+#[derive(Component)]
+pub struct PresetNameField;
+
+#[derive(Component)]
+pub struct PresetNameText;
+
+#[derive(Component)]
+pub struct PresetSaveButton;
+
+/// Outcome of the last preset action
+#[derive(Component)]
+pub struct PresetStatusText;
+
+/// Equips the preset at this index of `MetaProfile::presets`
+#[derive(Component)]
+pub struct PresetApplyButton(pub usize);
+
+/// Deletes the preset at this index of `MetaProfile::presets`
+#[derive(Component)]
+pub struct PresetDeleteButton(pub usize);
+
+/// Marker for the attack stat changes the selected card would make
+#[derive(Component)]
+pub struct ShopStatPreviewText;
+
+/// Container the rolled offer cards are spawned into
+#[derive(Component)]
+pub struct ShopOfferGrid;
+
+#[derive(Component)]
+pub struct ShopRerollButton;
+
+#[derive(Component)]
+pub struct ShopRerollText;
+
+/// Lock toggle on an offer card
+#[derive(Component)]
+pub struct ShopLockButton(pub ShopButton);
+
 #[derive(Component)]
 pub struct SpellListDescriptionText;
 
@@ -1,10 +1,50 @@
-use crate::components::player::{CombatStats, Currency, Health, Player, Progression};
+use crate::components::enemy::Enemy;
+use crate::components::pickup::{Pickup, PowerUps, ShopDrone};
+use crate::components::player::{
+    CombatStats, Currency, Dash, Health, Mana, Player, PlayerStats, Progression,
+};
+use crate::components::relic::{Decoy, RelicTriggers};
+use crate::components::weapon::Projectile;
+use crate::resources::achievements::AchievementTracker;
 use crate::resources::game_state::GameState;
+use crate::resources::locale::Locale;
+use crate::resources::profile::MetaProfile;
 use crate::resources::round::RoundManager;
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_stats::RunStats;
+use crate::systems::meta::apply_meta_bonuses;
 use crate::systems::ui::components::ConfirmationDialogUI;
+use crate::systems::ui::menu::offers::ShopOffers;
 use bevy::prelude::*;
 
-pub fn spawn_confirmation_dialog(commands: &mut Commands) {
+type NewGamePlayer<'w, 's> = Single<
+    'w,
+    's,
+    (
+        &'static mut Health,
+        &'static mut Mana,
+        &'static mut Currency,
+        &'static mut PlayerStats,
+        &'static mut CombatStats,
+        &'static mut Dash,
+        &'static mut PowerUps,
+        &'static mut Progression,
+        &'static mut RelicTriggers,
+        &'static mut Transform,
+    ),
+    With<Player>,
+>;
+
+/// Entities that only live for one run
+type RunEntity = Or<(
+    With<Enemy>,
+    With<Projectile>,
+    With<Pickup>,
+    With<ShopDrone>,
+    With<Decoy>,
+)>;
+
+pub fn spawn_confirmation_dialog(commands: &mut Commands, locale: &Locale) {
     commands
         .spawn((
             Node {
@@ -38,7 +78,7 @@ pub fn spawn_confirmation_dialog(commands: &mut Commands) {
                 .insert(BorderRadius::all(Val::Px(12.0)))
                 .with_children(|modal| {
                     modal.spawn((
-                        Text::new("Start a New Game?"),
+                        Text::new(locale.get("confirm.new_game")),
                         TextFont {
                             font_size: 28.0,
                             ..default()
@@ -57,14 +97,14 @@ pub fn spawn_confirmation_dialog(commands: &mut Commands) {
                             ..default()
                         })
                         .with_children(|buttons| {
-                            spawn_ok_button(buttons);
-                            spawn_cancel_button(buttons);
+                            spawn_ok_button(buttons, locale);
+                            spawn_cancel_button(buttons, locale);
                         });
                 });
         });
 }
 
-fn spawn_ok_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_ok_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Button,
@@ -80,54 +120,9 @@ fn spawn_ok_button(parent: &mut ChildSpawnerCommands) {
             BorderRadius::all(Val::Px(8.0)),
             BackgroundColor(Color::srgba(0.15, 0.25, 0.15, 1.0)),
         ))
-        .observe(
-            |_: On<Pointer<Click>>,
-             mut next_state: ResMut<NextState<GameState>>,
-             player: Single<
-                (
-                    &mut Health,
-                    &mut Currency,
-                    &mut CombatStats,
-                    &mut Progression,
-                    &mut Transform,
-                ),
-                With<Player>,
-            >,
-             mut round_manager: ResMut<RoundManager>,
-             enemy_query: Query<Entity, With<crate::components::enemy::Enemy>>,
-             projectile_query: Query<Entity, With<crate::components::weapon::Projectile>>,
-             dialog_query: Query<Entity, With<ConfirmationDialogUI>>,
-             mut commands: Commands| {
-                // Reset Player
-                let (mut health, mut currency, mut combat, mut progression, mut transform) =
-                    player.into_inner();
-                *health = Health::default();
-                *currency = Currency::default();
-                *combat = CombatStats::default();
-                *progression = Progression::default();
-                transform.translation = Vec3::ZERO;
-
-                // Reset Round
-                *round_manager = RoundManager::default();
-                round_manager.has_started = false;
-
-                // Despawn Enemies and Projectiles
-                for entity in &enemy_query {
-                    commands.entity(entity).despawn();
-                }
-                for entity in &projectile_query {
-                    commands.entity(entity).despawn();
-                }
-
-                // Despawn Dialog
-                for entity in &dialog_query {
-                    commands.entity(entity).despawn();
-                }
-
-                // Return to Weapon Menu
-                next_state.set(GameState::WeaponMenu);
-            },
-        )
+        .observe(|_: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached(reset_run);
+        })
         .observe(
             |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
                 if let Ok(mut color) = color.get_mut(trigger.entity) {
@@ -144,7 +139,7 @@ fn spawn_ok_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("OK"),
+                Text::new(locale.get("common.ok")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -154,7 +149,70 @@ fn spawn_ok_button(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_cancel_button(parent: &mut ChildSpawnerCommands) {
+/// Puts the player, round and run-scoped resources back to a fresh run, clears the
+/// run's entities and returns to the weapon menu
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn reset_run(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    player: NewGamePlayer,
+    mut round_manager: ResMut<RoundManager>,
+    mut offers: ResMut<ShopOffers>,
+    mut run_stats: ResMut<RunStats>,
+    mut run_seed: ResMut<RunSeed>,
+    mut achievement_tracker: ResMut<AchievementTracker>,
+    profile: Res<MetaProfile>,
+    run_entities: Query<Entity, RunEntity>,
+    dialog_query: Query<Entity, With<ConfirmationDialogUI>>,
+) {
+    // Reset Player
+    let (
+        mut health,
+        mut mana,
+        mut currency,
+        mut stats,
+        mut combat,
+        mut dash,
+        mut power_ups,
+        mut progression,
+        mut relic_triggers,
+        mut transform,
+    ) = player.into_inner();
+    *health = Health::default();
+    *mana = Mana::default();
+    *currency = Currency::default();
+    *stats = PlayerStats::default();
+    *combat = CombatStats::default();
+    *dash = Dash::default();
+    *power_ups = PowerUps::default();
+    *progression = Progression::default();
+    *relic_triggers = RelicTriggers::default();
+    apply_meta_bonuses(&profile, &mut health, &mut mana, &mut currency, &mut stats);
+    transform.translation = Vec3::ZERO;
+
+    // Reset Round
+    *round_manager = RoundManager::default();
+    round_manager.has_started = false;
+    *offers = ShopOffers::default();
+    *run_stats = RunStats::default();
+    *run_seed = RunSeed::random();
+    *achievement_tracker = AchievementTracker::default();
+
+    // Despawn Enemies, Projectiles, Pickups, Shop Drones and Decoys
+    for entity in &run_entities {
+        commands.entity(entity).despawn();
+    }
+
+    // Despawn Dialog
+    for entity in &dialog_query {
+        commands.entity(entity).despawn();
+    }
+
+    // Return to Weapon Menu
+    next_state.set(GameState::WeaponMenu);
+}
+
+fn spawn_cancel_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Button,
@@ -195,7 +253,7 @@ fn spawn_cancel_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("CANCEL"),
+                Text::new(locale.get("common.cancel")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -204,3 +262,37 @@ fn spawn_cancel_button(parent: &mut ChildSpawnerCommands) {
             ));
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn test_new_game_resets_bought_speed_and_dash() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RoundManager>()
+            .init_resource::<ShopOffers>()
+            .init_resource::<RunStats>()
+            .init_resource::<RunSeed>()
+            .init_resource::<AchievementTracker>()
+            .init_resource::<MetaProfile>();
+        let mut stats = PlayerStats::default();
+        stats.speed *= 2.0;
+        let mut dash = Dash::default();
+        dash.cooldown.set_duration(dash.cooldown.duration() / 2);
+        let player = app.world_mut().spawn((Player, stats, dash)).id();
+
+        app.world_mut().run_system_cached(reset_run).unwrap();
+
+        let world = app.world();
+        let speed = world.get::<PlayerStats>(player).unwrap().speed;
+        assert!((speed - PlayerStats::default().speed).abs() < f32::EPSILON);
+        assert_eq!(
+            world.get::<Dash>(player).unwrap().cooldown.duration(),
+            Dash::default().cooldown.duration()
+        );
+    }
+}
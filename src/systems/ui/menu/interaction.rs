@@ -1,19 +1,32 @@
 use super::components::{
-    ActiveTab, EquipmentContainer, PurchaseEvent, SelectCardEvent, SelectedShopCard, ShopButton,
-    ShopBuyButton, ShopBuyButtonPrice, ShopBuyButtonText, ShopContainer, TabButton, WeaponMenuTab,
+    ActiveTab, CardPurchasedEvent, PurchaseEvent, SelectCardEvent, SelectedShopCard, ShopButton,
+    ShopBuyButton, ShopBuyButtonPrice, ShopBuyButtonText, TabButton, WeaponMenuTab,
 };
-use crate::components::player::{CombatStats, Currency, Health, Player, PlayerStats, Progression};
-use crate::configs::shop::get_card_config;
+use super::offers::ShopOffers;
+use crate::components::player::{
+    CombatStats, Currency, Dash, Health, Mana, Player, PlayerStats, Progression,
+};
+use crate::configs::shop::{card_price, get_card_config};
 use crate::resources::game_state::GameState;
+use crate::resources::locale::Locale;
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
 use crate::systems::ui::menu::shop::get_shop_button_content;
 use bevy::prelude::*;
 
 /// Handle card selection when clicking on shop cards
-#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::type_complexity,
+    clippy::too_many_arguments
+)]
 pub fn handle_card_selection(
     trigger: On<SelectCardEvent>,
     mut selected: ResMut<SelectedShopCard>,
     progression: Single<&Progression, With<Player>>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    locale: Res<Locale>,
     mut buy_btn_query: Query<&mut Node, With<ShopBuyButton>>,
     mut buy_text_query: Query<&mut Text, With<ShopBuyButtonText>>,
     mut buy_price_query: Query<&mut Text, (With<ShopBuyButtonPrice>, Without<ShopBuyButtonText>)>,
@@ -22,16 +35,7 @@ pub fn handle_card_selection(
     let event = trigger.event();
     // Check if card is already maxed
     let config = get_card_config(event.btn_type);
-    let count = match event.btn_type {
-        ShopButton::Heal => progression.heal_count,
-        ShopButton::DamageUp => progression.damage_upgrades,
-        ShopButton::MaxHealthUp => progression.max_health_upgrades,
-        ShopButton::CritDamageUp => progression.crit_damage_upgrades,
-        ShopButton::CritChanceUp => progression.crit_chance_upgrades,
-        ShopButton::LifestealUp => progression.lifesteal_upgrades,
-        ShopButton::CooldownReductionUp => progression.cdr_upgrades,
-        ShopButton::NovaCore => progression.nova_core,
-    };
+    let count = get_progression_count(event.btn_type, &progression);
     let is_maxed = config.limit.is_some_and(|limit| count >= limit);
 
     // If maxed, don't show buy button
@@ -48,9 +52,14 @@ pub fn handle_card_selection(
     }
 
     // Update title text
-    let (title, _desc, price) = get_shop_button_content(event.btn_type);
+    let (title, _desc, price) = get_shop_button_content(
+        event.btn_type,
+        round_manager.current_round,
+        run_settings.price_multiplier(),
+        &locale,
+    );
     for mut text in &mut buy_text_query {
-        text.0 = format!("BUY {title}");
+        text.0 = locale.format("shop.buy", &[("title", &title)]);
     }
 
     // Update price text
@@ -93,7 +102,7 @@ pub fn setup_buy_button_observer(
     }
 }
 
-const fn get_progression_count(btn_type: ShopButton, progression: &Progression) -> u32 {
+pub fn get_progression_count(btn_type: ShopButton, progression: &Progression) -> u32 {
     match btn_type {
         ShopButton::Heal => progression.heal_count,
         ShopButton::DamageUp => progression.damage_upgrades,
@@ -103,13 +112,45 @@ const fn get_progression_count(btn_type: ShopButton, progression: &Progression)
         ShopButton::LifestealUp => progression.lifesteal_upgrades,
         ShopButton::CooldownReductionUp => progression.cdr_upgrades,
         ShopButton::NovaCore => progression.nova_core,
+        ShopButton::MaxManaUp => progression.max_mana_upgrades,
+        ShopButton::ManaRegenUp => progression.mana_regen_upgrades,
+        ShopButton::ArmorUp => progression.armor_upgrades,
+        ShopButton::MoveSpeedUp => progression.move_speed_upgrades,
+        ShopButton::MagnetUp => progression.magnet_upgrades,
+        ShopButton::DashCooldownDown => progression.dash_cooldown_upgrades,
+        ShopButton::Relic(relic) => u32::from(progression.has_relic(relic)),
+    }
+}
+
+/// The attack stat part of a card, shared by purchases and the shop's stat preview;
+/// false for cards that leave attacks unchanged
+pub fn apply_attack_upgrade(
+    btn_type: ShopButton,
+    value: f32,
+    stats: &mut PlayerStats,
+    combat: &mut CombatStats,
+) -> bool {
+    match btn_type {
+        ShopButton::DamageUp => stats.damage_multiplier += value,
+        ShopButton::CritDamageUp => combat.crit_damage += value,
+        ShopButton::CritChanceUp => combat.crit_chance = (combat.crit_chance + value).min(1.0),
+        ShopButton::LifestealUp => combat.lifesteal = (combat.lifesteal + value).min(0.5),
+        ShopButton::CooldownReductionUp => {
+            combat.cooldown_reduction = (combat.cooldown_reduction + value).min(0.8);
+        }
+        _ => return false,
     }
+    true
 }
 
-fn apply_upgrade_effect(
+/// Applies a bought card to the player; false if it had no effect (e.g. healing at full health)
+#[allow(clippy::too_many_arguments)]
+pub fn apply_upgrade_effect(
     btn_type: ShopButton,
     value: f32,
     health: &mut Health,
+    mana: &mut Mana,
+    dash: &mut Dash,
     stats: &mut PlayerStats,
     combat: &mut CombatStats,
     progression: &mut Progression,
@@ -125,7 +166,7 @@ fn apply_upgrade_effect(
             }
         }
         ShopButton::DamageUp => {
-            stats.damage_multiplier += value;
+            apply_attack_upgrade(btn_type, value, stats, combat);
             progression.damage_upgrades += 1;
             true
         }
@@ -136,22 +177,22 @@ fn apply_upgrade_effect(
             true
         }
         ShopButton::CritDamageUp => {
-            combat.crit_damage += value;
+            apply_attack_upgrade(btn_type, value, stats, combat);
             progression.crit_damage_upgrades += 1;
             true
         }
         ShopButton::CritChanceUp => {
-            combat.crit_chance = (combat.crit_chance + value).min(1.0);
+            apply_attack_upgrade(btn_type, value, stats, combat);
             progression.crit_chance_upgrades += 1;
             true
         }
         ShopButton::LifestealUp => {
-            combat.lifesteal = (combat.lifesteal + value).min(0.5);
+            apply_attack_upgrade(btn_type, value, stats, combat);
             progression.lifesteal_upgrades += 1;
             true
         }
         ShopButton::CooldownReductionUp => {
-            combat.cooldown_reduction = (combat.cooldown_reduction + value).min(0.8);
+            apply_attack_upgrade(btn_type, value, stats, combat);
             progression.cdr_upgrades += 1;
             true
         }
@@ -159,6 +200,42 @@ fn apply_upgrade_effect(
             progression.nova_core += 1;
             true
         }
+        ShopButton::MaxManaUp => {
+            mana.max += value;
+            mana.current += value;
+            progression.max_mana_upgrades += 1;
+            true
+        }
+        ShopButton::ManaRegenUp => {
+            mana.regen += value;
+            progression.mana_regen_upgrades += 1;
+            true
+        }
+        ShopButton::ArmorUp => {
+            combat.armor += value;
+            progression.armor_upgrades += 1;
+            true
+        }
+        ShopButton::MoveSpeedUp => {
+            stats.speed += value;
+            progression.move_speed_upgrades += 1;
+            true
+        }
+        ShopButton::DashCooldownDown => {
+            let duration = dash.cooldown.duration().mul_f32(1.0 - value);
+            dash.cooldown.set_duration(duration);
+            progression.dash_cooldown_upgrades += 1;
+            true
+        }
+        ShopButton::MagnetUp => {
+            stats.magnet_radius += value;
+            progression.magnet_upgrades += 1;
+            true
+        }
+        ShopButton::Relic(relic) => {
+            progression.relics.push(relic);
+            true
+        }
     }
 }
 
@@ -172,6 +249,8 @@ pub fn handle_purchases(
     player_query: Single<
         (
             &mut Health,
+            &mut Mana,
+            &mut Dash,
             &mut Currency,
             &mut PlayerStats,
             &mut CombatStats,
@@ -181,31 +260,48 @@ pub fn handle_purchases(
     >,
     mut color_query: Query<&mut BackgroundColor>,
     mut selected: ResMut<SelectedShopCard>,
+    mut offers: ResMut<ShopOffers>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    mut commands: Commands,
     mut buy_btn_query: Query<&mut Node, With<ShopBuyButton>>,
-    mut card_border_query: Query<(&ShopButton, &mut BorderColor)>,
 ) {
     let event = trigger.event();
-    let (mut health, mut currency, mut stats, mut combat, mut progression) =
+    let (mut health, mut mana, mut dash, mut currency, mut stats, mut combat, mut progression) =
         player_query.into_inner();
     let config = get_card_config(event.btn_type);
+    let price = card_price(
+        event.btn_type,
+        round_manager.current_round,
+        run_settings.price_multiplier(),
+    );
     let mut success = false;
 
     // Check limit
     let count = get_progression_count(event.btn_type, &progression);
     let is_maxed = config.limit.is_some_and(|limit| count >= limit);
+    // Endless runs buy from shop drones once the fight is on
+    let shop_closed = run_settings.is_endless() && round_manager.has_started;
 
     if !is_maxed
-        && currency.gold >= config.price
+        && !shop_closed
+        && currency.gold >= price
         && apply_upgrade_effect(
             event.btn_type,
             config.value,
             &mut health,
+            &mut mana,
+            &mut dash,
             &mut stats,
             &mut combat,
             &mut progression,
         )
     {
-        currency.gold -= config.price;
+        currency.gold -= price;
+        commands.trigger(CardPurchasedEvent {
+            card: event.btn_type,
+            price,
+        });
         success = true;
     }
 
@@ -213,29 +309,17 @@ pub fn handle_purchases(
     if let Ok(mut color) = color_query.get_mut(event.entity) {
         if success {
             *color = BackgroundColor(Color::srgba(0.2, 0.8, 0.2, 1.0));
-        } else if is_maxed || currency.gold < config.price {
+        } else if is_maxed || shop_closed || currency.gold < price {
             *color = BackgroundColor(Color::srgba(0.8, 0.2, 0.2, 1.0));
         }
     }
 
-    // Check if card is now maxed after purchase - only then hide buy button
+    // Bought offers leave the shop; the offer grid rebuilds from `ShopOffers`
     if success {
-        let now_maxed = config
-            .limit
-            .is_some_and(|limit| get_progression_count(event.btn_type, &progression) >= limit);
-
-        // Only hide buy button if card is now maxed
-        if now_maxed {
-            selected.0 = None;
-            for mut node in &mut buy_btn_query {
-                node.display = Display::None;
-            }
-            // Reset all card borders
-            for (btn_type, mut border) in &mut card_border_query {
-                let (original, _, _, _) =
-                    crate::systems::ui::menu::shop::get_shop_button_colors(*btn_type);
-                *border = BorderColor::all(original);
-            }
+        offers.take(event.btn_type);
+        selected.0 = None;
+        for mut node in &mut buy_btn_query {
+            node.display = Display::None;
         }
     }
 }
@@ -278,15 +362,15 @@ pub fn handle_tab_out(
     }
 }
 
-/// Handle tab switching (Card <-> Equip)
+/// Handle tab switching between the menu panels
 #[allow(clippy::needless_pass_by_value)]
 pub fn handle_tab_interaction(
     trigger: On<Pointer<Click>>,
     mut commands: Commands,
     tab_query: Query<&TabButton>,
     mut all_tabs: Query<(Entity, &TabButton, &mut BackgroundColor, &mut BorderColor)>,
-    mut shop_container: Single<&mut Node, (With<ShopContainer>, Without<EquipmentContainer>)>,
-    mut equip_container: Single<&mut Node, (With<EquipmentContainer>, Without<ShopContainer>)>,
+    // Each tab's content container carries the `WeaponMenuTab` it belongs to
+    mut containers: Query<(&mut Node, &WeaponMenuTab)>,
 ) {
     if let Ok(clicked_tab) = tab_query.get(trigger.entity) {
         // Update Tab Styles
@@ -306,15 +390,12 @@ pub fn handle_tab_interaction(
         }
 
         // Update Container Visibility
-        match clicked_tab.tab {
-            WeaponMenuTab::Card => {
-                shop_container.display = Display::Flex;
-                equip_container.display = Display::None;
-            }
-            WeaponMenuTab::Equip => {
-                shop_container.display = Display::None;
-                equip_container.display = Display::Flex;
-            }
+        for (mut node, tab) in &mut containers {
+            node.display = if *tab == clicked_tab.tab {
+                Display::Flex
+            } else {
+                Display::None
+            };
         }
     }
 }
@@ -325,18 +406,24 @@ pub fn handle_menu_toggle(
     state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
     round_manager: Res<crate::resources::round::RoundManager>,
+    mut preset_draft: ResMut<super::resources::PresetDraft>,
 ) {
     if input.just_pressed(KeyCode::Escape) {
+        // Escape first leaves the preset name field
+        if preset_draft.editing {
+            preset_draft.editing = false;
+            return;
+        }
         match state.get() {
             GameState::WeaponMenu | GameState::Settings => {
                 if round_manager.has_started {
                     next_state.set(GameState::Playing);
                 }
             }
-            GameState::Playing | GameState::Tutorial => {
-                next_state.set(GameState::WeaponMenu);
-            }
-            GameState::GameOver => {}
+            GameState::Playing => next_state.set(GameState::Paused),
+            GameState::Paused => next_state.set(GameState::Playing),
+            GameState::Tutorial => next_state.set(GameState::WeaponMenu),
+            GameState::GameOver | GameState::LevelUp => {}
         }
     }
 }
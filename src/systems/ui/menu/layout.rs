@@ -3,10 +3,11 @@ use super::components::{
     MenuLifestealText, TabButton, WeaponMenuRestartButton, WeaponMenuSettingsButton, WeaponMenuTab,
 };
 use super::interaction::{handle_tab_hover, handle_tab_interaction, handle_tab_out};
+use crate::resources::locale::Locale;
 use crate::systems::ui::TutorialButton;
 use bevy::prelude::*;
 
-pub fn spawn_sidebar(parent: &mut ChildSpawnerCommands) {
+pub fn spawn_sidebar(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Node {
@@ -41,9 +42,13 @@ pub fn spawn_sidebar(parent: &mut ChildSpawnerCommands) {
                     ..default()
                 })
                 .with_children(|content| {
-                    spawn_sidebar_title(content);
-                    spawn_upgrade_tab_button(content);
-                    spawn_equip_tab_button(content);
+                    spawn_sidebar_title(content, locale);
+                    spawn_upgrade_tab_button(content, locale);
+                    spawn_equip_tab_button(content, locale);
+                    spawn_unlocks_tab_button(content, locale);
+                    spawn_run_tab_button(content, locale);
+                    spawn_achievements_tab_button(content, locale);
+                    spawn_records_tab_button(content, locale);
 
                     // Flexible Spacer - Pushes content below to the bottom
                     content.spawn(Node {
@@ -54,17 +59,17 @@ pub fn spawn_sidebar(parent: &mut ChildSpawnerCommands) {
                     });
 
                     // Footer Actions moved to Sidebar (Bottom)
-                    spawn_battle_button(content);
-                    spawn_tutorial_button(content);
-                    spawn_settings_button(content);
-                    spawn_new_game_button(content);
+                    spawn_battle_button(content, locale);
+                    spawn_tutorial_button(content, locale);
+                    spawn_settings_button(content, locale);
+                    spawn_new_game_button(content, locale);
                 });
         });
 }
 
-fn spawn_sidebar_title(parent: &mut ChildSpawnerCommands) {
+fn spawn_sidebar_title(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent.spawn((
-        Text::new("MENU"),
+        Text::new(locale.get("menu.title")),
         TextFont {
             font_size: 28.0,
             ..default()
@@ -77,7 +82,7 @@ fn spawn_sidebar_title(parent: &mut ChildSpawnerCommands) {
     ));
 }
 
-fn spawn_upgrade_tab_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_upgrade_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Button,
@@ -104,7 +109,7 @@ fn spawn_upgrade_tab_button(parent: &mut ChildSpawnerCommands) {
         .observe(handle_tab_out)
         .with_children(|btn| {
             btn.spawn((
-                Text::new("UPGRADES"),
+                Text::new(locale.get("menu.tab.upgrades")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -114,7 +119,7 @@ fn spawn_upgrade_tab_button(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_equip_tab_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_equip_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Button,
@@ -140,7 +145,147 @@ fn spawn_equip_tab_button(parent: &mut ChildSpawnerCommands) {
         .observe(handle_tab_out)
         .with_children(|btn| {
             btn.spawn((
-                Text::new("EQUIPMENT"),
+                Text::new(locale.get("menu.tab.equipment")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_unlocks_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect::left(Val::Px(20.0)),
+                border: UiRect::left(Val::Px(5.0)),
+                margin: UiRect::vertical(Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 1.0)),
+            TabButton {
+                tab: WeaponMenuTab::Unlocks,
+            },
+            BorderColor::all(Color::NONE),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .observe(handle_tab_interaction)
+        .observe(handle_tab_hover)
+        .observe(handle_tab_out)
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("menu.tab.unlocks")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_run_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect::left(Val::Px(20.0)),
+                border: UiRect::left(Val::Px(5.0)),
+                margin: UiRect::vertical(Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 1.0)),
+            TabButton {
+                tab: WeaponMenuTab::Run,
+            },
+            BorderColor::all(Color::NONE),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .observe(handle_tab_interaction)
+        .observe(handle_tab_hover)
+        .observe(handle_tab_out)
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("menu.tab.run")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_achievements_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect::left(Val::Px(20.0)),
+                border: UiRect::left(Val::Px(5.0)),
+                margin: UiRect::vertical(Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 1.0)),
+            TabButton {
+                tab: WeaponMenuTab::Achievements,
+            },
+            BorderColor::all(Color::NONE),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .observe(handle_tab_interaction)
+        .observe(handle_tab_hover)
+        .observe(handle_tab_out)
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("menu.tab.achievements")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_records_tab_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect::left(Val::Px(20.0)),
+                border: UiRect::left(Val::Px(5.0)),
+                margin: UiRect::vertical(Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 1.0)),
+            TabButton {
+                tab: WeaponMenuTab::Records,
+            },
+            BorderColor::all(Color::NONE),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .observe(handle_tab_interaction)
+        .observe(handle_tab_hover)
+        .observe(handle_tab_out)
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("menu.tab.records")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -175,7 +320,7 @@ pub fn spawn_header(parent: &mut ChildSpawnerCommands) {
                 })
                 .with_children(|status_bar| {
                     status_bar.spawn((
-                        Text::new("HP: 100/100"),
+                        Text::default(),
                         TextFont {
                             font_size: 20.0,
                             ..default()
@@ -184,7 +329,7 @@ pub fn spawn_header(parent: &mut ChildSpawnerCommands) {
                         MenuHealthText,
                     ));
                     status_bar.spawn((
-                        Text::new("Gold: 0"),
+                        Text::default(),
                         TextFont {
                             font_size: 20.0,
                             ..default()
@@ -197,25 +342,25 @@ pub fn spawn_header(parent: &mut ChildSpawnerCommands) {
                         ..default()
                     };
                     status_bar.spawn((
-                        Text::new("Dmg: +0%"),
+                        Text::default(),
                         stat_font.clone(),
                         TextColor(Color::srgb(1.0, 0.4, 0.1)),
                         MenuDamageText,
                     ));
                     status_bar.spawn((
-                        Text::new("Crit: 0%"),
+                        Text::default(),
                         stat_font.clone(),
                         TextColor(Color::srgb(1.0, 0.2, 0.2)),
                         MenuCritText,
                     ));
                     status_bar.spawn((
-                        Text::new("Life: 0%"),
+                        Text::default(),
                         stat_font.clone(),
                         TextColor(Color::srgb(1.0, 0.2, 1.0)),
                         MenuLifestealText,
                     ));
                     status_bar.spawn((
-                        Text::new("CDR: 0%"),
+                        Text::default(),
                         stat_font,
                         TextColor(Color::srgb(0.2, 0.8, 1.0)),
                         MenuCDRText,
@@ -224,7 +369,7 @@ pub fn spawn_header(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_battle_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_battle_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     use crate::resources::game_state::GameState;
     use crate::resources::round::{RoundManager, RoundState};
 
@@ -247,8 +392,14 @@ fn spawn_battle_button(parent: &mut ChildSpawnerCommands) {
         .observe(
             |_: On<Pointer<Click>>,
              mut next_state: ResMut<NextState<GameState>>,
-             mut round_manager: ResMut<RoundManager>| {
-                if round_manager.round_state == RoundState::Shop {
+             mut round_manager: ResMut<RoundManager>,
+             run_settings: Res<crate::resources::run_settings::RunSettings>,
+             mut commands: Commands| {
+                if !round_manager.has_started {
+                    // First wave of the run uses the `RoundManager` defaults
+                    run_settings.scale_wave(&mut round_manager);
+                    commands.trigger(crate::resources::round::RunStartedEvent);
+                } else if round_manager.round_state == RoundState::Shop {
                     round_manager.current_round += 1;
                     round_manager.enemies_to_spawn = crate::configs::enemy::BASE_ENEMY_COUNT
                         + (round_manager.current_round
@@ -263,6 +414,7 @@ fn spawn_battle_button(parent: &mut ChildSpawnerCommands) {
                     round_manager.elites_to_spawn = round_manager.current_round;
                     round_manager.yellow_enemies_to_spawn =
                         u32::from(round_manager.current_round >= 1);
+                    run_settings.scale_wave(&mut round_manager);
                     round_manager.round_state = RoundState::Spawning;
                 }
                 round_manager.has_started = true;
@@ -285,7 +437,7 @@ fn spawn_battle_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("GO TO BATTLE"),
+                Text::new(locale.get("menu.battle")),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -295,7 +447,7 @@ fn spawn_battle_button(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_tutorial_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_tutorial_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     use crate::resources::game_state::GameState;
 
     parent
@@ -339,7 +491,7 @@ fn spawn_tutorial_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("TUTORIAL"),
+                Text::new(locale.get("menu.tutorial")),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -349,7 +501,7 @@ fn spawn_tutorial_button(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_settings_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_settings_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     use crate::resources::game_state::GameState;
 
     parent
@@ -393,7 +545,7 @@ fn spawn_settings_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("SETTINGS"),
+                Text::new(locale.get("menu.settings")),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -403,7 +555,7 @@ fn spawn_settings_button(parent: &mut ChildSpawnerCommands) {
         });
 }
 
-fn spawn_new_game_button(parent: &mut ChildSpawnerCommands) {
+fn spawn_new_game_button(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Button,
@@ -421,9 +573,14 @@ fn spawn_new_game_button(parent: &mut ChildSpawnerCommands) {
             BackgroundColor(Color::srgba(0.3, 0.15, 0.15, 1.0)),
             WeaponMenuRestartButton,
         ))
-        .observe(|_: On<Pointer<Click>>, mut commands: Commands| {
-            crate::systems::ui::menu::confirmation::spawn_confirmation_dialog(&mut commands);
-        })
+        .observe(
+            |_: On<Pointer<Click>>, mut commands: Commands, locale: Res<Locale>| {
+                crate::systems::ui::menu::confirmation::spawn_confirmation_dialog(
+                    &mut commands,
+                    &locale,
+                );
+            },
+        )
         .observe(
             |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
                 if let Ok(mut color) = color.get_mut(trigger.entity) {
@@ -440,7 +597,7 @@ fn spawn_new_game_button(parent: &mut ChildSpawnerCommands) {
         )
         .with_children(|btn| {
             btn.spawn((
-                Text::new("NEW GAME"),
+                Text::new(locale.get("menu.new_game")),
                 TextFont {
                     font_size: 18.0,
                     ..default()
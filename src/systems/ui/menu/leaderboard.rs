@@ -0,0 +1,241 @@
+//! Records screen: the local high score tables, with replays of an entry's seed and settings
+
+use super::components::{LeaderboardContainer, LeaderboardGrid, ReplayButton, WeaponMenuTab};
+use super::offers::ShopOffers;
+use crate::resources::game_state::GameState;
+use crate::resources::leaderboard::{Leaderboard, ScoreEntry};
+use crate::resources::locale::Locale;
+use crate::resources::round::RoundManager;
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::{GameMode, RunSettings};
+use crate::systems::ui::game_over::format_time;
+use bevy::prelude::*;
+
+const TITLE_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+const HEADER_COLOR: Color = Color::srgb(0.6, 0.6, 0.7);
+const ROW_COLOR: Color = Color::srgb(0.85, 0.85, 0.9);
+const REPLAY_BG: Color = Color::srgba(0.15, 0.25, 0.15, 1.0);
+const REPLAY_HOVER_BG: Color = Color::srgba(0.25, 0.45, 0.25, 1.0);
+
+pub fn spawn_leaderboard_panel(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                display: Display::None, // Default hidden
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            LeaderboardContainer,
+            WeaponMenuTab::Records,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(locale.get("records.title")),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(TITLE_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            panel.spawn((
+                Text::new(locale.get("records.hint")),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(HEADER_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+            ));
+
+            // Tables are filled in by `refresh_leaderboard_ui`
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                LeaderboardGrid,
+            ));
+        });
+}
+
+fn spawn_cell(parent: &mut ChildSpawnerCommands, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 15.0,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn spawn_replay_button(parent: &mut ChildSpawnerCommands, index: usize, locale: &Locale) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(REPLAY_BG),
+            BorderRadius::all(Val::Px(4.0)),
+            ReplayButton(index),
+        ))
+        .observe(replay_entry)
+        .observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(REPLAY_HOVER_BG);
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(REPLAY_BG);
+                }
+            },
+        )
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(locale.get("records.replay")),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn spawn_entry_row(
+    parent: &mut ChildSpawnerCommands,
+    rank: usize,
+    index: usize,
+    entry: &ScoreEntry,
+    can_replay: bool,
+    locale: &Locale,
+) {
+    let cells = [
+        format!("#{}", rank + 1),
+        entry.round.to_string(),
+        entry.kills.to_string(),
+        format_time(entry.time),
+        entry.loadout.label(locale),
+        entry.settings().label(locale),
+        RunSeed::label(entry.seed),
+        entry.version.clone(),
+    ];
+    for cell in cells {
+        spawn_cell(parent, cell, ROW_COLOR);
+    }
+    if can_replay {
+        spawn_replay_button(parent, index, locale);
+    } else {
+        // Keep the grid aligned
+        spawn_cell(parent, String::new(), ROW_COLOR);
+    }
+}
+
+/// Starts the next run on the entry's seed and settings by reopening the menu,
+/// which rolls the first shop from the new seed
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn replay_entry(
+    trigger: On<Pointer<Click>>,
+    button_query: Query<&ReplayButton>,
+    leaderboard: Res<Leaderboard>,
+    round_manager: Res<RoundManager>,
+    mut settings: ResMut<RunSettings>,
+    mut run_seed: ResMut<RunSeed>,
+    mut offers: ResMut<ShopOffers>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if round_manager.has_started {
+        return;
+    }
+    let Some(entry) = button_query
+        .get(trigger.entity)
+        .ok()
+        .and_then(|button| leaderboard.entries.get(button.0))
+    else {
+        return;
+    };
+
+    *settings = entry.settings();
+    *run_seed = RunSeed::new(entry.seed);
+    *offers = ShopOffers::default();
+    next_state.set(GameState::WeaponMenu);
+}
+
+/// Rebuilds the table when it changes; replays are only offered before the run starts
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_leaderboard_ui(
+    mut commands: Commands,
+    leaderboard: Res<Leaderboard>,
+    round_manager: Res<RoundManager>,
+    locale: Res<Locale>,
+    grid: Single<(Entity, Ref<LeaderboardGrid>)>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !leaderboard.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    let can_replay = !round_manager.has_started;
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|tables| {
+        for (mode, title, round_header) in [
+            (GameMode::Rounds, "records.rounds", "records.round"),
+            (GameMode::Endless, "records.endless", "records.level"),
+        ] {
+            spawn_cell(tables, locale.get(title).to_string(), TITLE_COLOR);
+            tables
+                .spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::auto(9),
+                    column_gap: Val::Px(16.0),
+                    row_gap: Val::Px(6.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for header in [
+                        "records.rank",
+                        round_header,
+                        "records.kills",
+                        "records.time",
+                        "records.loadout",
+                        "records.setup",
+                        "records.seed",
+                        "records.version",
+                    ] {
+                        spawn_cell(grid, locale.get(header).to_string(), HEADER_COLOR);
+                    }
+                    // Replay column
+                    spawn_cell(grid, String::new(), HEADER_COLOR);
+                    let mut entries = leaderboard.mode_entries(mode).peekable();
+                    if entries.peek().is_none() {
+                        spawn_cell(grid, locale.get("records.empty").to_string(), HEADER_COLOR);
+                    }
+                    for (rank, (index, entry)) in entries.enumerate() {
+                        spawn_entry_row(grid, rank, index, entry, can_replay, &locale);
+                    }
+                });
+        }
+    });
+}
@@ -1,17 +1,29 @@
+pub mod achievements;
 pub mod arsenal;
 pub mod components;
 pub mod confirmation;
 pub mod interaction;
 pub mod layout;
+pub mod leaderboard;
+pub mod offers;
+pub mod presets;
 pub mod resources;
+pub mod run_setup;
 pub mod shop;
 pub mod spawn;
 pub mod systems;
+pub mod unlocks;
 
+pub use achievements::*;
 pub use components::*;
 pub use confirmation::*;
 pub use interaction::*;
+pub use leaderboard::*;
+pub use offers::*;
+pub use presets::*;
 pub use resources::*;
+pub use run_setup::*;
 pub use shop::*;
 pub use spawn::*;
 pub use systems::*;
+pub use unlocks::*;
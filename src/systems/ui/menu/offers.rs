@@ -0,0 +1,223 @@
+//! Draft-style shop offers
+//! Each post-round shop rolls `OFFER_COUNT` cards from `SHOP_POOL`, weighted by rarity.
+//! Locked offers survive rerolls and carry over into the next shop.
+
+use super::components::ShopButton;
+use super::interaction::get_progression_count;
+use crate::components::player::{Currency, Player, Progression};
+use crate::configs::shop::{
+    OFFER_COUNT, REROLL_BASE_COST, REROLL_COST_STEP, SHOP_POOL, get_card_config,
+};
+use crate::resources::profile::MetaProfile;
+use crate::resources::round::{RoundManager, RoundState};
+use crate::resources::run_seed::RunSeed;
+use crate::resources::run_settings::RunSettings;
+use crate::resources::run_stats::RunStats;
+use bevy::prelude::*;
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShopOffer {
+    pub card: ShopButton,
+    pub locked: bool,
+}
+
+#[derive(Resource, Default)]
+pub struct ShopOffers {
+    pub offers: Vec<ShopOffer>,
+    /// Rerolls used in the current shop, drives the scaling cost
+    pub reroll_count: u32,
+    /// Shop the offers were rolled for: 0 before the run starts, N after round N
+    pub rolled_for: Option<u32>,
+}
+
+impl ShopOffers {
+    pub const fn reroll_cost(&self) -> u32 {
+        REROLL_BASE_COST + REROLL_COST_STEP * self.reroll_count
+    }
+
+    /// Replaces every unlocked offer with a fresh weighted roll.
+    pub fn roll(&mut self, rng: &mut impl Rng, is_available: impl Fn(ShopButton) -> bool) {
+        self.offers.retain(|offer| offer.locked);
+        let exclude: Vec<ShopButton> = self.offers.iter().map(|offer| offer.card).collect();
+        let count = OFFER_COUNT.saturating_sub(self.offers.len());
+        self.offers.extend(
+            roll_cards(rng, count, &exclude, is_available)
+                .into_iter()
+                .map(|card| ShopOffer {
+                    card,
+                    locked: false,
+                }),
+        );
+    }
+
+    pub fn toggle_lock(&mut self, card: ShopButton) {
+        if let Some(offer) = self.offers.iter_mut().find(|offer| offer.card == card) {
+            offer.locked = !offer.locked;
+        }
+    }
+
+    /// Removes a purchased offer from the shop.
+    pub fn take(&mut self, card: ShopButton) {
+        self.offers.retain(|offer| offer.card != card);
+    }
+}
+
+/// Weighted sample of up to `count` distinct cards from `SHOP_POOL`.
+pub fn roll_cards(
+    rng: &mut impl Rng,
+    count: usize,
+    exclude: &[ShopButton],
+    is_available: impl Fn(ShopButton) -> bool,
+) -> Vec<ShopButton> {
+    let mut candidates: Vec<ShopButton> = SHOP_POOL
+        .into_iter()
+        .filter(|card| !exclude.contains(card) && is_available(*card))
+        .collect();
+    let mut rolled = Vec::with_capacity(count);
+
+    while rolled.len() < count && !candidates.is_empty() {
+        let total: u32 = candidates
+            .iter()
+            .map(|card| get_card_config(*card).rarity.weight())
+            .sum();
+        let mut pick = rng.gen_range(0..total);
+        let index = candidates
+            .iter()
+            .position(|card| {
+                let weight = get_card_config(*card).rarity.weight();
+                if pick < weight {
+                    true
+                } else {
+                    pick -= weight;
+                    false
+                }
+            })
+            .unwrap_or(0);
+        rolled.push(candidates.swap_remove(index));
+    }
+
+    rolled
+}
+
+/// Locked cards, cards that have hit their purchase limit and cards banned by an ascension
+/// never show up in the pool.
+pub fn card_available(
+    card: ShopButton,
+    progression: &Progression,
+    profile: &MetaProfile,
+    settings: &RunSettings,
+) -> bool {
+    profile.card_unlocked(card)
+        && (card != ShopButton::LifestealUp || settings.lifesteal_allowed())
+        && get_card_config(card)
+            .limit
+            .is_none_or(|limit| get_progression_count(card, progression) < limit)
+}
+
+/// Rolls new offers when a new shop opens (run start or a cleared round).
+/// Opening the menu mid-round keeps the current offers.
+#[allow(clippy::needless_pass_by_value)]
+pub fn roll_shop_offers(
+    mut offers: ResMut<ShopOffers>,
+    round_manager: Res<RoundManager>,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    mut run_seed: ResMut<RunSeed>,
+    progression: Single<&Progression, With<Player>>,
+) {
+    let shop = if !round_manager.has_started {
+        Some(0)
+    } else if round_manager.round_state == RoundState::Shop {
+        Some(round_manager.current_round)
+    } else {
+        None
+    };
+
+    if let Some(shop) = shop
+        && offers.rolled_for != Some(shop)
+    {
+        offers.rolled_for = Some(shop);
+        offers.reroll_count = 0;
+        offers.roll(&mut run_seed.shop, |card| {
+            card_available(card, &progression, &profile, &run_settings)
+        });
+    }
+}
+
+/// Paid reroll of every unlocked offer.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn reroll_shop_offers(
+    _trigger: On<Pointer<Click>>,
+    mut offers: ResMut<ShopOffers>,
+    profile: Res<MetaProfile>,
+    run_settings: Res<RunSettings>,
+    round_manager: Res<RoundManager>,
+    mut run_stats: ResMut<RunStats>,
+    mut run_seed: ResMut<RunSeed>,
+    player: Single<(&mut Currency, &Progression), With<Player>>,
+) {
+    let (mut currency, progression) = player.into_inner();
+    let cost = offers.reroll_cost();
+    if currency.gold < cost {
+        return;
+    }
+
+    currency.gold -= cost;
+    run_stats.record_gold_spent(round_manager.current_round, cost);
+    offers.reroll_count += 1;
+    offers.roll(&mut run_seed.shop, |card| {
+        card_available(card, progression, &profile, &run_settings)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_cards_distinct_and_filtered() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let rolled = roll_cards(&mut rng, OFFER_COUNT, &[ShopButton::Heal], |card| {
+                card != ShopButton::NovaCore
+            });
+            assert_eq!(rolled.len(), OFFER_COUNT);
+            assert!(!rolled.contains(&ShopButton::Heal));
+            assert!(!rolled.contains(&ShopButton::NovaCore));
+            for (i, card) in rolled.iter().enumerate() {
+                assert!(!rolled[i + 1..].contains(card), "{card:?} rolled twice");
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_keeps_locked_offers() {
+        let mut rng = rand::thread_rng();
+        let mut offers = ShopOffers::default();
+        offers.roll(&mut rng, |_| true);
+        assert_eq!(offers.offers.len(), OFFER_COUNT);
+
+        let kept = offers.offers[0].card;
+        offers.toggle_lock(kept);
+        for _ in 0..20 {
+            offers.roll(&mut rng, |_| true);
+            assert_eq!(offers.offers.len(), OFFER_COUNT);
+            assert!(offers.offers.iter().any(|o| o.card == kept && o.locked));
+        }
+
+        offers.take(kept);
+        assert!(offers.offers.iter().all(|o| o.card != kept));
+    }
+
+    #[test]
+    fn test_reroll_cost_scales() {
+        let mut offers = ShopOffers::default();
+        assert_eq!(offers.reroll_cost(), REROLL_BASE_COST);
+        offers.reroll_count = 2;
+        assert_eq!(
+            offers.reroll_cost(),
+            REROLL_BASE_COST + 2 * REROLL_COST_STEP
+        );
+    }
+}
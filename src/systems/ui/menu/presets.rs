@@ -0,0 +1,518 @@
+//! Named loadout presets on the equipment tab: weapons, spells and modes of both hands
+//! Presets live in `MetaProfile` so they carry over between runs and app restarts.
+
+use super::arsenal::equip_weapon;
+use super::components::{
+    PresetApplyButton, PresetDeleteButton, PresetList, PresetNameField, PresetNameText,
+    PresetSaveButton, PresetStatusText,
+};
+use super::resources::PresetDraft;
+use crate::components::player::{Hand, HandType};
+use crate::components::weapon::{GunState, MagicLoadout, SwordState, Weapon};
+use crate::configs::meta::PRESET_NAME_MAX_CHARS;
+use crate::resources::locale::Locale;
+use crate::resources::profile::{HandPreset, LoadoutPreset, MetaProfile};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+const CHIP_BG: Color = Color::srgba(0.16, 0.16, 0.24, 1.0);
+const CHIP_BG_HOVER: Color = Color::srgba(0.25, 0.25, 0.4, 1.0);
+const CHIP_BORDER: Color = Color::srgba(0.3, 0.3, 0.4, 1.0);
+const CHIP_BORDER_LOCKED: Color = Color::srgba(0.2, 0.2, 0.2, 1.0);
+const FIELD_BORDER_EDITING: Color = Color::srgb(1.0, 0.84, 0.0);
+const PLACEHOLDER_COLOR: Color = Color::srgb(0.5, 0.5, 0.6);
+
+/// Appends typed text to a preset name, dropping control characters and anything past the cap
+fn push_name_chars(name: &mut String, text: &str) {
+    for c in text.chars().filter(|c| !c.is_control()) {
+        if name.chars().count() >= PRESET_NAME_MAX_CHARS {
+            break;
+        }
+        name.push(c);
+    }
+}
+
+/// Equips one hand of a preset
+pub const fn apply_hand_preset(
+    preset: &HandPreset,
+    hand: &mut Hand,
+    weapon: &mut Weapon,
+    loadout: &mut MagicLoadout,
+    gun: &mut GunState,
+    sword: &mut SwordState,
+) {
+    equip_weapon(hand, weapon, preset.weapon);
+    loadout.primary = preset.primary;
+    loadout.secondary = preset.secondary;
+    gun.mode = preset.gun_mode;
+    sword.mode = preset.sword_mode;
+}
+
+fn set_status(status: &mut Query<&mut Text, With<PresetStatusText>>, message: &str) {
+    for mut text in status.iter_mut() {
+        text.0 = message.to_string();
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn spawn_preset_bar(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(|bar| {
+            bar.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(locale.get("presets.title")),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+                row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.12, 1.0)),
+                    BorderColor::all(CHIP_BORDER),
+                    BorderRadius::all(Val::Px(6.0)),
+                    PresetNameField,
+                ))
+                .observe(|_: On<Pointer<Click>>, mut draft: ResMut<PresetDraft>| {
+                    draft.editing = !draft.editing;
+                })
+                .with_children(|field| {
+                    field.spawn((
+                        Text::default(),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        PresetNameText,
+                    ));
+                });
+                row.spawn((
+                    Button,
+                    Node {
+                        height: Val::Px(36.0),
+                        padding: UiRect::horizontal(Val::Px(12.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.1, 0.25, 0.12, 1.0)),
+                    BorderColor::all(Color::srgb(0.3, 0.8, 0.4)),
+                    BorderRadius::all(Val::Px(6.0)),
+                    PresetSaveButton,
+                ))
+                .observe(save_preset_observer)
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(locale.get("presets.save")),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+
+            // Chips are filled in by `refresh_preset_list`
+            bar.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    column_gap: Val::Px(8.0),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                PresetList,
+            ));
+
+            bar.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.6)),
+                PresetStatusText,
+            ));
+        });
+}
+
+fn spawn_preset_chip(
+    parent: &mut ChildSpawnerCommands,
+    index: usize,
+    preset: &LoadoutPreset,
+    allowed: bool,
+    locale: &Locale,
+) {
+    let summary = format!(
+        "{} / {}",
+        locale.get(preset.left.weapon.name()),
+        locale.get(preset.right.weapon.name())
+    );
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(CHIP_BG),
+            BorderColor::all(if allowed {
+                CHIP_BORDER
+            } else {
+                CHIP_BORDER_LOCKED
+            }),
+            BorderRadius::all(Val::Px(6.0)),
+        ))
+        .with_children(|chip| {
+            chip.spawn((
+                Button,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                PresetApplyButton(index),
+            ))
+            .observe(apply_preset_observer)
+            .observe(
+                |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                    if let Ok(mut color) = color.get_mut(trigger.entity) {
+                        *color = BackgroundColor(CHIP_BG_HOVER);
+                    }
+                },
+            )
+            .observe(
+                |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                    if let Ok(mut color) = color.get_mut(trigger.entity) {
+                        *color = BackgroundColor(Color::NONE);
+                    }
+                },
+            )
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(preset.name.clone()),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(if allowed {
+                        Color::WHITE
+                    } else {
+                        PLACEHOLDER_COLOR
+                    }),
+                ));
+                btn.spawn((
+                    Text::new(summary),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                ));
+            });
+            chip.spawn((
+                Button,
+                Node {
+                    width: Val::Px(28.0),
+                    align_self: AlignSelf::Stretch,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.3, 0.1, 0.1, 0.8)),
+                PresetDeleteButton(index),
+            ))
+            .observe(delete_preset_observer)
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("×"),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+/// Saves both hands under the typed name, or a numbered default name
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn save_preset_observer(
+    _: On<Pointer<Click>>,
+    hands: Query<(&Hand, &Weapon, &MagicLoadout, &GunState, &SwordState)>,
+    mut profile: ResMut<MetaProfile>,
+    mut draft: ResMut<PresetDraft>,
+    locale: Res<Locale>,
+    mut status: Query<&mut Text, With<PresetStatusText>>,
+) {
+    let capture = |side: HandType| {
+        hands
+            .iter()
+            .find(|(hand, ..)| hand.side == side)
+            .map(|(_, weapon, loadout, gun, sword)| {
+                HandPreset::capture(weapon.kind, loadout, gun.mode, sword.mode)
+            })
+    };
+    let (Some(left), Some(right)) = (capture(HandType::Left), capture(HandType::Right)) else {
+        return;
+    };
+    let name = match draft.name.trim() {
+        "" => locale.format(
+            "presets.default_name",
+            &[("n", &(profile.presets.len() + 1))],
+        ),
+        typed => typed.to_string(),
+    };
+
+    let message = if profile.store_preset(LoadoutPreset {
+        name: name.clone(),
+        left,
+        right,
+    }) {
+        profile.save();
+        draft.name.clear();
+        draft.editing = false;
+        locale.format("presets.saved", &[("name", &name)])
+    } else {
+        locale.get("presets.full").to_string()
+    };
+    set_status(&mut status, &message);
+}
+
+/// Equips a preset unless it holds content that is still locked
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn apply_preset_observer(
+    trigger: On<Pointer<Click>>,
+    buttons: Query<&PresetApplyButton>,
+    mut hands: Query<(
+        &mut Hand,
+        &mut Weapon,
+        &mut MagicLoadout,
+        &mut GunState,
+        &mut SwordState,
+    )>,
+    profile: Res<MetaProfile>,
+    locale: Res<Locale>,
+    mut status: Query<&mut Text, With<PresetStatusText>>,
+) {
+    let Some(preset) = buttons
+        .get(trigger.entity)
+        .ok()
+        .and_then(|button| profile.presets.get(button.0))
+    else {
+        return;
+    };
+    if !profile.preset_allowed(preset) {
+        let message = locale.format("presets.locked", &[("name", &preset.name)]);
+        set_status(&mut status, &message);
+        return;
+    }
+
+    for (mut hand, mut weapon, mut loadout, mut gun, mut sword) in &mut hands {
+        apply_hand_preset(
+            preset.hand(hand.side),
+            &mut hand,
+            &mut weapon,
+            &mut loadout,
+            &mut gun,
+            &mut sword,
+        );
+    }
+    let message = locale.format("presets.applied", &[("name", &preset.name)]);
+    set_status(&mut status, &message);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn delete_preset_observer(
+    trigger: On<Pointer<Click>>,
+    buttons: Query<&PresetDeleteButton>,
+    mut profile: ResMut<MetaProfile>,
+    locale: Res<Locale>,
+    mut status: Query<&mut Text, With<PresetStatusText>>,
+) {
+    let Ok(button) = buttons.get(trigger.entity) else {
+        return;
+    };
+    if button.0 >= profile.presets.len() {
+        return;
+    }
+    let preset = profile.presets.remove(button.0);
+    profile.save();
+    let message = locale.format("presets.deleted", &[("name", &preset.name)]);
+    set_status(&mut status, &message);
+}
+
+/// Rebuilds the preset chips whenever the profile changes
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_preset_list(
+    mut commands: Commands,
+    profile: Res<MetaProfile>,
+    locale: Res<Locale>,
+    list: Single<(Entity, Ref<PresetList>)>,
+) {
+    let (list_entity, list_marker) = list.into_inner();
+    if !profile.is_changed() && !list_marker.is_added() {
+        return;
+    }
+
+    commands.entity(list_entity).despawn_related::<Children>();
+    commands.entity(list_entity).with_children(|list| {
+        if profile.presets.is_empty() {
+            list.spawn((
+                Text::new(locale.get("presets.empty")),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(PLACEHOLDER_COLOR),
+            ));
+        }
+        for (index, preset) in profile.presets.iter().enumerate() {
+            spawn_preset_chip(list, index, preset, profile.preset_allowed(preset), &locale);
+        }
+    });
+}
+
+/// Types into the name field while it is focused
+pub fn edit_preset_name(mut keys: MessageReader<KeyboardInput>, mut draft: ResMut<PresetDraft>) {
+    if !draft.editing {
+        keys.clear();
+        return;
+    }
+    for event in keys.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => draft.editing = false,
+            Key::Backspace => {
+                draft.name.pop();
+            }
+            Key::Space => push_name_chars(&mut draft.name, " "),
+            Key::Character(text) => push_name_chars(&mut draft.name, text),
+            _ => {}
+        }
+    }
+}
+
+/// Shows the typed name with a caret while editing, or the default name as a placeholder
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn update_preset_name_field(
+    draft: Res<PresetDraft>,
+    profile: Res<MetaProfile>,
+    locale: Res<Locale>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<PresetNameText>>,
+    mut fields: Query<&mut BorderColor, With<PresetNameField>>,
+) {
+    if !draft.is_changed() && !profile.is_changed() && !locale.is_changed() {
+        return;
+    }
+    let (label, color) = if draft.editing {
+        (format!("{}|", draft.name), Color::WHITE)
+    } else if draft.name.is_empty() {
+        (
+            locale.format(
+                "presets.default_name",
+                &[("n", &(profile.presets.len() + 1))],
+            ),
+            PLACEHOLDER_COLOR,
+        )
+    } else {
+        (draft.name.clone(), Color::WHITE)
+    };
+    for (mut text, mut text_color) in &mut texts {
+        text.0.clone_from(&label);
+        text_color.0 = color;
+    }
+    for mut border in &mut fields {
+        *border = BorderColor::all(if draft.editing {
+            FIELD_BORDER_EDITING
+        } else {
+            CHIP_BORDER
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::weapon::{GunMode, SpellType, SwordMode, WeaponType};
+
+    #[test]
+    fn test_name_input_is_capped() {
+        let mut name = String::new();
+        push_name_chars(&mut name, "Boss\trush");
+        assert_eq!(name, "Bossrush");
+
+        push_name_chars(&mut name, &"x".repeat(PRESET_NAME_MAX_CHARS));
+        assert_eq!(name.chars().count(), PRESET_NAME_MAX_CHARS);
+    }
+
+    #[test]
+    fn test_apply_hand_preset_sets_weapon_spells_and_modes() {
+        let preset = HandPreset {
+            weapon: WeaponType::Magic,
+            primary: SpellType::Nova,
+            secondary: SpellType::Laser,
+            gun_mode: GunMode::Rapid,
+            sword_mode: SwordMode::Shattered,
+        };
+        let mut hand = Hand {
+            side: HandType::Left,
+            equipped_weapon: Some(WeaponType::Sword),
+        };
+        let mut weapon = Weapon::default();
+        let mut loadout = MagicLoadout::default();
+        let mut gun = GunState::default();
+        let mut sword = SwordState::default();
+
+        apply_hand_preset(
+            &preset,
+            &mut hand,
+            &mut weapon,
+            &mut loadout,
+            &mut gun,
+            &mut sword,
+        );
+
+        assert_eq!(hand.equipped_weapon, Some(WeaponType::Magic));
+        assert_eq!(weapon.kind, WeaponType::Magic);
+        assert_eq!(
+            (loadout.primary, loadout.secondary),
+            (SpellType::Nova, SpellType::Laser)
+        );
+        assert_eq!(gun.mode, GunMode::Rapid);
+        assert_eq!(sword.mode, SwordMode::Shattered);
+    }
+}
@@ -9,3 +9,11 @@ impl Default for ActiveDescriptionSide {
         Self(HandType::Left)
     }
 }
+
+/// Name typed for the next saved loadout preset
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PresetDraft {
+    pub name: String,
+    /// Keyboard input goes to the name field instead of the menu
+    pub editing: bool,
+}
@@ -0,0 +1,348 @@
+//! Run setup tab: game mode, difficulty preset and stackable ascension modifiers.
+//! Choices can only be changed before the first wave of a run.
+
+use super::components::{
+    AscensionButton, DifficultyButton, GameModeButton, RunSetupContainer, RunSetupGrid,
+    RunSetupSummaryText, WeaponMenuTab,
+};
+use crate::configs::difficulty::{
+    ASCENSION_LIST, DIFFICULTY_LIST, GAME_MODE_LIST, get_ascension_config, get_difficulty_config,
+    get_game_mode_config,
+};
+use crate::resources::locale::Locale;
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::{Ascension, Difficulty, GameMode, RunSettings};
+use bevy::prelude::*;
+
+const SELECTED_BORDER: Color = Color::srgb(1.0, 0.8, 0.2);
+const ASCENSION_BORDER: Color = Color::srgb(1.0, 0.35, 0.3);
+const IDLE_BORDER: Color = Color::srgb(0.3, 0.3, 0.35);
+const CARD_BG: Color = Color::srgba(0.1, 0.08, 0.08, 0.95);
+const CARD_HOVER_BG: Color = Color::srgba(0.18, 0.12, 0.12, 1.0);
+
+pub fn spawn_run_setup_panel(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                display: Display::None, // Default hidden
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            RunSetupContainer,
+            WeaponMenuTab::Run,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(locale.get("run_setup.title")),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(SELECTED_BORDER),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+                RunSetupSummaryText,
+            ));
+
+            // Sections are filled in by `refresh_run_setup_ui`
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                RunSetupGrid,
+            ));
+        });
+}
+
+fn spawn_section_title(parent: &mut ChildSpawnerCommands, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 0.6, 0.7)),
+        Node {
+            margin: UiRect::top(Val::Px(10.0)),
+            ..default()
+        },
+    ));
+}
+
+fn spawn_option_row(
+    parent: &mut ChildSpawnerCommands,
+    spawn_cards: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            width: Val::Percent(100.0),
+            row_gap: Val::Px(10.0),
+            column_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(spawn_cards);
+}
+
+/// Card body shared by game mode, difficulty and ascension options
+fn spawn_option_card<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    name: &str,
+    description: &str,
+    locale: &Locale,
+    border: Color,
+    locked: bool,
+    marker: impl Bundle,
+) -> EntityCommands<'a> {
+    let mut card = parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(170.0),
+            min_height: Val::Px(80.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(8.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BorderColor::all(border),
+        BorderRadius::all(Val::Px(8.0)),
+        BackgroundColor(if locked {
+            CARD_BG.with_alpha(0.4)
+        } else {
+            CARD_BG
+        }),
+        marker,
+    ));
+    card.with_children(|card| {
+        card.spawn((
+            Text::new(locale.get(name)),
+            TextFont {
+                font_size: 17.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        card.spawn((
+            Text::new(locale.get(description)),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.75)),
+            Node {
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            },
+        ));
+    });
+    if !locked {
+        card.observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(CARD_HOVER_BG);
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(CARD_BG);
+                }
+            },
+        );
+    }
+    card
+}
+
+fn spawn_game_mode_card(
+    parent: &mut ChildSpawnerCommands,
+    mode: GameMode,
+    settings: &RunSettings,
+    locale: &Locale,
+    locked: bool,
+) {
+    let config = get_game_mode_config(mode);
+    let border = if settings.mode == mode {
+        SELECTED_BORDER
+    } else {
+        IDLE_BORDER
+    };
+    spawn_option_card(
+        parent,
+        config.name,
+        config.description,
+        locale,
+        border,
+        locked,
+        GameModeButton(mode),
+    )
+    .observe(
+        |trigger: On<Pointer<Click>>,
+         button_query: Query<&GameModeButton>,
+         round_manager: Res<RoundManager>,
+         mut settings: ResMut<RunSettings>| {
+            if !round_manager.has_started
+                && let Ok(button) = button_query.get(trigger.entity)
+            {
+                settings.mode = button.0;
+            }
+        },
+    );
+}
+
+fn spawn_difficulty_card(
+    parent: &mut ChildSpawnerCommands,
+    difficulty: Difficulty,
+    settings: &RunSettings,
+    locale: &Locale,
+    locked: bool,
+) {
+    let config = get_difficulty_config(difficulty);
+    let border = if settings.difficulty == difficulty {
+        SELECTED_BORDER
+    } else {
+        IDLE_BORDER
+    };
+    spawn_option_card(
+        parent,
+        config.name,
+        config.description,
+        locale,
+        border,
+        locked,
+        DifficultyButton(difficulty),
+    )
+    .observe(
+        |trigger: On<Pointer<Click>>,
+         button_query: Query<&DifficultyButton>,
+         round_manager: Res<RoundManager>,
+         mut settings: ResMut<RunSettings>| {
+            if !round_manager.has_started
+                && let Ok(button) = button_query.get(trigger.entity)
+            {
+                settings.difficulty = button.0;
+            }
+        },
+    );
+}
+
+fn spawn_ascension_card(
+    parent: &mut ChildSpawnerCommands,
+    ascension: Ascension,
+    settings: &RunSettings,
+    locale: &Locale,
+    locked: bool,
+) {
+    let config = get_ascension_config(ascension);
+    let border = if settings.has(ascension) {
+        ASCENSION_BORDER
+    } else {
+        IDLE_BORDER
+    };
+    spawn_option_card(
+        parent,
+        config.name,
+        config.description,
+        locale,
+        border,
+        locked,
+        AscensionButton(ascension),
+    )
+    .observe(
+        |trigger: On<Pointer<Click>>,
+         button_query: Query<&AscensionButton>,
+         round_manager: Res<RoundManager>,
+         mut settings: ResMut<RunSettings>| {
+            if !round_manager.has_started
+                && let Ok(button) = button_query.get(trigger.entity)
+            {
+                settings.toggle(button.0);
+            }
+        },
+    );
+}
+
+/// Rebuilds the option cards whenever the selection changes or the run starts
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_run_setup_ui(
+    mut commands: Commands,
+    settings: Res<RunSettings>,
+    round_manager: Res<RoundManager>,
+    locale: Res<Locale>,
+    grid: Single<(Entity, Ref<RunSetupGrid>)>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !settings.is_changed() && !round_manager.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    let locked = round_manager.has_started;
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|grid| {
+        spawn_section_title(grid, locale.get("run_setup.mode"));
+        spawn_option_row(grid, |row| {
+            for mode in GAME_MODE_LIST {
+                spawn_game_mode_card(row, mode, &settings, &locale, locked);
+            }
+        });
+        spawn_section_title(grid, locale.get("run_setup.difficulty"));
+        spawn_option_row(grid, |row| {
+            for difficulty in DIFFICULTY_LIST {
+                spawn_difficulty_card(row, difficulty, &settings, &locale, locked);
+            }
+        });
+        spawn_section_title(grid, locale.get("run_setup.ascensions"));
+        spawn_option_row(grid, |row| {
+            for ascension in ASCENSION_LIST {
+                spawn_ascension_card(row, ascension, &settings, &locale, locked);
+            }
+        });
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_run_setup_summary_text(
+    settings: Res<RunSettings>,
+    round_manager: Res<RoundManager>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<RunSetupSummaryText>>,
+) {
+    let status = locale.get(if round_manager.has_started {
+        "run_setup.locked"
+    } else {
+        "run_setup.next_run"
+    });
+    let label = format!("{}  |  {status}", settings.label(&locale));
+    for mut text in &mut query {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+}
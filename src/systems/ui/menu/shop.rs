@@ -1,12 +1,22 @@
+use super::arsenal::format_attack_comparison;
 use super::components::{
-    InfinitySymbol, SelectCardEvent, ShopButton, ShopBuyButton, ShopBuyButtonPrice,
-    ShopBuyButtonText, ShopCardCount, ShopCardCurrentCount, ShopCardLimit, ShopContainer,
+    InfinitySymbol, SelectCardEvent, SelectedShopCard, ShopButton, ShopBuyButton,
+    ShopBuyButtonPrice, ShopBuyButtonText, ShopCardCount, ShopCardCurrentCount, ShopCardLimit,
+    ShopContainer, ShopLockButton, ShopOfferGrid, ShopRerollButton, ShopRerollText,
+    ShopStatPreviewText, WeaponMenuTab,
 };
-use crate::components::player::Progression;
+use super::interaction::{apply_attack_upgrade, get_progression_count};
+use super::offers::{ShopOffers, reroll_shop_offers};
+use crate::components::player::{CombatStats, Hand, HandType, Player, PlayerStats, Progression};
+use crate::components::weapon::{MagicLoadout, Weapon};
+use crate::resources::locale::Locale;
+use crate::resources::round::RoundManager;
+use crate::resources::run_settings::RunSettings;
+use crate::systems::combat::stat_calc::{AttackStats, hand_attacks};
 use bevy::prelude::*;
 
 #[allow(clippy::too_many_lines)]
-pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
+pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands, locale: &Locale) {
     parent
         .spawn((
             Node {
@@ -19,10 +29,11 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
                 ..default()
             },
             ShopContainer,
+            WeaponMenuTab::Card,
         ))
         .with_children(|shop_scroll| {
             shop_scroll.spawn((
-                Text::new("SHOP UPGRADES"),
+                Text::new(locale.get("shop.title")),
                 TextFont {
                     font_size: 24.0,
                     ..default()
@@ -33,8 +44,52 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
                     ..default()
                 },
             ));
+
+            // Reroll Button
             shop_scroll
-                .spawn(Node {
+                .spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                        margin: UiRect::bottom(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.8, 1.0)),
+                    BorderRadius::all(Val::Px(8.0)),
+                    BackgroundColor(Color::srgba(0.05, 0.15, 0.2, 0.95)),
+                    ShopRerollButton,
+                ))
+                .observe(reroll_shop_offers)
+                .observe(
+                    |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                        if let Ok(mut color) = color.get_mut(trigger.entity) {
+                            *color = BackgroundColor(Color::srgba(0.1, 0.25, 0.3, 1.0));
+                        }
+                    },
+                )
+                .observe(
+                    |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                        if let Ok(mut color) = color.get_mut(trigger.entity) {
+                            *color = BackgroundColor(Color::srgba(0.05, 0.15, 0.2, 0.95));
+                        }
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(locale.get("shop.reroll")),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.9, 1.0)),
+                        ShopRerollText,
+                    ));
+                });
+
+            // Offer cards are filled in by `refresh_shop_offers_ui`
+            shop_scroll.spawn((
+                Node {
                     flex_direction: FlexDirection::Row,
                     flex_wrap: FlexWrap::Wrap,
                     justify_content: JustifyContent::Center,
@@ -43,17 +98,9 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
                     row_gap: Val::Px(10.0),
                     column_gap: Val::Px(10.0),
                     ..default()
-                })
-                .with_children(|grid| {
-                    spawn_shop_button(grid, ShopButton::Heal, "");
-                    spawn_shop_button(grid, ShopButton::DamageUp, "");
-                    spawn_shop_button(grid, ShopButton::MaxHealthUp, "");
-                    spawn_shop_button(grid, ShopButton::CritDamageUp, "");
-                    spawn_shop_button(grid, ShopButton::CritChanceUp, "");
-                    spawn_shop_button(grid, ShopButton::LifestealUp, "");
-                    spawn_shop_button(grid, ShopButton::CooldownReductionUp, "");
-                    spawn_shop_button(grid, ShopButton::NovaCore, "");
-                });
+                },
+                ShopOfferGrid,
+            ));
 
             // Shop Buy Button
             shop_scroll
@@ -92,7 +139,7 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
                 )
                 .with_children(|buy_btn| {
                     buy_btn.spawn((
-                        Text::new("Select an upgrade"),
+                        Text::new(locale.get("shop.select")),
                         TextFont {
                             font_size: 22.0,
                             ..default()
@@ -110,6 +157,21 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
                         ShopBuyButtonPrice,
                     ));
                 });
+            shop_scroll.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 1.0, 0.7)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    max_width: Val::Px(560.0),
+                    display: Display::None,
+                    ..default()
+                },
+                ShopStatPreviewText,
+            ));
             shop_scroll.spawn(Node {
                 height: Val::Px(20.0),
                 ..default()
@@ -118,12 +180,20 @@ pub fn spawn_shop_panel(parent: &mut ChildSpawnerCommands) {
 }
 
 #[allow(clippy::too_many_lines)]
-pub fn spawn_shop_button(parent: &mut ChildSpawnerCommands, btn_type: ShopButton, _label: &str) {
+pub fn spawn_shop_button(
+    parent: &mut ChildSpawnerCommands,
+    btn_type: ShopButton,
+    locked: bool,
+    round: u32,
+    markup: f32,
+    locale: &Locale,
+) {
     // Determine card type: BLUE (Advanced) or WHITE (Basic)
     let (border_color, bg_color, bg_hover, text_accent) = get_shop_button_colors(btn_type);
 
     // Get title, description, and price for each upgrade type
-    let (title, desc, price) = get_shop_button_content(btn_type);
+    let (title, desc, price) = get_shop_button_content(btn_type, round, markup, locale);
+    let rarity = crate::configs::shop::get_card_config(btn_type).rarity;
 
     parent
         .spawn((
@@ -170,6 +240,62 @@ pub fn spawn_shop_button(parent: &mut ChildSpawnerCommands, btn_type: ShopButton
             },
         )
         .with_children(|card| {
+            // Lock toggle (top-right corner)
+            card.spawn((
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(6.0),
+                    right: Val::Px(6.0),
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(if locked {
+                    Color::srgb(1.0, 0.85, 0.0)
+                } else {
+                    Color::srgb(0.4, 0.4, 0.4)
+                }),
+                BorderRadius::all(Val::Px(4.0)),
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                ShopLockButton(btn_type),
+            ))
+            .observe(
+                |mut trigger: On<Pointer<Click>>,
+                 lock_query: Query<&ShopLockButton>,
+                 mut offers: ResMut<ShopOffers>| {
+                    // Don't let the click select the card underneath
+                    trigger.propagate(false);
+                    if let Ok(lock) = lock_query.get(trigger.entity) {
+                        offers.toggle_lock(lock.0);
+                    }
+                },
+            )
+            .with_children(|lock| {
+                lock.spawn((
+                    Text::new(locale.get(if locked { "shop.locked" } else { "shop.lock" })),
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(if locked {
+                        Color::srgb(1.0, 0.85, 0.0)
+                    } else {
+                        Color::srgb(0.6, 0.6, 0.6)
+                    }),
+                ));
+            });
+
+            // Rarity
+            card.spawn((
+                Text::new(locale.get(rarity.label())),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(rarity.color()),
+            ));
+
             // Rust-Drawn Icon
             crate::visuals::ui_icons::spawn_shop_icon(card, btn_type);
 
@@ -343,21 +469,77 @@ pub const fn get_shop_button_colors(btn_type: ShopButton) -> (Color, Color, Colo
             Color::srgba(0.25, 0.1, 0.3, 1.0),   // Hover purple
             Color::srgb(0.9, 0.7, 1.0),          // Light purple accent
         ),
+        ShopButton::MaxManaUp => (
+            Color::srgb(0.3, 0.5, 1.0),           // Royal blue border
+            Color::srgba(0.05, 0.08, 0.25, 0.95), // Dark navy background
+            Color::srgba(0.1, 0.15, 0.35, 1.0),   // Hover navy
+            Color::srgb(0.6, 0.75, 1.0),          // Blue accent text
+        ),
+        ShopButton::ManaRegenUp => (
+            Color::srgb(0.4, 0.7, 1.0),           // Sky blue border
+            Color::srgba(0.05, 0.12, 0.22, 0.95), // Dark azure background
+            Color::srgba(0.1, 0.2, 0.32, 1.0),    // Hover azure
+            Color::srgb(0.7, 0.85, 1.0),          // Sky accent text
+        ),
+        ShopButton::ArmorUp => (
+            Color::srgb(0.7, 0.7, 0.75),          // Steel border
+            Color::srgba(0.12, 0.12, 0.14, 0.95), // Dark iron background
+            Color::srgba(0.2, 0.2, 0.23, 1.0),    // Hover iron
+            Color::srgb(0.85, 0.85, 0.9),         // Steel accent text
+        ),
+        ShopButton::MoveSpeedUp => (
+            Color::srgb(0.3, 0.9, 0.8),           // Teal border
+            Color::srgba(0.03, 0.18, 0.16, 0.95), // Dark teal background
+            Color::srgba(0.06, 0.28, 0.25, 1.0),  // Hover teal
+            Color::srgb(0.6, 1.0, 0.9),           // Teal accent text
+        ),
+        ShopButton::DashCooldownDown => (
+            Color::srgb(0.0, 1.0, 1.0),          // Aqua border (player color)
+            Color::srgba(0.0, 0.16, 0.18, 0.95), // Dark aqua background
+            Color::srgba(0.0, 0.26, 0.28, 1.0),  // Hover aqua
+            Color::srgb(0.6, 1.0, 1.0),          // Aqua accent text
+        ),
+        ShopButton::MagnetUp => (
+            Color::srgb(0.9, 0.3, 1.0),           // Magenta border
+            Color::srgba(0.16, 0.04, 0.18, 0.95), // Dark magenta background
+            Color::srgba(0.26, 0.08, 0.28, 1.0),  // Hover magenta
+            Color::srgb(1.0, 0.6, 1.0),           // Magenta accent text
+        ),
+        ShopButton::Relic(_) => (
+            Color::srgb(1.0, 0.5, 0.1),           // Ember border
+            Color::srgba(0.18, 0.09, 0.02, 0.95), // Dark bronze background
+            Color::srgba(0.28, 0.15, 0.04, 1.0),  // Hover bronze
+            Color::srgb(1.0, 0.75, 0.45),         // Ember accent text
+        ),
     }
 }
 
-pub fn get_shop_button_content(btn_type: ShopButton) -> (String, String, String) {
+pub fn get_shop_button_content(
+    btn_type: ShopButton,
+    round: u32,
+    markup: f32,
+    locale: &Locale,
+) -> (String, String, String) {
     let config = crate::configs::shop::get_card_config(btn_type);
-    let title = config.name.to_string();
-    let price = format!("{}G", config.price);
+    let title = locale.get(config.name).to_string();
+    let price = format!(
+        "{}G",
+        crate::configs::shop::card_price(btn_type, round, markup)
+    );
 
     #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
     let desc = match btn_type {
-        ShopButton::Heal => format!("+{} HP", config.value),
+        ShopButton::Heal => locale.format("card.heal.effect", &[("value", &config.value)]),
         ShopButton::DamageUp | ShopButton::CritChanceUp | ShopButton::CooldownReductionUp => {
             format!("+{}%", (config.value * 100.0) as u32)
         }
-        ShopButton::MaxHealthUp => format!("+{}", config.value as u32),
+        ShopButton::MaxHealthUp
+        | ShopButton::MaxManaUp
+        | ShopButton::ArmorUp
+        | ShopButton::MoveSpeedUp
+        | ShopButton::MagnetUp => format!("+{}", config.value as u32),
+        ShopButton::DashCooldownDown => format!("-{}%", (config.value * 100.0) as u32),
+        ShopButton::ManaRegenUp => format!("+{}/s", config.value as u32),
         ShopButton::CritDamageUp => format!("+{}%", (config.value * 100.0) as u32),
         ShopButton::LifestealUp => {
             format!(
@@ -366,7 +548,7 @@ pub fn get_shop_button_content(btn_type: ShopButton) -> (String, String, String)
                 (config.value * 50.0) as u32
             )
         }
-        ShopButton::NovaCore => config.description.to_string(),
+        ShopButton::NovaCore | ShopButton::Relic(_) => locale.get(config.description).to_string(),
     };
 
     (title, desc, price)
@@ -383,16 +565,7 @@ pub fn update_shop_cards_ui(
 ) {
     for (btn_type, mut bg_color, card_children) in &mut card_query {
         let config = crate::configs::shop::get_card_config(*btn_type);
-        let count = match btn_type {
-            ShopButton::Heal => progression.heal_count,
-            ShopButton::DamageUp => progression.damage_upgrades,
-            ShopButton::MaxHealthUp => progression.max_health_upgrades,
-            ShopButton::CritDamageUp => progression.crit_damage_upgrades,
-            ShopButton::CritChanceUp => progression.crit_chance_upgrades,
-            ShopButton::LifestealUp => progression.lifesteal_upgrades,
-            ShopButton::CooldownReductionUp => progression.cdr_upgrades,
-            ShopButton::NovaCore => progression.nova_core,
-        };
+        let count = get_progression_count(*btn_type, &progression);
 
         // Find the ShopCardCount container among card's children
         for &child in card_children {
@@ -432,3 +605,120 @@ pub fn update_shop_cards_ui(
         }
     }
 }
+
+/// Rebuilds the offer cards whenever `ShopOffers` changes (roll, reroll, lock, purchase).
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn refresh_shop_offers_ui(
+    mut commands: Commands,
+    offers: Res<ShopOffers>,
+    round_manager: Res<RoundManager>,
+    run_settings: Res<RunSettings>,
+    locale: Res<Locale>,
+    grid: Single<(Entity, Ref<ShopOfferGrid>)>,
+    mut selected: ResMut<SelectedShopCard>,
+    mut buy_btn_query: Query<&mut Node, With<ShopBuyButton>>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !offers.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|grid| {
+        for offer in &offers.offers {
+            spawn_shop_button(
+                grid,
+                offer.card,
+                offer.locked,
+                round_manager.current_round,
+                run_settings.price_multiplier(),
+                &locale,
+            );
+        }
+    });
+
+    // A selection that is no longer on offer can't be bought
+    if selected
+        .0
+        .is_some_and(|card| offers.offers.iter().all(|offer| offer.card != card))
+    {
+        selected.0 = None;
+        for mut node in &mut buy_btn_query {
+            node.display = Display::None;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_shop_reroll_text(
+    offers: Res<ShopOffers>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<ShopRerollText>>,
+) {
+    for mut text in &mut query {
+        text.0 = locale.format("shop.reroll_cost", &[("cost", &offers.reroll_cost())]);
+    }
+}
+
+/// Attacks of both hands, left first, for `stats`
+fn all_hand_attacks(
+    hands: &Query<(&Hand, &Weapon, Option<&MagicLoadout>)>,
+    stats: &PlayerStats,
+    combat: &CombatStats,
+) -> Vec<(&'static str, AttackStats)> {
+    let mut attacks = Vec::new();
+    for side in [HandType::Left, HandType::Right] {
+        for (hand, weapon, loadout) in hands {
+            if hand.side == side {
+                attacks.extend(hand_attacks(weapon.kind, loadout, stats, combat));
+            }
+        }
+    }
+    attacks
+}
+
+/// Shows how the selected card would change the player's attacks
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn update_shop_stat_preview(
+    selected: Res<SelectedShopCard>,
+    player: Single<(&PlayerStats, &CombatStats), With<Player>>,
+    hands: Query<(&Hand, &Weapon, Option<&MagicLoadout>)>,
+    locale: Res<Locale>,
+    mut preview_query: Query<(&mut Text, &mut Node), With<ShopStatPreviewText>>,
+) {
+    let (stats, combat) = *player;
+    let preview = selected.0.and_then(|card| {
+        let mut new_stats = *stats;
+        let mut new_combat = *combat;
+        let value = crate::configs::shop::get_card_config(card).value;
+        if !apply_attack_upgrade(card, value, &mut new_stats, &mut new_combat) {
+            return None;
+        }
+        let comparison = format_attack_comparison(
+            &all_hand_attacks(&hands, stats, combat),
+            &all_hand_attacks(&hands, &new_stats, &new_combat),
+            &locale,
+        );
+        Some(if comparison.is_empty() {
+            locale.get("shop.preview_none").to_string()
+        } else {
+            comparison
+        })
+    });
+
+    let display = if preview.is_some() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for (mut text, mut node) in &mut preview_query {
+        if let Some(preview) = &preview
+            && text.0 != *preview
+        {
+            text.0.clone_from(preview);
+        }
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
@@ -1,11 +1,20 @@
+use super::achievements::spawn_achievement_panel;
 use super::arsenal::spawn_equipment_panel;
 use super::components::WeaponMenuUI;
 use super::layout::{spawn_header, spawn_sidebar};
+use super::leaderboard::spawn_leaderboard_panel;
+use super::run_setup::spawn_run_setup_panel;
 use super::shop::spawn_shop_panel;
+use super::unlocks::spawn_unlock_panel;
+use crate::resources::locale::Locale;
 use bevy::prelude::*;
 
 #[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
-pub fn spawn_weapon_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_weapon_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    locale: Res<Locale>,
+) {
     commands
         .spawn((
             Node {
@@ -23,7 +32,7 @@ pub fn spawn_weapon_menu(mut commands: Commands, asset_server: Res<AssetServer>)
         ))
         .with_children(|root| {
             // === SIDEBAR (Left) ===
-            spawn_sidebar(root);
+            spawn_sidebar(root, &locale);
 
             // === MAIN CONTENT COLUMN (Right) ===
             root.spawn(Node {
@@ -50,10 +59,22 @@ pub fn spawn_weapon_menu(mut commands: Commands, asset_server: Res<AssetServer>)
                     })
                     .with_children(|content| {
                         // === SHOP CONTAINER (Card Tab) ===
-                        spawn_shop_panel(content);
+                        spawn_shop_panel(content, &locale);
 
                         // === EQUIPMENT CONTAINER (Equip Tab) ===
-                        spawn_equipment_panel(content, &asset_server);
+                        spawn_equipment_panel(content, &asset_server, &locale);
+
+                        // === UNLOCK CONTAINER (Unlocks Tab) ===
+                        spawn_unlock_panel(content, &locale);
+
+                        // === RUN SETUP CONTAINER (Run Tab) ===
+                        spawn_run_setup_panel(content, &locale);
+
+                        // === ACHIEVEMENT CONTAINER (Achievements Tab) ===
+                        spawn_achievement_panel(content, &locale);
+
+                        // === LEADERBOARD CONTAINER (Records Tab) ===
+                        spawn_leaderboard_panel(content, &locale);
                     });
 
                 // --- FOOTER ---
@@ -62,8 +83,13 @@ pub fn spawn_weapon_menu(mut commands: Commands, asset_server: Res<AssetServer>)
         });
 }
 
-pub fn despawn_weapon_menu(mut commands: Commands, query: Query<Entity, With<WeaponMenuUI>>) {
+pub fn despawn_weapon_menu(
+    mut commands: Commands,
+    query: Query<Entity, With<WeaponMenuUI>>,
+    mut preset_draft: ResMut<super::resources::PresetDraft>,
+) {
     for entity in &query {
         commands.entity(entity).despawn();
     }
+    preset_draft.editing = false;
 }
@@ -4,10 +4,11 @@ use super::components::{
     WeaponStateGroup,
 };
 use crate::components::player::{CombatStats, Currency, Hand, Health, Player, PlayerStats};
-use crate::components::weapon::{MagicLoadout, SpellType, WeaponType};
+use crate::components::weapon::{MagicLoadout, WeaponType};
 use crate::configs::visuals::{
     MAGIC_SELECTION_BG, MAGIC_SLOT_BG, MAGIC_SLOT_BORDER_HIGHLIGHT, MAGIC_SLOT_BORDER_SELECTED,
 };
+use crate::resources::locale::Locale;
 use bevy::prelude::*;
 
 #[derive(Component)]
@@ -36,9 +37,12 @@ pub fn magic_button_observer(
     btn_query: Query<&MagicSlotButton>,
     mut loadout_query: Query<(&Hand, &mut MagicLoadout)>,
     selected_spell: Res<super::components::SelectedSpell>,
+    profile: Res<crate::resources::profile::MetaProfile>,
 ) {
     if let Ok(btn_data) = btn_query.get(trigger.entity) {
-        if let Some(spell) = selected_spell.0 {
+        if let Some(spell) = selected_spell.0
+            && profile.spell_unlocked(spell)
+        {
             for (hand, mut loadout) in &mut loadout_query {
                 if hand.side == btn_data.side {
                     if btn_data.is_primary {
@@ -56,12 +60,31 @@ pub fn magic_button_observer(
 pub fn update_spell_list_description(
     mut query: Query<&mut Text, With<super::components::SpellListDescriptionText>>,
     selected_spell: Res<super::components::SelectedSpell>,
+    profile: Res<crate::resources::profile::MetaProfile>,
+    player: Single<(&PlayerStats, &CombatStats), With<Player>>,
+    locale: Res<Locale>,
 ) {
-    use super::arsenal::get_spell_description;
+    use super::arsenal::{format_attack_stats, get_spell_description};
+    use crate::systems::combat::stat_calc::spell_attack;
 
+    let (stats, combat) = *player;
     let text_content = selected_spell.0.map_or_else(
-        || "Select a spell to see details...".to_string(),
-        get_spell_description,
+        || locale.get("arsenal.select_spell").to_string(),
+        |spell| {
+            let mut description = get_spell_description(spell, &locale);
+            if let Some(attack) = spell_attack(spell, stats, combat) {
+                description.push('\n');
+                description.push_str(&format_attack_stats(
+                    &[(spell.definition().name(), attack)],
+                    &locale,
+                ));
+            }
+            if profile.spell_unlocked(spell) {
+                description
+            } else {
+                locale.format("arsenal.spell_locked", &[("description", &description)])
+            }
+        },
     );
 
     for mut text in &mut query {
@@ -85,6 +108,7 @@ pub fn update_menu_magic_ui(
         Without<MagicSlotButton>,
     >,
     selected_spell: Res<super::components::SelectedSpell>,
+    locale: Res<Locale>,
 ) {
     // 1. Panel Visibility
     for (mut node, panel) in &mut panel_query {
@@ -118,34 +142,22 @@ pub fn update_menu_magic_ui(
                         loadout.primary
                     } else {
                         loadout.secondary
-                    };
+                    }
+                    .definition();
+                    let spell_name = locale.get(spell.short_name());
+                    let icon_path = spell.icon();
 
-                    let spell_name = match spell {
-                        SpellType::EnergyBolt => "Bolt",
-                        SpellType::Laser => "Laser",
-                        SpellType::Nova => "Nova",
-                        SpellType::Blink => "Blink",
-                        SpellType::Global => "Global",
-                        SpellType::ForcePush => "Push",
-                        SpellType::ForcePull => "Pull",
-                    };
-                    let icon_path = match spell {
-                        SpellType::EnergyBolt => "ui/icons/magic_bolt.png",
-                        SpellType::Laser => "ui/icons/magic_laser.png",
-                        SpellType::Nova => "ui/icons/magic_nova.png",
-                        SpellType::Blink => "ui/icons/magic_blink.png",
-                        SpellType::Global => "ui/icons/magic_global.png",
-                        SpellType::ForcePush => "ui/icons/magic_push.png",
-                        SpellType::ForcePull => "ui/icons/magic_pull.png",
+                    let label_key = if btn_data.is_primary {
+                        "arsenal.slot.primary"
+                    } else {
+                        "arsenal.slot.secondary"
                     };
 
-                    let prefix = if btn_data.is_primary { "Pri" } else { "Sec" };
-
                     // Iterate children to find Text and Icon
                     for &child in children {
                         // Update Text
                         if let Ok(mut text) = text_query.get_mut(child) {
-                            **text = format!("{prefix}: {spell_name}");
+                            **text = locale.format(label_key, &[("spell", &spell_name)]);
                         }
                         // Update Icon - Note: In new layout, Icon is deeper, but we can still try to find it
                         // if the child path is direct. However, in new layout, Icon is inside a container.
@@ -188,6 +200,27 @@ pub fn update_menu_magic_ui(
     }
 }
 
+/// Greys out palette icons of spells that haven't been unlocked yet
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_spell_palette_locks(
+    profile: Res<crate::resources::profile::MetaProfile>,
+    button_query: Query<(&SpellListButton, &Children)>,
+    mut icon_query: Query<&mut ImageNode>,
+) {
+    for (btn_data, children) in &button_query {
+        let tint = if profile.spell_unlocked(btn_data.0) {
+            Color::WHITE
+        } else {
+            Color::srgba(0.3, 0.3, 0.3, 0.6)
+        };
+        for &child in children {
+            if let Ok(mut image) = icon_query.get_mut(child) {
+                image.color = tint;
+            }
+        }
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 pub fn update_menu_weapon_details_ui(
     mut panel_query: Query<(&mut Node, &Children, &WeaponDetailPanel)>,
@@ -199,8 +232,13 @@ pub fn update_menu_weapon_details_ui(
         Option<&MagicLoadout>,
     )>,
     active_side: Res<super::resources::ActiveDescriptionSide>,
+    player: Single<(&PlayerStats, &CombatStats), With<Player>>,
+    locale: Res<Locale>,
 ) {
-    use super::arsenal::get_weapon_description;
+    use super::arsenal::{format_attack_stats, get_weapon_description};
+    use crate::systems::combat::stat_calc::hand_attacks;
+
+    let (stats, combat) = *player;
     for (mut panel_node, children, panel) in &mut panel_query {
         if panel.side != active_side.0 {
             panel_node.display = Display::None;
@@ -217,7 +255,12 @@ pub fn update_menu_weapon_details_ui(
             } else {
                 None
             };
-            let desc = get_weapon_description(weapon_kind, loadout);
+            let mut desc = get_weapon_description(weapon_kind, loadout, &locale);
+            let attacks = hand_attacks(weapon_kind, loadout, stats, combat);
+            if !attacks.is_empty() {
+                desc.push_str("\n\n");
+                desc.push_str(&format_attack_stats(&attacks, &locale));
+            }
 
             for &child in children {
                 if let Ok(mut text) = text_query.get_mut(child) {
@@ -244,9 +287,10 @@ pub fn update_menu_weapon_details_ui(
 pub fn update_menu_gold_text(
     mut query: Query<&mut Text, With<MenuGoldText>>,
     player: Single<&Currency, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
-        text.0 = format!("Gold: {}", player.gold);
+        text.0 = locale.format("menu.gold", &[("gold", &player.gold)]);
     }
 }
 
@@ -254,9 +298,16 @@ pub fn update_menu_gold_text(
 pub fn update_menu_health_text(
     mut query: Query<&mut Text, With<MenuHealthText>>,
     player: Single<&Health, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
-        text.0 = format!("HP: {:.0}/{:.0}", player.current, player.max);
+        text.0 = locale.format(
+            "menu.hp",
+            &[
+                ("current", &format!("{:.0}", player.current)),
+                ("max", &format!("{:.0}", player.max)),
+            ],
+        );
     }
 }
 
@@ -264,10 +315,11 @@ pub fn update_menu_health_text(
 pub fn update_menu_damage_text(
     mut query: Query<&mut Text, With<MenuDamageText>>,
     player: Single<&PlayerStats, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
         let bonus = (player.damage_multiplier - 1.0) * 100.0;
-        text.0 = format!("Dmg: +{bonus:.0}%");
+        text.0 = locale.format("menu.damage", &[("bonus", &format!("{bonus:.0}"))]);
     }
 }
 
@@ -275,11 +327,18 @@ pub fn update_menu_damage_text(
 pub fn update_menu_crit_text(
     mut query: Query<&mut Text, With<MenuCritText>>,
     player: Single<&CombatStats, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
         let chance = player.crit_chance * 100.0;
         let damage = player.crit_damage;
-        text.0 = format!("Crit: {chance:.0}% (x{damage:.1})");
+        text.0 = locale.format(
+            "menu.crit",
+            &[
+                ("chance", &format!("{chance:.0}")),
+                ("damage", &format!("{damage:.1}")),
+            ],
+        );
     }
 }
 
@@ -287,25 +346,35 @@ pub fn update_menu_crit_text(
 pub fn update_menu_lifesteal_text(
     mut query: Query<&mut Text, With<MenuLifestealText>>,
     player: Single<&CombatStats, With<Player>>,
+    locale: Res<Locale>,
 ) {
     for mut text in &mut query {
         let life = player.lifesteal * 100.0;
-        text.0 = format!("Life Steal: {life:.0}% | AOE: {:.0}%", life / 2.0);
+        text.0 = locale.format(
+            "menu.lifesteal",
+            &[
+                ("life", &format!("{life:.0}")),
+                ("aoe", &format!("{:.0}", life / 2.0)),
+            ],
+        );
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
 pub fn update_menu_cdr_text(
     mut query: Query<&mut Text, With<MenuCDRText>>,
     player_query: Query<&CombatStats, With<Player>>,
+    locale: Res<Locale>,
 ) {
     if let Ok(stats) = player_query.single() {
         for mut text in &mut query {
             let cdr = stats.cooldown_reduction * 100.0;
-            text.0 = format!("CDR: {cdr:.0}%");
+            text.0 = locale.format("menu.cdr", &[("cdr", &format!("{cdr:.0}"))]);
         }
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
 pub fn update_menu_weapon_buttons(
     mut button_query: Query<(
         &Interaction,
@@ -314,13 +383,17 @@ pub fn update_menu_weapon_buttons(
         &mut BorderColor,
     )>,
     hand_query: Query<(&Hand, &crate::components::weapon::Weapon)>,
+    profile: Res<crate::resources::profile::MetaProfile>,
 ) {
     for (interaction, button, mut color, mut border) in &mut button_query {
         let is_active = hand_query
             .iter()
             .any(|(h, weapon)| h.side == button.side && weapon.kind == button.kind);
 
-        if is_active {
+        if !profile.weapon_unlocked(button.kind) {
+            *color = BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 1.0));
+            *border = BorderColor::from(Color::srgba(0.2, 0.2, 0.2, 1.0));
+        } else if is_active {
             *color = BackgroundColor(Color::srgba(0.2, 0.2, 0.3, 1.0));
             *border = BorderColor::from(Color::srgba(1.0, 0.84, 0.0, 1.0)); // Gold
         } else {
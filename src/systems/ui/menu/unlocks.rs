@@ -0,0 +1,210 @@
+//! Unlock screen: spends meta shards earned across runs on permanent content and bonuses
+
+use super::components::{
+    UnlockCardButton, UnlockContainer, UnlockGrid, UnlockShardsText, WeaponMenuTab,
+};
+use crate::configs::meta::{UNLOCK_LIST, get_unlock_config};
+use crate::resources::locale::Locale;
+use crate::resources::profile::{MetaProfile, Unlock};
+use bevy::prelude::*;
+
+const UNLOCKED_BORDER: Color = Color::srgb(0.3, 0.9, 0.4);
+const AFFORDABLE_BORDER: Color = Color::srgb(0.7, 0.5, 1.0);
+const LOCKED_BORDER: Color = Color::srgb(0.3, 0.3, 0.35);
+const SHARD_COLOR: Color = Color::srgb(0.75, 0.55, 1.0);
+
+pub fn spawn_unlock_panel(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                display: Display::None, // Default hidden
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            UnlockContainer,
+            WeaponMenuTab::Unlocks,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(locale.get("menu.tab.unlocks")),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(SHARD_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            panel.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+                UnlockShardsText,
+            ));
+
+            // Cards are filled in by `refresh_unlocks_ui`
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::FlexStart,
+                    width: Val::Percent(100.0),
+                    row_gap: Val::Px(10.0),
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                UnlockGrid,
+            ));
+        });
+}
+
+fn spawn_unlock_card(
+    parent: &mut ChildSpawnerCommands,
+    unlock: Unlock,
+    profile: &MetaProfile,
+    locale: &Locale,
+) {
+    let config = get_unlock_config(unlock);
+    let owned = profile.is_unlocked(unlock);
+    let border = if owned {
+        UNLOCKED_BORDER
+    } else if profile.shards >= config.cost {
+        AFFORDABLE_BORDER
+    } else {
+        LOCKED_BORDER
+    };
+    let status = if owned {
+        locale.get("achievements.unlocked").to_string()
+    } else {
+        locale.format("unlocks.cost", &[("cost", &config.cost)])
+    };
+
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(200.0),
+                min_height: Val::Px(110.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor::all(border),
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(Color::srgba(0.08, 0.06, 0.12, 0.95)),
+            UnlockCardButton(unlock),
+        ))
+        .observe(
+            |trigger: On<Pointer<Click>>,
+             button_query: Query<&UnlockCardButton>,
+             mut profile: ResMut<MetaProfile>| {
+                if let Ok(button) = button_query.get(trigger.entity)
+                    && profile.try_unlock(button.0)
+                {
+                    profile.save();
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.14, 0.1, 0.2, 1.0));
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(Color::srgba(0.08, 0.06, 0.12, 0.95));
+                }
+            },
+        )
+        .with_children(|card| {
+            card.spawn((
+                Text::new(locale.get(config.name)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            card.spawn((
+                Text::new(locale.get(config.description)),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            card.spawn((
+                Text::new(status),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(if owned { UNLOCKED_BORDER } else { SHARD_COLOR }),
+            ));
+        });
+}
+
+/// Rebuilds the unlock cards whenever the profile changes
+#[allow(clippy::needless_pass_by_value)]
+pub fn refresh_unlocks_ui(
+    mut commands: Commands,
+    profile: Res<MetaProfile>,
+    locale: Res<Locale>,
+    grid: Single<(Entity, Ref<UnlockGrid>)>,
+) {
+    let (grid_entity, grid_marker) = grid.into_inner();
+    if !profile.is_changed() && !grid_marker.is_added() {
+        return;
+    }
+
+    commands.entity(grid_entity).despawn_related::<Children>();
+    commands.entity(grid_entity).with_children(|grid| {
+        for unlock in UNLOCK_LIST {
+            spawn_unlock_card(grid, unlock, &profile, &locale);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_unlock_shards_text(
+    profile: Res<MetaProfile>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<UnlockShardsText>>,
+) {
+    for mut text in &mut query {
+        text.0 = locale.format(
+            "unlocks.summary",
+            &[
+                ("shards", &profile.shards),
+                ("round", &profile.best_round),
+                ("ascension", &profile.best_ascension),
+                ("runs", &profile.runs),
+            ],
+        );
+    }
+}
@@ -1,17 +1,25 @@
 pub mod components;
+pub mod font;
 pub mod game_over;
 pub mod hud;
+pub mod level_up;
 
 pub mod menu;
+pub mod pause;
 pub mod scaling;
 pub mod settings;
+pub mod toasts;
 pub mod tutorial;
 
 pub use components::*;
+pub use font::*;
 pub use game_over::*;
 pub use hud::*;
+pub use level_up::*;
 
 pub use menu::*;
+pub use pause::*;
 pub use scaling::*;
 pub use settings::*;
+pub use toasts::*;
 pub use tutorial::*;
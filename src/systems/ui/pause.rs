@@ -0,0 +1,105 @@
+//! Pause overlay: resume, open settings, or end the run on the game over screen
+
+use super::components::PauseMenuUI;
+use crate::resources::game_state::{GameState, PreviousMenuState};
+use crate::resources::locale::Locale;
+use bevy::prelude::*;
+
+const BUTTON_COLOR: Color = Color::srgba(0.2, 0.2, 0.2, 1.0);
+const BUTTON_HOVER_COLOR: Color = Color::srgba(0.3, 0.3, 0.3, 1.0);
+
+fn spawn_pause_button(parent: &mut ChildSpawnerCommands, label: &str, target: GameState) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(240.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::vertical(Val::Px(6.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor::all(Color::srgb(0.6, 0.6, 0.6)),
+            BorderRadius::all(Val::Px(10.0)),
+            BackgroundColor(BUTTON_COLOR),
+        ))
+        .observe(
+            move |_: On<Pointer<Click>>,
+                  mut next_state: ResMut<NextState<GameState>>,
+                  mut prev_state: ResMut<PreviousMenuState>| {
+                if target == GameState::Settings {
+                    prev_state.0 = GameState::Paused;
+                }
+                next_state.set(target);
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Over>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(BUTTON_HOVER_COLOR);
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut color: Query<&mut BackgroundColor>| {
+                if let Ok(mut color) = color.get_mut(trigger.entity) {
+                    *color = BackgroundColor(BUTTON_COLOR);
+                }
+            },
+        )
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn spawn_pause_menu(mut commands: Commands, locale: Res<Locale>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            PauseMenuUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(locale.get("pause.title")),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            spawn_pause_button(parent, locale.get("pause.resume"), GameState::Playing);
+            spawn_pause_button(parent, locale.get("pause.settings"), GameState::Settings);
+            // Ends the run through the same game over flow as dying
+            spawn_pause_button(parent, locale.get("pause.quit"), GameState::GameOver);
+        });
+}
+
+pub fn despawn_pause_menu(mut commands: Commands, query: Query<Entity, With<PauseMenuUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy::window::Window;
 
+use crate::resources::game_settings::GameSettings;
+
 /// Reference height for UI design (standard 1080p).
 const REFERENCE_HEIGHT: f32 = 1080.0;
 /// Minimum scale factor to prevent UI from becoming too small (unreadable).
@@ -10,14 +12,17 @@ const MAX_SCALE: f32 = 2.0;
 
 /// Updates the `UiScale` resource based on the primary window's height.
 /// This ensures uniform scaling of texts, buttons, and procedurally drawn icons.
+/// The player's text scale from `GameSettings` is applied on top.
+#[allow(clippy::needless_pass_by_value)]
 pub fn update_ui_scale(
     mut ui_scale: ResMut<UiScale>,
     window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    settings: Res<GameSettings>,
 ) {
     if let Some(window) = window_query.iter().next() {
         let height = window.height();
         // Calculate scale based on height ratio
-        let scale = (height / REFERENCE_HEIGHT).clamp(MIN_SCALE, MAX_SCALE);
+        let scale = (height / REFERENCE_HEIGHT).clamp(MIN_SCALE, MAX_SCALE) * settings.text_scale;
 
         // Update UiScale if changed significantly (to avoid float jitter)
         if (ui_scale.0 - scale).abs() > 0.01 {
@@ -1,10 +1,24 @@
-use super::components::{Action, RebindButton, RebindState, SettingsBackButton, SettingsUI};
+use super::components::{
+    Action, LanguageButton, PaletteButton, RebindButton, RebindState, SettingToggle,
+    SettingToggleButton, SettingsBackButton, SettingsUI, TextScaleValueText, VolumeChannel,
+    VolumeFill, VolumeSlider, VolumeValueText,
+};
+use crate::configs::accessibility::{TEXT_SCALE_MAX, TEXT_SCALE_MIN, TEXT_SCALE_STEP};
+use crate::configs::audio::{VOLUME_SLIDER_HEIGHT, VOLUME_SLIDER_WIDTH, VOLUME_STEP};
+use crate::resources::game_settings::{ColorPalette, GameSettings};
 use crate::resources::game_state::GameState;
 use crate::resources::input_settings::{ActionInput, InputSettings};
+use crate::resources::locale::Locale;
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 
 #[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
-pub fn spawn_settings_menu(mut commands: Commands, input_settings: Res<InputSettings>) {
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    input_settings: Res<InputSettings>,
+    game_settings: Res<GameSettings>,
+    locale: Res<Locale>,
+) {
     commands
         .spawn((
             Node {
@@ -23,7 +37,7 @@ pub fn spawn_settings_menu(mut commands: Commands, input_settings: Res<InputSett
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("SETTINGS"),
+                Text::new(locale.get("settings.title")),
                 TextFont {
                     font_size: 48.0,
                     ..default()
@@ -35,55 +49,162 @@ pub fn spawn_settings_menu(mut commands: Commands, input_settings: Res<InputSett
                 },
             ));
 
-            // Movement Section
-            spawn_section_header(parent, "MOVEMENT");
-            spawn_rebind_row(
-                parent,
-                "Up",
-                Action::MoveUp,
-                format!("{0:?}", input_settings.move_up),
-            );
-            spawn_rebind_row(
-                parent,
-                "Down",
-                Action::MoveDown,
-                format!("{0:?}", input_settings.move_down),
-            );
-            spawn_rebind_row(
-                parent,
-                "Left",
-                Action::MoveLeft,
-                format!("{0:?}", input_settings.move_left),
-            );
-            spawn_rebind_row(
-                parent,
-                "Right",
-                Action::MoveRight,
-                format!("{0:?}", input_settings.move_right),
-            );
-
-            // Combat Section
-            spawn_section_header(parent, "COMBAT/SKILLS");
-            spawn_rebind_row(
-                parent,
-                "Left Skill",
-                Action::LeftSkill,
-                format_action(input_settings.left_skill),
-            );
-            spawn_rebind_row(
-                parent,
-                "Right Skill",
-                Action::RightSkill,
-                format_action(input_settings.right_skill),
-            );
-
-            // Cursor Sensitivity Adjustment
-            spawn_section_header(parent, "TOUCH SETTINGS");
-            spawn_sensitivity_row(
-                parent,
-                "Cursor Sensitivity",
-                input_settings.touch_cursor_sensitivity,
-            );
+            // Controls on the left, display and accessibility on the right
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::FlexStart,
+                    column_gap: Val::Px(60.0),
+                    ..default()
+                })
+                .with_children(|columns| {
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            // Movement Section
+                            spawn_section_header(column, locale.get("settings.section.movement"));
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.up"),
+                                Action::MoveUp,
+                                format!("{0:?}", input_settings.move_up),
+                            );
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.down"),
+                                Action::MoveDown,
+                                format!("{0:?}", input_settings.move_down),
+                            );
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.left"),
+                                Action::MoveLeft,
+                                format!("{0:?}", input_settings.move_left),
+                            );
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.right"),
+                                Action::MoveRight,
+                                format!("{0:?}", input_settings.move_right),
+                            );
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.dash"),
+                                Action::Dash,
+                                format_action(input_settings.dash),
+                            );
+
+                            // Combat Section
+                            spawn_section_header(column, locale.get("settings.section.combat"));
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.left_skill"),
+                                Action::LeftSkill,
+                                format_action(input_settings.left_skill),
+                            );
+                            spawn_rebind_row(
+                                column,
+                                locale.get("settings.right_skill"),
+                                Action::RightSkill,
+                                format_action(input_settings.right_skill),
+                            );
+
+                            // Cursor Sensitivity Adjustment
+                            spawn_section_header(column, locale.get("settings.section.touch"));
+                            spawn_sensitivity_row(
+                                column,
+                                locale.get("settings.sensitivity"),
+                                input_settings.touch_cursor_sensitivity,
+                            );
+
+                            // Audio Section
+                            spawn_section_header(column, locale.get("settings.section.audio"));
+                            spawn_volume_row(
+                                column,
+                                locale.get("settings.master_volume"),
+                                VolumeChannel::Master,
+                                &game_settings,
+                            );
+                            spawn_volume_row(
+                                column,
+                                locale.get("settings.sfx_volume"),
+                                VolumeChannel::Sfx,
+                                &game_settings,
+                            );
+                            spawn_volume_row(
+                                column,
+                                locale.get("settings.music_volume"),
+                                VolumeChannel::Music,
+                                &game_settings,
+                            );
+                        });
+
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            // Display Section
+                            spawn_section_header(column, locale.get("settings.section.display"));
+                            spawn_toggle_row(
+                                column,
+                                locale.get("settings.damage_numbers"),
+                                SettingToggle::DamageNumbers,
+                                &game_settings,
+                                &locale,
+                            );
+                            spawn_toggle_row(
+                                column,
+                                locale.get("settings.offscreen_arrows"),
+                                SettingToggle::OffscreenIndicators,
+                                &game_settings,
+                                &locale,
+                            );
+                            spawn_toggle_row(
+                                column,
+                                locale.get("settings.minimap"),
+                                SettingToggle::Minimap,
+                                &game_settings,
+                                &locale,
+                            );
+
+                            // Accessibility Section
+                            spawn_section_header(
+                                column,
+                                locale.get("settings.section.accessibility"),
+                            );
+                            spawn_palette_row(column, game_settings.palette, &locale);
+                            spawn_toggle_row(
+                                column,
+                                locale.get("settings.enemy_glyphs"),
+                                SettingToggle::EnemyGlyphs,
+                                &game_settings,
+                                &locale,
+                            );
+                            spawn_toggle_row(
+                                column,
+                                locale.get("settings.reduced_motion"),
+                                SettingToggle::ReducedMotion,
+                                &game_settings,
+                                &locale,
+                            );
+                            spawn_text_scale_row(
+                                column,
+                                locale.get("settings.text_scale"),
+                                game_settings.text_scale,
+                            );
+
+                            // Language Section
+                            spawn_section_header(column, locale.get("settings.section.language"));
+                            spawn_language_row(column, &locale);
+                        });
+                });
 
             // Back Button
             parent
@@ -104,13 +225,15 @@ pub fn spawn_settings_menu(mut commands: Commands, input_settings: Res<InputSett
                     SettingsBackButton,
                 ))
                 .observe(
-                    |_: On<Pointer<Click>>, mut next_state: ResMut<NextState<GameState>>| {
-                        next_state.set(GameState::WeaponMenu);
+                    |_: On<Pointer<Click>>,
+                     mut next_state: ResMut<NextState<GameState>>,
+                     prev_state: Res<crate::resources::game_state::PreviousMenuState>| {
+                        next_state.set(prev_state.0);
                     },
                 )
                 .with_children(|btn| {
                     btn.spawn((
-                        Text::new("BACK"),
+                        Text::new(locale.get("common.back")),
                         TextFont {
                             font_size: 20.0,
                             ..default()
@@ -273,6 +396,475 @@ fn spawn_sensitivity_row(parent: &mut ChildSpawnerCommands, label: &str, value:
         });
 }
 
+const fn toggle_key(on: bool) -> &'static str {
+    if on { "settings.on" } else { "settings.off" }
+}
+
+fn spawn_toggle_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    toggle: SettingToggle,
+    settings: &GameSettings,
+    locale: &Locale,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Px(400.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+
+            row.spawn((
+                Button,
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(30.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                SettingToggleButton(toggle),
+            ))
+            .observe(
+                move |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                    toggle.toggle(&mut settings);
+                    settings.save();
+                },
+            )
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(locale.get(toggle_key(toggle.value(settings)))),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+fn spawn_palette_row(parent: &mut ChildSpawnerCommands, palette: ColorPalette, locale: &Locale) {
+    parent
+        .spawn(Node {
+            width: Val::Px(400.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(locale.get("settings.palette")),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+
+            row.spawn((
+                Button,
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(30.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                PaletteButton,
+            ))
+            .observe(
+                |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                    settings.palette = settings.palette.next();
+                    settings.save();
+                },
+            )
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(locale.get(palette.name())),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+fn spawn_text_scale_row(parent: &mut ChildSpawnerCommands, label: &str, value: f32) {
+    parent
+        .spawn(Node {
+            width: Val::Px(400.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+
+            row.spawn(Node {
+                width: Val::Px(150.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|ctrl| {
+                ctrl.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(30.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                ))
+                .observe(
+                    |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                        settings.text_scale =
+                            step_text_scale(settings.text_scale, -TEXT_SCALE_STEP);
+                        settings.save();
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("-"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                ctrl.spawn((
+                    Text::new(format_percent(value)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    TextScaleValueText,
+                ));
+
+                ctrl.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(30.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                ))
+                .observe(
+                    |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                        settings.text_scale = step_text_scale(settings.text_scale, TEXT_SCALE_STEP);
+                        settings.save();
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("+"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+        });
+}
+
+/// Steps the text scale, rounded to one decimal so repeated clicks don't drift
+fn step_text_scale(scale: f32, step: f32) -> f32 {
+    (((scale + step) * 10.0).round() / 10.0).clamp(TEXT_SCALE_MIN, TEXT_SCALE_MAX)
+}
+
+fn format_percent(value: f32) -> String {
+    format!("{:.0}%", value * 100.0)
+}
+
+#[allow(clippy::too_many_lines)]
+fn spawn_volume_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    channel: VolumeChannel,
+    settings: &GameSettings,
+) {
+    let value = channel.value(settings);
+    parent
+        .spawn(Node {
+            width: Val::Px(400.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+
+            row.spawn(Node {
+                width: Val::Px(250.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|ctrl| {
+                ctrl.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(30.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                ))
+                .observe(
+                    move |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                        let volume = step_volume(channel.value(&settings), -VOLUME_STEP);
+                        channel.set(&mut settings, volume);
+                        settings.save();
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("-"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                // Click or drag anywhere on the track to set the volume
+                ctrl.spawn((
+                    Node {
+                        width: Val::Px(VOLUME_SLIDER_WIDTH),
+                        height: Val::Px(VOLUME_SLIDER_HEIGHT),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                    RelativeCursorPosition::default(),
+                    VolumeSlider(channel),
+                ))
+                .observe(
+                    |trigger: On<Pointer<Click>>,
+                     sliders: Query<(&VolumeSlider, &RelativeCursorPosition)>,
+                     mut settings: ResMut<GameSettings>| {
+                        if set_volume_from_cursor(trigger.entity, &sliders, &mut settings) {
+                            settings.save();
+                        }
+                    },
+                )
+                .observe(
+                    |trigger: On<Pointer<Drag>>,
+                     sliders: Query<(&VolumeSlider, &RelativeCursorPosition)>,
+                     mut settings: ResMut<GameSettings>| {
+                        set_volume_from_cursor(trigger.entity, &sliders, &mut settings);
+                    },
+                )
+                .observe(|_: On<Pointer<DragEnd>>, settings: Res<GameSettings>| {
+                    settings.save();
+                })
+                .with_children(|track| {
+                    track.spawn((
+                        Node {
+                            width: Val::Percent(value * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.0, 0.8, 0.8)),
+                        Pickable::IGNORE,
+                        VolumeFill(channel),
+                    ));
+                });
+
+                ctrl.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(30.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                ))
+                .observe(
+                    move |_: On<Pointer<Click>>, mut settings: ResMut<GameSettings>| {
+                        let volume = step_volume(channel.value(&settings), VOLUME_STEP);
+                        channel.set(&mut settings, volume);
+                        settings.save();
+                    },
+                )
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("+"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                ctrl.spawn((
+                    Text::new(format_percent(value)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        width: Val::Px(45.0),
+                        ..default()
+                    },
+                    VolumeValueText(channel),
+                ));
+            });
+        });
+}
+
+/// Steps a volume by `step`, rounded to one decimal so repeated clicks don't drift
+fn step_volume(volume: f32, step: f32) -> f32 {
+    (((volume + step) * 10.0).round() / 10.0).clamp(0.0, 1.0)
+}
+
+/// Volume under a cursor at `x` across the track, in `RelativeCursorPosition` units
+fn volume_at(x: f32) -> f32 {
+    (((x + 0.5) * 20.0).round() / 20.0).clamp(0.0, 1.0)
+}
+
+/// Returns whether the cursor was over a slider and changed its volume
+fn set_volume_from_cursor(
+    slider: Entity,
+    sliders: &Query<(&VolumeSlider, &RelativeCursorPosition)>,
+    settings: &mut GameSettings,
+) -> bool {
+    let Ok((slider, cursor)) = sliders.get(slider) else {
+        return false;
+    };
+    let Some(position) = cursor.normalized else {
+        return false;
+    };
+    let volume = volume_at(position.x);
+    if (slider.0.value(settings) - volume).abs() < f32::EPSILON {
+        return false;
+    }
+    slider.0.set(settings, volume);
+    true
+}
+
+fn spawn_language_row(parent: &mut ChildSpawnerCommands, locale: &Locale) {
+    parent
+        .spawn(Node {
+            width: Val::Px(400.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(locale.get("settings.language")),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+
+            row.spawn((
+                Button,
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(30.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgb(0.4, 0.4, 0.4)),
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                LanguageButton,
+            ))
+            .observe(
+                |_: On<Pointer<Click>>,
+                 mut settings: ResMut<GameSettings>,
+                 mut locale: ResMut<Locale>,
+                 mut commands: Commands| {
+                    settings.language = settings.language.next();
+                    settings.save();
+                    locale.set_language(settings.language);
+                    // Every label on this screen was spawned in the old language
+                    commands.run_system_cached(despawn_settings_menu);
+                    commands.run_system_cached(spawn_settings_menu);
+                },
+            )
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(locale.language().native_name()),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
 fn format_action(action: ActionInput) -> String {
     match action {
         ActionInput::Keyboard(k) => format!("{k:?}"),
@@ -347,6 +939,7 @@ pub fn rebind_system(
             }
             Action::LeftSkill => input_settings.left_skill = input,
             Action::RightSkill => input_settings.right_skill = input,
+            Action::Dash => input_settings.dash = input,
         }
         rebind_state.active_action = None;
     }
@@ -371,6 +964,7 @@ pub fn update_rebind_ui(
                     Action::MoveRight => format!("{0:?}", input_settings.move_right),
                     Action::LeftSkill => format_action(input_settings.left_skill),
                     Action::RightSkill => format_action(input_settings.right_skill),
+                    Action::Dash => format_action(input_settings.dash),
                 };
             }
         }
@@ -386,3 +980,88 @@ pub fn update_sensitivity_ui(
         text.0 = format!("{:.1}", input_settings.touch_cursor_sensitivity);
     }
 }
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_setting_toggles(
+    settings: Res<GameSettings>,
+    locale: Res<Locale>,
+    query: Query<(&SettingToggleButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (button, children) in &query {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            text.0 = locale
+                .get(toggle_key(button.0.value(&settings)))
+                .to_string();
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_accessibility_settings_ui(
+    settings: Res<GameSettings>,
+    locale: Res<Locale>,
+    palette_query: Query<&Children, With<PaletteButton>>,
+    mut scale_query: Query<&mut Text, With<TextScaleValueText>>,
+    mut text_query: Query<&mut Text, Without<TextScaleValueText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for children in &palette_query {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            text.0 = locale.get(settings.palette.name()).to_string();
+        }
+    }
+    for mut text in &mut scale_query {
+        text.0 = format_percent(settings.text_scale);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_volume_settings_ui(
+    settings: Res<GameSettings>,
+    mut fills: Query<(&VolumeFill, &mut Node)>,
+    mut texts: Query<(&VolumeValueText, &mut Text)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (fill, mut node) in &mut fills {
+        node.width = Val::Percent(fill.0.value(&settings) * 100.0);
+    }
+    for (label, mut text) in &mut texts {
+        text.0 = format_percent(label.0.value(&settings));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_scale_steps_stay_in_range() {
+        assert!((step_text_scale(1.0, TEXT_SCALE_STEP) - 1.1).abs() < f32::EPSILON);
+        assert!(
+            (step_text_scale(TEXT_SCALE_MAX, TEXT_SCALE_STEP) - TEXT_SCALE_MAX).abs()
+                < f32::EPSILON
+        );
+        assert!(
+            (step_text_scale(TEXT_SCALE_MIN, -TEXT_SCALE_STEP) - TEXT_SCALE_MIN).abs()
+                < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_volume_steps_and_track_positions_stay_in_range() {
+        assert!((step_volume(0.8, VOLUME_STEP) - 0.9).abs() < f32::EPSILON);
+        assert!((step_volume(1.0, VOLUME_STEP) - 1.0).abs() < f32::EPSILON);
+        assert!(step_volume(0.0, -VOLUME_STEP).abs() < f32::EPSILON);
+        assert!(volume_at(-0.5).abs() < f32::EPSILON);
+        assert!((volume_at(0.0) - 0.5).abs() < f32::EPSILON);
+        assert!((volume_at(0.7) - 1.0).abs() < f32::EPSILON);
+    }
+}
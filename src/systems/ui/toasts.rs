@@ -0,0 +1,114 @@
+//! Achievement unlock toasts, shown over every screen
+
+use super::components::{AchievementToast, ToastStack};
+use crate::configs::achievements::{
+    TOAST_DURATION, TOAST_FADE, TOAST_WIDTH, get_achievement_config,
+};
+use crate::resources::locale::Locale;
+use crate::systems::achievements::AchievementUnlockedEvent;
+use bevy::prelude::*;
+
+const TOAST_BG: Color = Color::srgba(0.12, 0.1, 0.05, 0.95);
+const TOAST_BORDER: Color = Color::srgb(1.0, 0.8, 0.2);
+const TOAST_DESCRIPTION: Color = Color::srgb(0.8, 0.8, 0.85);
+
+pub fn spawn_toast_stack(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        // Above menus and the game over overlay
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+        ToastStack,
+    ));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn spawn_achievement_toast(
+    trigger: On<AchievementUnlockedEvent>,
+    mut commands: Commands,
+    locale: Res<Locale>,
+    stack: Single<Entity, With<ToastStack>>,
+) {
+    let config = get_achievement_config(trigger.event().achievement);
+    commands.entity(*stack).with_children(|stack| {
+        stack
+            .spawn((
+                Node {
+                    width: Val::Px(TOAST_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(10.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(TOAST_BG),
+                BorderColor::all(TOAST_BORDER),
+                BorderRadius::all(Val::Px(8.0)),
+                Pickable::IGNORE,
+                AchievementToast {
+                    timer: Timer::from_seconds(TOAST_DURATION, TimerMode::Once),
+                },
+            ))
+            .with_children(|toast| {
+                toast.spawn((
+                    Text::new(locale.format(
+                        "toast.achievement",
+                        &[("name", &locale.get(config.name).to_uppercase())],
+                    )),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(TOAST_BORDER),
+                ));
+                toast.spawn((
+                    Text::new(locale.get(config.description)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(TOAST_DESCRIPTION),
+                ));
+            });
+    });
+}
+
+/// Fades toasts out over their last `TOAST_FADE` seconds, then removes them
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn update_achievement_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toasts: Query<(
+        Entity,
+        &mut AchievementToast,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &Children,
+    )>,
+    mut text_colors: Query<&mut TextColor>,
+) {
+    for (entity, mut toast, mut background, mut border, children) in &mut toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let fade = (toast.timer.remaining_secs() / TOAST_FADE).min(1.0);
+        background.0 = TOAST_BG.with_alpha(TOAST_BG.alpha() * fade);
+        *border = BorderColor::all(TOAST_BORDER.with_alpha(fade));
+        for child in children {
+            if let Ok(mut color) = text_colors.get_mut(*child) {
+                color.0.set_alpha(fade);
+            }
+        }
+    }
+}
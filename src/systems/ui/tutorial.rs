@@ -1,10 +1,25 @@
 use super::components::TutorialUI;
 use crate::resources::game_state::GameState;
+use crate::resources::locale::Locale;
 
 use bevy::prelude::*;
 
-#[allow(clippy::too_many_lines)]
-pub fn spawn_tutorial_ui(mut commands: Commands) {
+/// (label, text) keys of the instruction lines, a spacer between the two groups
+const CONTROL_LINES: [(&str, &str); 6] = [
+    ("tutorial.movement.label", "tutorial.movement.text"),
+    ("tutorial.auto_attack.label", "tutorial.auto_attack.text"),
+    ("tutorial.left_skill.label", "tutorial.left_skill.text"),
+    ("tutorial.right_skill.label", "tutorial.right_skill.text"),
+    ("tutorial.pause.label", "tutorial.pause.text"),
+    ("tutorial.open_menu.label", "tutorial.open_menu.text"),
+];
+const TIP_LINES: [(&str, &str); 2] = [
+    ("tutorial.economy.label", "tutorial.economy.text"),
+    ("tutorial.ambidex.label", "tutorial.ambidex.text"),
+];
+
+#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
+pub fn spawn_tutorial_ui(mut commands: Commands, locale: Res<Locale>) {
     commands
         .spawn((
             Node {
@@ -24,7 +39,7 @@ pub fn spawn_tutorial_ui(mut commands: Commands) {
         .with_children(|parent| {
             // Title
             parent.spawn((
-                Text::new("TUTORIAL"),
+                Text::new(locale.get("tutorial.title")),
                 TextFont {
                     font_size: 60.0,
                     ..default()
@@ -50,39 +65,18 @@ pub fn spawn_tutorial_ui(mut commands: Commands) {
                     BorderColor::all(Color::srgba(0.5, 0.5, 0.5, 0.5)),
                 ))
                 .with_children(|box_node| {
-                    spawn_tutorial_line(box_node, "MOVEMENT", "W, A, S, D");
-                    spawn_tutorial_line(
-                        box_node,
-                        "AUTO-ATTACK",
-                        "Weapons & Spells fire automatically towards cursor.",
-                    );
-                    spawn_tutorial_line(
-                        box_node,
-                        "LEFT HAND SKILL",
-                        "Q Key (Weapon Specific Ability)",
-                    );
-                    spawn_tutorial_line(
-                        box_node,
-                        "RIGHT HAND SKILL",
-                        "E Key (Weapon Specific Ability)",
-                    );
-                    spawn_tutorial_line(box_node, "OPEN MENU", "ESC / Menu Button");
+                    for (label, text) in CONTROL_LINES {
+                        spawn_tutorial_line(box_node, locale.get(label), locale.get(text));
+                    }
 
                     box_node.spawn(Node {
                         height: Val::Px(20.0),
                         ..default()
                     });
 
-                    spawn_tutorial_line(
-                        box_node,
-                        "ECONOMY",
-                        "Kill enemies for 10G. Spend in Shop between rounds.",
-                    );
-                    spawn_tutorial_line(
-                        box_node,
-                        "AMBIDEX",
-                        "Customize each hand with unique weapons & spells!",
-                    );
+                    for (label, text) in TIP_LINES {
+                        spawn_tutorial_line(box_node, locale.get(label), locale.get(text));
+                    }
                 });
 
             // Back Button
@@ -124,7 +118,7 @@ pub fn spawn_tutorial_ui(mut commands: Commands) {
                 )
                 .with_children(|btn| {
                     btn.spawn((
-                        Text::new("BACK"),
+                        Text::new(locale.get("common.back")),
                         TextFont {
                             font_size: 30.0,
                             ..default()
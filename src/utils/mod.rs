@@ -1,2 +1 @@
-// This module contains utility functions for the project.
-// Currently empty as log_error was removed.
+pub mod storage;
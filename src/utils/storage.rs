@@ -0,0 +1,52 @@
+//! JSON persistence shared by the profile and run exports.
+//! Entries are files in the working directory natively and `localStorage` keys on wasm.
+
+use bevy::prelude::*;
+
+/// Where the entry called `name` is stored, for display
+#[cfg(not(target_arch = "wasm32"))]
+pub fn location(name: &str) -> String {
+    format!("ambidex_{name}.json")
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn location(name: &str) -> String {
+    format!("ambidex_survival.{name}")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read(name: &str) -> Option<String> {
+    std::fs::read_to_string(location(name)).ok()
+}
+
+/// Writes the entry, returning false (and logging a warning) on failure.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write(name: &str, json: &str) -> bool {
+    match std::fs::write(location(name), json) {
+        Ok(()) => true,
+        Err(err) => {
+            warn!("Failed to save {name}: {err}");
+            false
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn read(name: &str) -> Option<String> {
+    local_storage()?.get_item(&location(name)).ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn write(name: &str, json: &str) -> bool {
+    let saved =
+        local_storage().is_some_and(|storage| storage.set_item(&location(name), json).is_ok());
+    if !saved {
+        warn!("Failed to save {name} to localStorage");
+    }
+    saved
+}
@@ -2,6 +2,7 @@
 //! Contains functions to spawn UI nodes representing various icons (sword, shield, cross, etc.)
 //! These are procedurally drawn using Bevy UI Nodes.
 
+use crate::components::relic::Relic;
 use crate::systems::ui::ShopButton;
 use bevy::prelude::*;
 
@@ -28,6 +29,13 @@ pub fn spawn_shop_icon(parent: &mut ChildSpawnerCommands, btn_type: ShopButton)
             ShopButton::LifestealUp => spawn_lifesteal_icon(icon),
             ShopButton::CooldownReductionUp => spawn_cdr_icon(icon),
             ShopButton::NovaCore => spawn_nova_core_icon(icon),
+            ShopButton::MaxManaUp => spawn_max_mana_icon(icon),
+            ShopButton::ManaRegenUp => spawn_mana_regen_icon(icon),
+            ShopButton::ArmorUp => spawn_armor_icon(icon),
+            ShopButton::MoveSpeedUp => spawn_move_speed_icon(icon),
+            ShopButton::MagnetUp => spawn_magnet_icon(icon),
+            ShopButton::DashCooldownDown => spawn_dash_icon(icon),
+            ShopButton::Relic(relic) => spawn_relic_icon(icon, relic, 36.0),
         });
 }
 
@@ -306,6 +314,213 @@ fn spawn_nova_core_icon(parent: &mut ChildSpawnerCommands) {
     ));
 }
 
+fn spawn_max_mana_icon(parent: &mut ChildSpawnerCommands) {
+    // Mana flask
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(34.0),
+                height: Val::Px(34.0),
+                margin: UiRect::top(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(17.0)),
+            BorderColor::all(Color::srgb(0.9, 0.9, 0.9)), // Glass rim
+            BackgroundColor(Color::srgb(0.2, 0.4, 1.0)),  // Blue liquid
+        ))
+        .with_children(|flask| {
+            flask.spawn((
+                Node {
+                    width: Val::Px(12.0),
+                    height: Val::Px(12.0),
+                    margin: UiRect::all(Val::Auto),
+                    ..default()
+                },
+                Text::new("+"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+    // Neck
+    parent.spawn((
+        Node {
+            width: Val::Px(12.0),
+            height: Val::Px(10.0),
+            position_type: PositionType::Absolute,
+            top: Val::Px(2.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+        BorderRadius::top(Val::Px(3.0)),
+    ));
+}
+
+fn spawn_mana_regen_icon(parent: &mut ChildSpawnerCommands) {
+    // Mana droplet with a rising arrow
+    parent.spawn((
+        Node {
+            width: Val::Px(26.0),
+            height: Val::Px(26.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.5, 1.0)),
+        BorderRadius::top_left(Val::Px(13.0))
+            .with_bottom_left(Val::Px(13.0))
+            .with_bottom_right(Val::Px(13.0))
+            .with_top_right(Val::Px(0.0)), // Pointy top-right
+        Transform::from_rotation(Quat::from_rotation_z(45.0f32.to_radians())),
+    ));
+    // Arrow shaft
+    parent.spawn((
+        Node {
+            width: Val::Px(4.0),
+            height: Val::Px(18.0),
+            position_type: PositionType::Absolute,
+            right: Val::Px(2.0),
+            top: Val::Px(10.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 1.0, 0.8)),
+    ));
+    // Arrow head
+    parent.spawn((
+        Node {
+            width: Val::Px(10.0),
+            height: Val::Px(10.0),
+            position_type: PositionType::Absolute,
+            right: Val::Px(-1.0),
+            top: Val::Px(5.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 1.0, 0.8)),
+        BorderRadius::top_left(Val::Px(5.0)),
+        Transform::from_rotation(Quat::from_rotation_z(45.0f32.to_radians())),
+    ));
+}
+
+fn spawn_armor_icon(parent: &mut ChildSpawnerCommands) {
+    // Chestplate
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(36.0),
+                height: Val::Px(38.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BorderRadius::new(Val::Px(6.0), Val::Px(6.0), Val::Px(12.0), Val::Px(12.0)),
+            BackgroundColor(Color::srgb(0.5, 0.52, 0.58)), // Steel plate
+            BorderColor::all(Color::srgb(0.85, 0.85, 0.9)), // Polished rim
+        ))
+        .with_children(|plate| {
+            // Center ridge
+            plate.spawn((
+                Node {
+                    width: Val::Px(4.0),
+                    height: Val::Percent(100.0),
+                    margin: UiRect::horizontal(Val::Auto),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.85, 0.85, 0.9)),
+            ));
+        });
+    // Neck cutout
+    parent.spawn((
+        Node {
+            width: Val::Px(14.0),
+            height: Val::Px(8.0),
+            position_type: PositionType::Absolute,
+            top: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.12, 0.12, 0.14, 1.0)),
+        BorderRadius::bottom(Val::Px(7.0)),
+    ));
+}
+
+fn spawn_move_speed_icon(parent: &mut ChildSpawnerCommands) {
+    // Three speed streaks of decreasing length
+    for (width, top) in [(34.0, 10.0), (26.0, 22.0), (18.0, 34.0)] {
+        parent.spawn((
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(5.0),
+                position_type: PositionType::Absolute,
+                top: Val::Px(top),
+                right: Val::Px(8.0),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(3.0)),
+            BackgroundColor(Color::srgb(0.3, 0.9, 0.8)),
+        ));
+    }
+}
+
+fn spawn_magnet_icon(parent: &mut ChildSpawnerCommands) {
+    // Horseshoe body: thick rounded U
+    parent.spawn((
+        Node {
+            width: Val::Px(34.0),
+            height: Val::Px(30.0),
+            position_type: PositionType::Absolute,
+            top: Val::Px(14.0),
+            left: Val::Px(8.0),
+            border: UiRect::new(Val::Px(9.0), Val::Px(9.0), Val::Px(0.0), Val::Px(9.0)),
+            ..default()
+        },
+        BorderColor::all(Color::srgb(0.9, 0.2, 0.25)),
+        BorderRadius::bottom(Val::Px(17.0)),
+    ));
+    // Silver pole tips
+    for left in [8.0, 33.0] {
+        parent.spawn((
+            Node {
+                width: Val::Px(9.0),
+                height: Val::Px(7.0),
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Px(left),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.85, 0.85, 0.9)),
+        ));
+    }
+}
+
+fn spawn_dash_icon(parent: &mut ChildSpawnerCommands) {
+    // Fading afterimages trailing the player square
+    for (left, alpha) in [(6.0, 0.2), (14.0, 0.45)] {
+        parent.spawn((
+            Node {
+                width: Val::Px(18.0),
+                height: Val::Px(18.0),
+                position_type: PositionType::Absolute,
+                left: Val::Px(left),
+                top: Val::Px(17.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 1.0, 1.0, alpha)),
+        ));
+    }
+    parent.spawn((
+        Node {
+            width: Val::Px(18.0),
+            height: Val::Px(18.0),
+            position_type: PositionType::Absolute,
+            left: Val::Px(24.0),
+            top: Val::Px(17.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.0, 1.0, 1.0)),
+        BorderColor::all(Color::WHITE),
+    ));
+}
+
 /// Draws an infinity symbol (∞) using two overlapping circles
 pub fn spawn_infinity_symbol(parent: &mut ChildSpawnerCommands) {
     let circle_size = Val::Px(7.0);
@@ -344,3 +559,40 @@ pub fn spawn_infinity_symbol(parent: &mut ChildSpawnerCommands) {
         BackgroundColor(Color::NONE),
     ));
 }
+
+/// Cut gem in the relic's colour. Also used by the HUD relic strip.
+pub fn spawn_relic_icon(parent: &mut ChildSpawnerCommands, relic: Relic, size: f32) {
+    let color = crate::configs::relic::get_relic_config(relic).color;
+    // Gem body: square turned into a diamond
+    parent.spawn((
+        Node {
+            width: Val::Px(size * 0.7),
+            height: Val::Px(size * 0.7),
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(color.with_alpha(0.85)),
+        BorderColor::all(Color::WHITE.with_alpha(0.7)),
+        BorderRadius::all(Val::Px(size * 0.1)),
+        Transform::from_rotation(Quat::from_rotation_z(45.0f32.to_radians())),
+    ));
+    // Facet highlight
+    parent.spawn((
+        Node {
+            width: Val::Px(size * 0.2),
+            height: Val::Px(size * 0.2),
+            position_type: PositionType::Absolute,
+            // Pushed towards the upper left facet
+            margin: UiRect::new(
+                Val::ZERO,
+                Val::Px(size * 0.25),
+                Val::ZERO,
+                Val::Px(size * 0.25),
+            ),
+            ..default()
+        },
+        BackgroundColor(Color::WHITE.with_alpha(0.8)),
+        BorderRadius::all(Val::Px(size * 0.1)),
+    ));
+}
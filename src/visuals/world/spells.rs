@@ -1,10 +1,12 @@
 #![allow(clippy::cast_precision_loss)]
-//! Visual effects for spell attacks (Energy Bolt, Laser, Nova, Global)
+//! Visual effects for spell attacks (Energy Bolt, Laser, Nova, Global, Meteor, Frost Ring,
+//! Chain Lightning, Arcane Turret)
 
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::configs::spells::{global, laser, nova};
+use crate::components::weapon::MeteorTelegraphFill;
+use crate::configs::spells::{arcane_turret, chain_lightning, global, laser, nova};
 
 /// Spawn visual effects for Energy Bolt spell
 pub fn spawn_energy_bolt_visuals(
@@ -261,3 +263,188 @@ pub fn spawn_global_visuals(
         ));
     }
 }
+
+/// Spawn the warning circle shown while a Meteor is falling
+pub fn spawn_meteor_telegraph_visuals(
+    parent: &mut ChildSpawnerCommands,
+    cached: &crate::resources::cached_assets::CachedAssets,
+    radius: f32,
+) {
+    // Full danger area
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_telegraph_20.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.2).with_scale(Vec3::splat(radius)),
+    ));
+
+    // Edge markers so the radius reads clearly over other effects
+    for i in 0..12 {
+        let angle = (i as f32) * std::f32::consts::TAU / 12.0;
+        parent.spawn((
+            Mesh2d(cached.unit_square.clone()),
+            MeshMaterial2d(cached.mat_telegraph_50.clone()),
+            Transform::from_xyz(angle.cos() * radius, angle.sin() * radius, -0.1)
+                .with_rotation(Quat::from_rotation_z(angle))
+                .with_scale(Vec3::new(10.0, 3.0, 1.0)),
+        ));
+    }
+
+    // Fill grows to the full radius as the impact approaches
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_telegraph_50.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.15).with_scale(Vec3::ZERO),
+        MeteorTelegraphFill,
+    ));
+}
+
+/// Spawn visual effects for the Meteor impact
+pub fn spawn_meteor_impact_visuals(
+    parent: &mut ChildSpawnerCommands,
+    cached: &crate::resources::cached_assets::CachedAssets,
+    radius: f32,
+) {
+    let mut rng = rand::thread_rng();
+
+    // Scorched blast area
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_orange_25.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.3).with_scale(Vec3::splat(radius)),
+    ));
+    // Molten core
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_lava_90.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.2).with_scale(Vec3::splat(radius * 0.5)),
+    ));
+    // Flash
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_yellow_100.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.1).with_scale(Vec3::splat(radius * 0.2)),
+    ));
+
+    // Debris thrown outwards
+    for _ in 0..14 {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let dist = radius * rng.gen_range(0.5..1.0);
+        let size = rng.gen_range(4.0..8.0);
+        parent.spawn((
+            Mesh2d(cached.unit_square.clone()),
+            MeshMaterial2d(cached.mat_brown.clone()),
+            Transform::from_xyz(angle.cos() * dist, angle.sin() * dist, 0.1)
+                .with_rotation(Quat::from_rotation_z(angle))
+                .with_scale(Vec3::splat(size)),
+        ));
+    }
+}
+
+/// Spawn visual effects for Frost Ring.
+/// Drawn at unit radius, the parent transform is scaled as the ring expands.
+pub fn spawn_frost_ring_visuals(
+    parent: &mut ChildSpawnerCommands,
+    cached: &crate::resources::cached_assets::CachedAssets,
+) {
+    // Chilled area
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_frost_20.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.2),
+    ));
+
+    // Ice shards along the rim
+    for i in 0..20 {
+        let angle = (i as f32) * std::f32::consts::TAU / 20.0;
+        parent.spawn((
+            Mesh2d(cached.unit_triangle.clone()),
+            MeshMaterial2d(cached.mat_frost_70.clone()),
+            Transform::from_xyz(angle.cos() * 0.95, angle.sin() * 0.95, -0.1)
+                .with_rotation(Quat::from_rotation_z(angle - std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::new(0.04, 0.08, 1.0)),
+        ));
+    }
+}
+
+/// Spawn one Chain Lightning arc along +X with the given length
+pub fn spawn_chain_lightning_visuals(
+    parent: &mut ChildSpawnerCommands,
+    cached: &crate::resources::cached_assets::CachedAssets,
+    length: f32,
+) {
+    let mut rng = rand::thread_rng();
+    let segments = 6;
+    let step = length / segments as f32;
+    let mut prev = Vec2::ZERO;
+
+    for i in 1..=segments {
+        let jitter = if i == segments {
+            0.0
+        } else {
+            rng.gen_range(-12.0..12.0)
+        };
+        let next = Vec2::new(step * i as f32, jitter);
+        let delta = next - prev;
+        let mid = (prev + next) / 2.0;
+        let rotation = Quat::from_rotation_z(delta.y.atan2(delta.x));
+
+        // Glow
+        parent.spawn((
+            Mesh2d(cached.unit_square.clone()),
+            MeshMaterial2d(cached.mat_lightning_glow.clone()),
+            Transform::from_translation(mid.extend(-0.1))
+                .with_rotation(rotation)
+                .with_scale(Vec3::new(
+                    delta.length(),
+                    chain_lightning::ARC_WIDTH * 3.0,
+                    1.0,
+                )),
+        ));
+        // Core
+        parent.spawn((
+            Mesh2d(cached.unit_square.clone()),
+            MeshMaterial2d(cached.mat_white.clone()),
+            Transform::from_translation(mid.extend(0.0))
+                .with_rotation(rotation)
+                .with_scale(Vec3::new(delta.length(), chain_lightning::ARC_WIDTH, 1.0)),
+        ));
+        prev = next;
+    }
+}
+
+/// Spawn visual effects for the Arcane Turret
+pub fn spawn_arcane_turret_visuals(
+    parent: &mut ChildSpawnerCommands,
+    cached: &crate::resources::cached_assets::CachedAssets,
+) {
+    let radius = arcane_turret::VISUAL_RADIUS;
+
+    // Aura
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_purple_20.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.2).with_scale(Vec3::splat(radius * 2.5)),
+    ));
+    // Base
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_blue_dark.clone()),
+        Transform::from_xyz(0.0, 0.0, -0.1).with_scale(Vec3::splat(radius)),
+    ));
+    // Crystal (two triangles forming a star)
+    for rot in [0.0, std::f32::consts::PI] {
+        parent.spawn((
+            Mesh2d(cached.unit_triangle.clone()),
+            MeshMaterial2d(cached.mat_bolt_glow.clone()),
+            Transform::from_xyz(0.0, 0.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(rot))
+                .with_scale(Vec3::splat(radius * 0.8)),
+        ));
+    }
+    // Core
+    parent.spawn((
+        Mesh2d(cached.unit_circle.clone()),
+        MeshMaterial2d(cached.mat_bolt_core.clone()),
+        Transform::from_xyz(0.0, 0.0, 0.1).with_scale(Vec3::splat(radius * 0.3)),
+    ));
+}